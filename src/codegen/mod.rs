@@ -0,0 +1,640 @@
+//! Generates typed element structs from an [`EbmlSchema`]
+//!
+//! Every element gets a struct with its `ID`, `NAME`, `PATH`, occurrence bounds and
+//! `DEFAULT` when the schema has one. Leaf elements hold their decoded `value`, master
+//! elements hold one field per child defined by the schema. Both can be read from and
+//! turned back into an [`EbmlNode`](crate::EbmlNode). [`Generator::generate_ids`] only
+//! writes an ID constant per element.
+//!
+//! From a build script:
+//!
+//! ```no_run
+//! // build.rs
+//! rebml::codegen::build("schemas/my_format.xml", "my_format.rs").unwrap();
+//! ```
+//!
+//! and in the crate:
+//!
+//! ```ignore
+//! mod my_format {
+//!     include!(concat!(env!("OUT_DIR"), "/my_format.rs"));
+//! }
+//! ```
+
+use crate::schema::{ElementType, Number, PathAtom, SchemaElement};
+use crate::{EbmlError, EbmlSchema};
+use std::collections::{BTreeSet, HashMap};
+use std::fmt::Write;
+use std::fs::File;
+use std::path::{Path, PathBuf};
+
+/// IDs of the global elements from the EBML header schema, typed structs skip them
+const BUILTIN_GLOBALS: [u64; 2] = [0xBF, 0xEC];
+
+pub struct Generator<'s> {
+    schema: &'s EbmlSchema,
+    crate_path: String,
+}
+
+impl<'s> Generator<'s> {
+    pub fn new(schema: &'s EbmlSchema) -> Self {
+        Self {
+            schema,
+            crate_path: "::rebml".to_string(),
+        }
+    }
+
+    /// Sets how the generated code refers to this crate, `::rebml` by default
+    pub fn with_crate_path(mut self, path: &str) -> Self {
+        self.crate_path = path.to_string();
+        self
+    }
+
+    /// Returns the Rust source for every element of the schema
+    pub fn generate(&self) -> String {
+        let elements = self.schema.elements();
+        let names = type_names(elements);
+        let globals: BTreeSet<u64> = BUILTIN_GLOBALS
+            .into_iter()
+            .chain(elements.iter().filter(|e| e.is_global()).map(|e| e.id))
+            .collect();
+
+        let mut out = String::new();
+        let _ = writeln!(
+            out,
+            "// Generated by rebml::codegen from the \"{}\" schema version {}, do not edit",
+            self.schema.doc_type, self.schema.version
+        );
+        for element in elements {
+            out.push('\n');
+            if element.is_master() {
+                let children: Vec<&SchemaElement> = elements
+                    .iter()
+                    .filter(|child| is_child(element, child))
+                    .collect();
+                self.master(&mut out, element, &children, &names, &globals);
+            } else {
+                self.leaf(&mut out, element, &names);
+            }
+        }
+        out
+    }
+
+    /// Returns a Rust module body with an ID constant per element, e.g.
+    /// `pub const TIMESTAMP_SCALE: u64 = 0x2AD7B1;`
+    pub fn generate_ids(&self) -> String {
+        let elements = self.schema.elements();
+        let mut counts: HashMap<String, usize> = HashMap::new();
+        for element in elements {
+            *counts.entry(const_name(&element.name)).or_default() += 1;
+        }
+
+        let mut out = String::new();
+        let _ = writeln!(
+            out,
+            "// Generated by rebml::codegen from the \"{}\" schema version {}, do not edit",
+            self.schema.doc_type, self.schema.version
+        );
+        for element in elements {
+            let mut name = const_name(&element.name);
+            if counts[&name] > 1 {
+                let _ = write!(name, "_{:X}", element.id);
+            }
+            let _ = writeln!(out, "\n/// `{}`", element.path);
+            let _ = writeln!(out, "pub const {}: u64 = 0x{:X};", name, element.id);
+        }
+        out
+    }
+
+    fn header(&self, out: &mut String, element: &SchemaElement, name: &str, derives: &str) {
+        if let Some(doc) = element
+            .documentation
+            .iter()
+            .find(|d| d.purpose == "definition")
+        {
+            let text = doc.text.split_whitespace().collect::<Vec<_>>().join(" ");
+            let _ = writeln!(out, "/// {}", text);
+        }
+        let _ = writeln!(out, "#[derive({})]", derives);
+        let _ = writeln!(out, "pub struct {} {{", name);
+    }
+
+    fn constants(&self, out: &mut String, element: &SchemaElement) {
+        let _ = writeln!(out, "    pub const ID: u64 = 0x{:X};", element.id);
+        let _ = writeln!(out, "    pub const NAME: &str = {:?};", element.name);
+        let _ = writeln!(
+            out,
+            "    pub const PATH: &str = {:?};",
+            element.path.to_string()
+        );
+        if let Some(default) = default_literal(element) {
+            let _ = writeln!(
+                out,
+                "    pub const DEFAULT: {} = {};",
+                const_type(element.element_type),
+                default
+            );
+        }
+        let _ = writeln!(
+            out,
+            "    pub const MIN_OCCURS: u64 = {};",
+            element.min_occurs
+        );
+        if let Some(max) = element.max_occurs {
+            let _ = writeln!(out, "    pub const MAX_OCCURS: u64 = {};", max);
+        }
+    }
+
+    fn leaf(&self, out: &mut String, element: &SchemaElement, names: &HashMap<u64, String>) {
+        let c = &self.crate_path;
+        let name = &names[&element.id];
+        let value_type = value_type(element.element_type);
+        let has_default = default_literal(element).is_some();
+
+        self.header(out, element, name, "Debug, Clone, PartialEq");
+        let _ = writeln!(out, "    pub value: {},", value_type);
+        let _ = writeln!(out, "}}\n");
+
+        let _ = writeln!(out, "impl {} {{", name);
+        self.constants(out, element);
+        let _ = writeln!(out);
+
+        let (argument, conversion) = match element.element_type {
+            ElementType::String | ElementType::Utf8 => ("impl Into<String>", "value: value.into()"),
+            _ => (value_type, "value"),
+        };
+        let _ = writeln!(out, "    pub fn new(value: {}) -> Self {{", argument);
+        let _ = writeln!(out, "        Self {{ {} }}", conversion);
+        let _ = writeln!(out, "    }}\n");
+
+        if has_default {
+            let _ = writeln!(
+                out,
+                "    /// Decodes the element's data, an empty element stands for `DEFAULT`"
+            );
+        } else {
+            let _ = writeln!(out, "    /// Decodes the element's data");
+        }
+        let _ = writeln!(
+            out,
+            "    pub fn parse(data: &[u8]) -> Result<Self, {}::EbmlError> {{",
+            c
+        );
+        if has_default {
+            let _ = writeln!(out, "        if data.is_empty() {{");
+            let _ = writeln!(out, "            return Ok(Self::new(Self::DEFAULT));");
+            let _ = writeln!(out, "        }}");
+        }
+        let decode = match element.element_type {
+            ElementType::UnsignedInteger => {
+                format!("{}::EbmlUnsignedInteger::new(data)?.value()", c)
+            }
+            ElementType::Integer => format!("{}::EbmlSignedInteger::new(data)?.value()", c),
+            ElementType::Float => format!("{}::EbmlFloat::new(data)?.value()", c),
+            ElementType::Date => format!("{}::EbmlDate::new(data)?.value()", c),
+            ElementType::String => format!("{}::EbmlString::new(data)?.as_str()", c),
+            ElementType::Utf8 => format!("{}::EbmlUtf8::new(data)?.as_str()", c),
+            ElementType::Binary | ElementType::Master => "data.to_vec()".to_string(),
+        };
+        let _ = writeln!(out, "        Ok(Self::new({}))", decode);
+        let _ = writeln!(out, "    }}\n");
+
+        let _ = writeln!(
+            out,
+            "    pub fn from_node(node: &{c}::EbmlNode) -> Result<Self, {c}::EbmlError> {{",
+            c = c
+        );
+        self.check_id(out);
+        let _ = writeln!(
+            out,
+            "        Self::parse(node.data().ok_or({}::EbmlError::IsMasterElement(node.id))?)",
+            c
+        );
+        let _ = writeln!(out, "    }}\n");
+
+        let encode = match element.element_type {
+            ElementType::UnsignedInteger => "unsigned(Self::ID, self.value)",
+            ElementType::Integer => "signed(Self::ID, self.value)",
+            ElementType::Float => "float(Self::ID, self.value)",
+            ElementType::Date => "date(Self::ID, self.value)",
+            ElementType::String | ElementType::Utf8 => "string(Self::ID, &self.value)",
+            ElementType::Binary | ElementType::Master => "binary(Self::ID, self.value.clone())",
+        };
+        let _ = writeln!(
+            out,
+            "    pub fn to_node(&self) -> {}::EbmlNode<'static> {{",
+            c
+        );
+        let _ = writeln!(out, "        {}::EbmlNode::{}", c, encode);
+        let _ = writeln!(out, "    }}");
+        let _ = writeln!(out, "}}");
+    }
+
+    fn master(
+        &self,
+        out: &mut String,
+        element: &SchemaElement,
+        children: &[&SchemaElement],
+        names: &HashMap<u64, String>,
+        globals: &BTreeSet<u64>,
+    ) {
+        let c = &self.crate_path;
+        let name = &names[&element.id];
+        // A single child containing its own parent is boxed to keep the struct sized
+        let fields: Vec<(String, &String, bool, bool)> = children
+            .iter()
+            .map(|child| {
+                let single = child.max_occurs == Some(1);
+                let boxed = single && is_self_or_ancestor(element, child);
+                (field_name(&child.name), &names[&child.id], single, boxed)
+            })
+            .collect();
+
+        self.header(out, element, name, "Debug, Clone, PartialEq, Default");
+        for (field, type_name, single, boxed) in &fields {
+            if *boxed {
+                let _ = writeln!(out, "    pub {}: Option<Box<{}>>,", field, type_name);
+            } else if *single {
+                let _ = writeln!(out, "    pub {}: Option<{}>,", field, type_name);
+            } else {
+                let _ = writeln!(out, "    pub {}: Vec<{}>,", field, type_name);
+            }
+        }
+        let _ = writeln!(
+            out,
+            "    /// Children the schema doesn't define for this element"
+        );
+        let _ = writeln!(out, "    pub unknown: Vec<{}::EbmlNode<'static>>,", c);
+        let _ = writeln!(out, "}}\n");
+
+        let _ = writeln!(out, "impl {} {{", name);
+        self.constants(out, element);
+        let _ = writeln!(out);
+
+        let _ = writeln!(
+            out,
+            "    pub fn from_node(node: &{c}::EbmlNode) -> Result<Self, {c}::EbmlError> {{",
+            c = c
+        );
+        self.check_id(out);
+        let _ = writeln!(out, "        if !node.is_master() {{");
+        let _ = writeln!(
+            out,
+            "            return Err({}::EbmlError::NotMasterElement(node.id));",
+            c
+        );
+        let _ = writeln!(out, "        }}");
+        let _ = writeln!(out, "        let mut value = Self::default();");
+        let _ = writeln!(out, "        for child in node.children() {{");
+        let _ = writeln!(out, "            match child.id {{");
+        for (field, type_name, single, boxed) in &fields {
+            if *boxed {
+                let _ = writeln!(
+                    out,
+                    "                {t}::ID => value.{f} = Some(Box::new({t}::from_node(child)?)),",
+                    t = type_name,
+                    f = field
+                );
+            } else if *single {
+                let _ = writeln!(
+                    out,
+                    "                {t}::ID => value.{f} = Some({t}::from_node(child)?),",
+                    t = type_name,
+                    f = field
+                );
+            } else {
+                let _ = writeln!(
+                    out,
+                    "                {t}::ID => value.{f}.push({t}::from_node(child)?),",
+                    t = type_name,
+                    f = field
+                );
+            }
+        }
+        let globals = globals
+            .iter()
+            .map(|id| format!("0x{:X}", id))
+            .collect::<Vec<_>>()
+            .join(" | ");
+        let _ = writeln!(out, "                {} => {{}}", globals);
+        let _ = writeln!(
+            out,
+            "                _ => value.unknown.push(child.clone().into_owned()),"
+        );
+        let _ = writeln!(out, "            }}");
+        let _ = writeln!(out, "        }}");
+        let _ = writeln!(out, "        Ok(value)");
+        let _ = writeln!(out, "    }}\n");
+
+        let _ = writeln!(
+            out,
+            "    pub fn to_node(&self) -> {}::EbmlNode<'static> {{",
+            c
+        );
+        let _ = writeln!(out, "        let mut children = Vec::new();");
+        for (field, _, single, _) in &fields {
+            if *single {
+                let _ = writeln!(out, "        if let Some(child) = &self.{} {{", field);
+            } else {
+                let _ = writeln!(out, "        for child in &self.{} {{", field);
+            }
+            let _ = writeln!(out, "            children.push(child.to_node());");
+            let _ = writeln!(out, "        }}");
+        }
+        let _ = writeln!(
+            out,
+            "        children.extend(self.unknown.iter().cloned());"
+        );
+        let _ = writeln!(out, "        {}::EbmlNode::master(Self::ID, children)", c);
+        let _ = writeln!(out, "    }}");
+        let _ = writeln!(out, "}}");
+    }
+
+    fn check_id(&self, out: &mut String) {
+        let _ = writeln!(out, "        if node.id != Self::ID {{");
+        let _ = writeln!(
+            out,
+            "            return Err({}::EbmlError::UnexpectedElement(Self::ID, node.id));",
+            self.crate_path
+        );
+        let _ = writeln!(out, "        }}");
+    }
+}
+
+/// Generates the code for the schema at `schema_path` into `out_file` inside the build
+/// script's `OUT_DIR`, returning the path written
+pub fn build(schema_path: impl AsRef<Path>, out_file: &str) -> Result<PathBuf, EbmlError> {
+    let schema_path = schema_path.as_ref();
+    println!("cargo:rerun-if-changed={}", schema_path.display());
+
+    let schema = EbmlSchema::from_reader(File::open(schema_path)?)?;
+    let out_dir = std::env::var_os("OUT_DIR")
+        .ok_or_else(|| std::io::Error::new(std::io::ErrorKind::NotFound, "OUT_DIR is not set"))?;
+    let path = Path::new(&out_dir).join(out_file);
+    std::fs::write(&path, Generator::new(&schema).generate())?;
+    Ok(path)
+}
+
+/// Whether `child` is defined directly under `parent`, including a recursive element
+/// nested in itself
+fn is_child(parent: &SchemaElement, child: &SchemaElement) -> bool {
+    child.path.parent_name() == Some(parent.name.as_str())
+        || (child.id == parent.id && parent.is_recursive())
+}
+
+/// Whether `child` is `element` itself or one of the elements on its path
+fn is_self_or_ancestor(element: &SchemaElement, child: &SchemaElement) -> bool {
+    child.id == element.id
+        || element
+            .path
+            .atoms()
+            .iter()
+            .any(|atom| matches!(atom, PathAtom::Name { name, .. } if *name == child.name))
+}
+
+/// Struct names for every element, names that collide after cleaning up get their ID
+/// appended
+fn type_names(elements: &[SchemaElement]) -> HashMap<u64, String> {
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    for element in elements {
+        *counts.entry(type_name(&element.name)).or_default() += 1;
+    }
+    elements
+        .iter()
+        .map(|element| {
+            let name = type_name(&element.name);
+            if counts[&name] > 1 {
+                (element.id, format!("{}{:X}", name, element.id))
+            } else {
+                (element.id, name)
+            }
+        })
+        .collect()
+}
+
+/// `CRC-32` becomes `CRC32`, other element names are already camel case
+fn type_name(name: &str) -> String {
+    let mut out = String::new();
+    let mut upper = true;
+    for c in name.chars() {
+        if c.is_ascii_alphanumeric() {
+            if out.is_empty() && c.is_ascii_digit() {
+                out.push('E');
+            }
+            out.push(if upper { c.to_ascii_uppercase() } else { c });
+            upper = false;
+        } else {
+            upper = true;
+        }
+    }
+    out
+}
+
+/// `EBMLMaxIDLength` becomes `ebml_max_id_length`
+fn field_name(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut out = String::new();
+    for (i, c) in chars.iter().enumerate() {
+        if !c.is_ascii_alphanumeric() {
+            if !out.is_empty() && !out.ends_with('_') {
+                out.push('_');
+            }
+            continue;
+        }
+        if c.is_ascii_uppercase() && i > 0 && !out.ends_with('_') {
+            let previous = chars[i - 1];
+            let next_lower = chars.get(i + 1).is_some_and(|n| n.is_ascii_lowercase());
+            if previous.is_ascii_lowercase()
+                || previous.is_ascii_digit()
+                || (previous.is_ascii_uppercase() && next_lower)
+            {
+                out.push('_');
+            }
+        }
+        out.push(c.to_ascii_lowercase());
+    }
+    if out.starts_with(|c: char| c.is_ascii_digit()) {
+        out.insert(0, '_');
+    }
+    match out.as_str() {
+        "as" | "break" | "const" | "continue" | "crate" | "else" | "enum" | "extern" | "false"
+        | "fn" | "for" | "if" | "impl" | "in" | "let" | "loop" | "match" | "mod" | "move"
+        | "mut" | "pub" | "ref" | "return" | "self" | "static" | "struct" | "super" | "trait"
+        | "true" | "type" | "unsafe" | "use" | "where" | "while" | "async" | "await" | "dyn"
+        | "unknown" => out + "_",
+        _ => out,
+    }
+}
+
+/// `EBMLMaxIDLength` becomes `EBML_MAX_ID_LENGTH`
+fn const_name(name: &str) -> String {
+    field_name(name).trim_end_matches('_').to_ascii_uppercase()
+}
+
+fn value_type(element_type: ElementType) -> &'static str {
+    match element_type {
+        ElementType::UnsignedInteger => "u64",
+        ElementType::Integer | ElementType::Date => "i64",
+        ElementType::Float => "f64",
+        ElementType::String | ElementType::Utf8 => "String",
+        ElementType::Binary | ElementType::Master => "Vec<u8>",
+    }
+}
+
+fn const_type(element_type: ElementType) -> &'static str {
+    match element_type {
+        ElementType::String | ElementType::Utf8 => "&str",
+        other => value_type(other),
+    }
+}
+
+fn default_literal(element: &SchemaElement) -> Option<String> {
+    element
+        .default
+        .as_deref()
+        .and_then(|value| default(element, value))
+}
+
+/// The Rust literal for a `default` attribute, `None` for types or values that can't
+/// be represented as a constant
+fn default(element: &SchemaElement, value: &str) -> Option<String> {
+    match element.element_type {
+        ElementType::UnsignedInteger => match Number::parse(value).ok()? {
+            Number::Integer(v) => u64::try_from(v).ok().map(|v| v.to_string()),
+            Number::Float(_) => None,
+        },
+        ElementType::Integer => match Number::parse(value).ok()? {
+            Number::Integer(v) => i64::try_from(v).ok().map(|v| v.to_string()),
+            Number::Float(_) => None,
+        },
+        ElementType::Float => {
+            let v = Number::parse(value).ok()?.as_f64();
+            Some(if v.is_nan() {
+                "f64::NAN".to_string()
+            } else if v.is_infinite() {
+                if v > 0.0 {
+                    "f64::INFINITY"
+                } else {
+                    "f64::NEG_INFINITY"
+                }
+                .to_string()
+            } else {
+                format!("{:?}", v)
+            })
+        }
+        ElementType::String | ElementType::Utf8 => Some(format!("{:?}", value)),
+        ElementType::Date | ElementType::Binary | ElementType::Master => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::tests::SAMPLE;
+    use crate::{EbmlDocument, EbmlNode};
+
+    #[allow(dead_code)]
+    mod sample {
+        include!("testdata/sample.rs");
+    }
+
+    #[test]
+    fn test_names() {
+        assert_eq!(type_name("CRC-32"), "CRC32");
+        assert_eq!(type_name("EBMLMaxIDLength"), "EBMLMaxIDLength");
+        assert_eq!(field_name("EBMLMaxIDLength"), "ebml_max_id_length");
+        assert_eq!(field_name("TimestampScale"), "timestamp_scale");
+        assert_eq!(field_name("CRC-32"), "crc_32");
+        assert_eq!(field_name("ChapLanguageIETF"), "chap_language_ietf");
+        assert_eq!(field_name("Type"), "type_");
+        assert_eq!(const_name("Type"), "TYPE");
+        assert_eq!(const_name("CRC-32"), "CRC_32");
+    }
+
+    #[test]
+    fn test_generated_is_current() {
+        let schema = EbmlSchema::from_xml(SAMPLE).unwrap();
+        let code = Generator::new(&schema).with_crate_path("crate").generate();
+        assert_eq!(code, include_str!("testdata/sample.rs"));
+    }
+
+    #[test]
+    fn test_ids() {
+        let mut schema = EbmlSchema::from_xml(SAMPLE).unwrap();
+        let mut title = schema.element(0x7BA9).unwrap().clone();
+        title.id = 0x7BAA;
+        schema.insert(title);
+        let code = Generator::new(&schema).generate_ids();
+        assert!(code.contains(
+            "/// `\\Segment\\Info\\TimestampScale`\npub const TIMESTAMP_SCALE: u64 = 0x2AD7B1;\n"
+        ));
+        assert!(code.contains("pub const TITLE_7BA9: u64 = 0x7BA9;\n"));
+        assert!(code.contains("pub const TITLE_7BAA: u64 = 0x7BAA;\n"));
+    }
+
+    #[test]
+    fn test_recursive_single() {
+        let mut schema = EbmlSchema::from_xml(SAMPLE).unwrap();
+        let mut simple_tag = schema.element(0x67C8).unwrap().clone();
+        simple_tag.max_occurs = Some(1);
+        schema.insert(simple_tag);
+        let code = Generator::new(&schema).generate();
+        assert!(code.contains("    pub simple_tag: Option<Box<SimpleTag>>,\n"));
+        assert!(code.contains(
+            "SimpleTag::ID => value.simple_tag = Some(Box::new(SimpleTag::from_node(child)?)),"
+        ));
+        // Only the nested SimpleTag is boxed, the one in Tag keeps its plain type
+        assert_eq!(code.matches("Option<Box<").count(), 1);
+        assert!(code.contains("    pub simple_tag: Option<SimpleTag>,\n"));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        use sample::{Info, Segment, SimpleTag, Tag, TagName, Tags, TimestampScale, Title};
+
+        let tag_name = |name: &str| Some(TagName::new(name));
+        let segment = Segment {
+            info: Some(Info {
+                title: Some(Title::new("title")),
+                ..Default::default()
+            }),
+            tags: vec![Tags {
+                tag: vec![Tag {
+                    simple_tag: vec![SimpleTag {
+                        tag_name: tag_name("outer"),
+                        simple_tag: vec![SimpleTag {
+                            tag_name: tag_name("inner"),
+                            ..Default::default()
+                        }],
+                        ..Default::default()
+                    }],
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        let schema = EbmlSchema::from_xml(SAMPLE).unwrap();
+        let mut node = segment.to_node();
+        node.push_child(EbmlNode::binary(0xEC, vec![0; 2])).unwrap();
+        node.push_child(EbmlNode::binary(0x4242, vec![1])).unwrap();
+        let data = EbmlDocument::new(vec![node]).to_bytes().unwrap();
+        let doc = EbmlDocument::parse(&data[..], &schema).unwrap();
+
+        let parsed = Segment::from_node(&doc.children[0]).unwrap();
+        assert_eq!(parsed.info, segment.info);
+        assert_eq!(parsed.tags, segment.tags);
+        // The Void is dropped, the unknown element is kept
+        assert_eq!(parsed.unknown.len(), 1);
+        assert_eq!(parsed.unknown[0].id, 0x4242);
+
+        // Empty elements decode to their default
+        let scale = TimestampScale::from_node(&EbmlNode::binary(0x2AD7B1, vec![])).unwrap();
+        assert_eq!(scale.value, TimestampScale::DEFAULT);
+        assert!(matches!(
+            Info::from_node(&doc.children[0]),
+            Err(EbmlError::UnexpectedElement(Info::ID, Segment::ID))
+        ));
+    }
+}
@@ -0,0 +1,406 @@
+// Generated by rebml::codegen from the "sample" schema version 2, do not edit
+
+/// The root element.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Segment {
+    pub info: Option<Info>,
+    pub tags: Vec<Tags>,
+    /// Children the schema doesn't define for this element
+    pub unknown: Vec<crate::EbmlNode<'static>>,
+}
+
+impl Segment {
+    pub const ID: u64 = 0x18538067;
+    pub const NAME: &str = "Segment";
+    pub const PATH: &str = "\\Segment";
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        if !node.is_master() {
+            return Err(crate::EbmlError::NotMasterElement(node.id));
+        }
+        let mut value = Self::default();
+        for child in node.children() {
+            match child.id {
+                Info::ID => value.info = Some(Info::from_node(child)?),
+                Tags::ID => value.tags.push(Tags::from_node(child)?),
+                0xBF | 0xEC => {}
+                _ => value.unknown.push(child.clone().into_owned()),
+            }
+        }
+        Ok(value)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        let mut children = Vec::new();
+        if let Some(child) = &self.info {
+            children.push(child.to_node());
+        }
+        for child in &self.tags {
+            children.push(child.to_node());
+        }
+        children.extend(self.unknown.iter().cloned());
+        crate::EbmlNode::master(Self::ID, children)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Info {
+    pub timestamp_scale: Option<TimestampScale>,
+    pub title: Option<Title>,
+    pub duration: Option<Duration>,
+    pub flag_interlaced: Vec<FlagInterlaced>,
+    /// Children the schema doesn't define for this element
+    pub unknown: Vec<crate::EbmlNode<'static>>,
+}
+
+impl Info {
+    pub const ID: u64 = 0x1549A966;
+    pub const NAME: &str = "Info";
+    pub const PATH: &str = "\\Segment\\Info";
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        if !node.is_master() {
+            return Err(crate::EbmlError::NotMasterElement(node.id));
+        }
+        let mut value = Self::default();
+        for child in node.children() {
+            match child.id {
+                TimestampScale::ID => value.timestamp_scale = Some(TimestampScale::from_node(child)?),
+                Title::ID => value.title = Some(Title::from_node(child)?),
+                Duration::ID => value.duration = Some(Duration::from_node(child)?),
+                FlagInterlaced::ID => value.flag_interlaced.push(FlagInterlaced::from_node(child)?),
+                0xBF | 0xEC => {}
+                _ => value.unknown.push(child.clone().into_owned()),
+            }
+        }
+        Ok(value)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        let mut children = Vec::new();
+        if let Some(child) = &self.timestamp_scale {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.title {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.duration {
+            children.push(child.to_node());
+        }
+        for child in &self.flag_interlaced {
+            children.push(child.to_node());
+        }
+        children.extend(self.unknown.iter().cloned());
+        crate::EbmlNode::master(Self::ID, children)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimestampScale {
+    pub value: u64,
+}
+
+impl TimestampScale {
+    pub const ID: u64 = 0x2AD7B1;
+    pub const NAME: &str = "TimestampScale";
+    pub const PATH: &str = "\\Segment\\Info\\TimestampScale";
+    pub const DEFAULT: u64 = 1000000;
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data, an empty element stands for `DEFAULT`
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        if data.is_empty() {
+            return Ok(Self::new(Self::DEFAULT));
+        }
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Title {
+    pub value: String,
+}
+
+impl Title {
+    pub const ID: u64 = 0x7BA9;
+    pub const NAME: &str = "Title";
+    pub const PATH: &str = "\\Segment\\Info\\Title";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: impl Into<String>) -> Self {
+        Self { value: value.into() }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUtf8::new(data)?.as_str()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::string(Self::ID, &self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Duration {
+    pub value: f64,
+}
+
+impl Duration {
+    pub const ID: u64 = 0x4489;
+    pub const NAME: &str = "Duration";
+    pub const PATH: &str = "\\Segment\\Info\\Duration";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: f64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlFloat::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::float(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Tags {
+    pub tag: Vec<Tag>,
+    /// Children the schema doesn't define for this element
+    pub unknown: Vec<crate::EbmlNode<'static>>,
+}
+
+impl Tags {
+    pub const ID: u64 = 0x1254C367;
+    pub const NAME: &str = "Tags";
+    pub const PATH: &str = "\\Segment\\Tags";
+    pub const MIN_OCCURS: u64 = 0;
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        if !node.is_master() {
+            return Err(crate::EbmlError::NotMasterElement(node.id));
+        }
+        let mut value = Self::default();
+        for child in node.children() {
+            match child.id {
+                Tag::ID => value.tag.push(Tag::from_node(child)?),
+                0xBF | 0xEC => {}
+                _ => value.unknown.push(child.clone().into_owned()),
+            }
+        }
+        Ok(value)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        let mut children = Vec::new();
+        for child in &self.tag {
+            children.push(child.to_node());
+        }
+        children.extend(self.unknown.iter().cloned());
+        crate::EbmlNode::master(Self::ID, children)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Tag {
+    pub simple_tag: Vec<SimpleTag>,
+    /// Children the schema doesn't define for this element
+    pub unknown: Vec<crate::EbmlNode<'static>>,
+}
+
+impl Tag {
+    pub const ID: u64 = 0x7373;
+    pub const NAME: &str = "Tag";
+    pub const PATH: &str = "\\Segment\\Tags\\Tag";
+    pub const MIN_OCCURS: u64 = 1;
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        if !node.is_master() {
+            return Err(crate::EbmlError::NotMasterElement(node.id));
+        }
+        let mut value = Self::default();
+        for child in node.children() {
+            match child.id {
+                SimpleTag::ID => value.simple_tag.push(SimpleTag::from_node(child)?),
+                0xBF | 0xEC => {}
+                _ => value.unknown.push(child.clone().into_owned()),
+            }
+        }
+        Ok(value)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        let mut children = Vec::new();
+        for child in &self.simple_tag {
+            children.push(child.to_node());
+        }
+        children.extend(self.unknown.iter().cloned());
+        crate::EbmlNode::master(Self::ID, children)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SimpleTag {
+    pub simple_tag: Vec<SimpleTag>,
+    pub tag_name: Option<TagName>,
+    /// Children the schema doesn't define for this element
+    pub unknown: Vec<crate::EbmlNode<'static>>,
+}
+
+impl SimpleTag {
+    pub const ID: u64 = 0x67C8;
+    pub const NAME: &str = "SimpleTag";
+    pub const PATH: &str = "\\Segment\\Tags\\Tag\\+SimpleTag";
+    pub const MIN_OCCURS: u64 = 1;
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        if !node.is_master() {
+            return Err(crate::EbmlError::NotMasterElement(node.id));
+        }
+        let mut value = Self::default();
+        for child in node.children() {
+            match child.id {
+                SimpleTag::ID => value.simple_tag.push(SimpleTag::from_node(child)?),
+                TagName::ID => value.tag_name = Some(TagName::from_node(child)?),
+                0xBF | 0xEC => {}
+                _ => value.unknown.push(child.clone().into_owned()),
+            }
+        }
+        Ok(value)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        let mut children = Vec::new();
+        for child in &self.simple_tag {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.tag_name {
+            children.push(child.to_node());
+        }
+        children.extend(self.unknown.iter().cloned());
+        crate::EbmlNode::master(Self::ID, children)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TagName {
+    pub value: String,
+}
+
+impl TagName {
+    pub const ID: u64 = 0x45A3;
+    pub const NAME: &str = "TagName";
+    pub const PATH: &str = "\\Segment\\Tags\\Tag\\+SimpleTag\\TagName";
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: impl Into<String>) -> Self {
+        Self { value: value.into() }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUtf8::new(data)?.as_str()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::string(Self::ID, &self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FlagInterlaced {
+    pub value: u64,
+}
+
+impl FlagInterlaced {
+    pub const ID: u64 = 0x9A;
+    pub const NAME: &str = "FlagInterlaced";
+    pub const PATH: &str = "\\Segment\\Info\\FlagInterlaced";
+    pub const DEFAULT: u64 = 0;
+    pub const MIN_OCCURS: u64 = 0;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data, an empty element stands for `DEFAULT`
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        if data.is_empty() {
+            return Ok(Self::new(Self::DEFAULT));
+        }
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
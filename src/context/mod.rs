@@ -0,0 +1,128 @@
+use crate::crc::crc32;
+use crate::{EbmlError, ParseLimits};
+
+/// How rule violations found in otherwise readable data are handled
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Strictness {
+    /// Every violation is an error, use this to validate your own output
+    #[default]
+    Strict,
+    /// Violations are recorded as warnings and reading continues, use this for files
+    /// written by other muxers
+    Lenient,
+}
+
+/// Whether `CRC-32` elements are checked against the data they cover
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CrcVerification {
+    /// CRC-32 elements are read like any other element
+    #[default]
+    Ignore,
+    /// A mismatch is a violation, reported according to the strictness
+    Verify,
+}
+
+/// A rule violation that was accepted while reading leniently
+#[derive(Debug)]
+pub struct EbmlWarning {
+    /// Position of the offending element or value
+    pub offset: u64,
+    /// The error strict mode would have returned
+    pub error: EbmlError,
+}
+
+/// Settings for a read along with the warnings it collected
+#[derive(Debug, Default)]
+pub struct ParseContext {
+    pub limits: ParseLimits,
+    pub strictness: Strictness,
+    pub crc: CrcVerification,
+    /// Keep `Void` elements in parsed trees instead of skipping them, set it when a
+    /// parsed tree is written back and its padding should stay
+    pub keep_void: bool,
+    /// Accept the all zero element ID `0x80` without a violation. Schemas defining it,
+    /// like Matroska for `ChapterDisplay`, are accepted without this.
+    pub allow_zero_id: bool,
+    warnings: Vec<EbmlWarning>,
+}
+
+impl ParseContext {
+    pub fn new(limits: ParseLimits, strictness: Strictness) -> Self {
+        Self {
+            limits,
+            strictness,
+            crc: CrcVerification::Ignore,
+            keep_void: false,
+            allow_zero_id: false,
+            warnings: Vec::new(),
+        }
+    }
+
+    pub fn strict() -> Self {
+        Self::new(ParseLimits::default(), Strictness::Strict)
+    }
+
+    pub fn lenient() -> Self {
+        Self::new(ParseLimits::default(), Strictness::Lenient)
+    }
+
+    /// Reports a violation: fails in strict mode, records a warning in lenient mode
+    pub fn violation(&mut self, offset: u64, error: EbmlError) -> Result<(), EbmlError> {
+        match self.strictness {
+            Strictness::Strict => Err(error),
+            Strictness::Lenient => {
+                self.warnings.push(EbmlWarning { offset, error });
+                Ok(())
+            }
+        }
+    }
+
+    /// Checks the CRC-32 element at `offset` against the rest of its parent's `data`
+    /// when verification is enabled. `first` tells whether it's the parent's first child
+    /// as required.
+    pub(crate) fn check_crc(
+        &mut self,
+        parent_id: u64,
+        offset: u64,
+        first: bool,
+        stored: &[u8],
+        data: &[u8],
+    ) -> Result<(), EbmlError> {
+        if self.crc == CrcVerification::Ignore {
+            return Ok(());
+        }
+        if !first {
+            return self.violation(
+                offset,
+                EbmlError::InvalidElement(format!(
+                    "CRC-32 must be the first child of {:X}",
+                    parent_id
+                )),
+            );
+        }
+        let stored = match <[u8; 4]>::try_from(stored) {
+            Ok(stored) => u32::from_le_bytes(stored),
+            Err(_) => {
+                return self.violation(offset, EbmlError::InvalidDataLength(stored.len(), "CRC-32"))
+            }
+        };
+        let computed = crc32(data);
+        if stored != computed {
+            self.violation(offset, EbmlError::CrcMismatch(parent_id, stored, computed))?;
+        }
+        Ok(())
+    }
+
+    pub fn warnings(&self) -> &[EbmlWarning] {
+        &self.warnings
+    }
+
+    pub fn take_warnings(&mut self) -> Vec<EbmlWarning> {
+        std::mem::take(&mut self.warnings)
+    }
+
+    /// Drops warnings recorded after the first `len`, used to discard failed attempts
+    pub(crate) fn truncate_warnings(&mut self, len: usize) {
+        self.warnings.truncate(len);
+    }
+}
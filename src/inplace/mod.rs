@@ -0,0 +1,465 @@
+use crate::{
+    element_id_length, write_element_id, EbmlElement, EbmlError, EbmlNode, ParseLimits, VarInt,
+    VarIntLength, Void,
+};
+use memmap2::MmapMut;
+use std::io::Cursor;
+
+/// The smallest possible Void element: a one octet ID and a one octet size
+const MIN_VOID_SIZE: u64 = 2;
+
+/// The location of an element inside the buffer being edited
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ElementSpan {
+    pub id: u64,
+    /// Position of the element ID
+    pub offset: u64,
+    /// Octets used by the element ID and data size
+    pub header_length: u64,
+    pub data_size: u64,
+    /// End of the parent's data, an edit never grows past this
+    pub parent_end: u64,
+}
+
+impl ElementSpan {
+    pub fn data_start(&self) -> u64 {
+        self.offset + self.header_length
+    }
+
+    pub fn end(&self) -> u64 {
+        self.data_start() + self.data_size
+    }
+}
+
+/// Edits elements of a writable buffer (usually a `MmapMut`) without moving any data
+/// outside of the edited element.
+///
+/// Growing an element consumes the `Void` elements directly after it, shrinking an
+/// element leaves a `Void` behind, so none of the ancestors' sizes change.
+pub struct InPlaceEditor<'m> {
+    data: &'m mut [u8],
+    limits: ParseLimits,
+}
+
+impl<'m> InPlaceEditor<'m> {
+    pub fn new(data: &'m mut [u8]) -> Self {
+        Self::with_limits(data, ParseLimits::default())
+    }
+
+    /// An editor that reads elements within `limits`
+    pub fn with_limits(data: &'m mut [u8], limits: ParseLimits) -> Self {
+        Self { data, limits }
+    }
+
+    pub fn from_mmap(mmap: &'m mut MmapMut) -> Self {
+        Self::new(&mut mmap[..])
+    }
+
+    pub fn data(&self) -> &[u8] {
+        self.data
+    }
+
+    /// Reads the element starting at `offset` inside a parent whose data ends at `parent_end`,
+    /// which is clamped to the end of the buffer
+    pub fn element_at(&self, offset: u64, parent_end: u64) -> Result<ElementSpan, EbmlError> {
+        let parent_end = parent_end.min(self.data.len() as u64);
+        let mut cursor = Cursor::new(&self.data[..]);
+        cursor.set_position(offset);
+        let element = EbmlElement::try_from(&mut cursor)?;
+        let data_start = cursor.position();
+        let data_size = if element.size.all_ones() {
+            parent_end.saturating_sub(data_start)
+        } else {
+            element.size.value
+        };
+        self.limits.check_element_size(element.id, data_size)?;
+
+        let span = ElementSpan {
+            id: element.id,
+            offset,
+            header_length: element.length,
+            data_size,
+            parent_end,
+        };
+        if data_start
+            .checked_add(data_size)
+            .filter(|end| *end <= parent_end)
+            .is_none()
+        {
+            return Err(EbmlError::InvalidElement(format!(
+                "Element {:X} at {} is larger than its parent",
+                span.id, offset
+            )));
+        }
+        Ok(span)
+    }
+
+    /// Checks that `span` lies inside the buffer and matches the header at its offset
+    fn check_span(&self, span: &ElementSpan) -> Result<(), EbmlError> {
+        let invalid = || {
+            EbmlError::InvalidElement(format!(
+                "Span of {:X} at {} doesn't match the buffer",
+                span.id, span.offset
+            ))
+        };
+        let end = span
+            .offset
+            .checked_add(span.header_length)
+            .and_then(|start| start.checked_add(span.data_size))
+            .ok_or_else(invalid)?;
+        if end > span.parent_end || span.parent_end > self.data.len() as u64 {
+            return Err(invalid());
+        }
+
+        let mut cursor = Cursor::new(&self.data[..]);
+        cursor.set_position(span.offset);
+        let element = EbmlElement::try_from(&mut cursor)?;
+        if element.id != span.id || element.length != span.header_length {
+            return Err(invalid());
+        }
+        // A stale span, taken before an earlier edit, no longer matches the size field
+        let sized = if element.size.all_ones() {
+            end == span.parent_end
+        } else {
+            element.size.value == span.data_size
+        };
+        if !sized {
+            return Err(invalid());
+        }
+        Ok(())
+    }
+
+    /// Finds an element by following the IDs in `path` from the start of the buffer
+    pub fn locate(&self, path: &[u64]) -> Result<Option<ElementSpan>, EbmlError> {
+        let mut start = 0;
+        let mut end = self.data.len() as u64;
+        let mut found: Option<ElementSpan> = None;
+
+        for (depth, id) in path.iter().enumerate() {
+            self.limits.check_depth(depth)?;
+            let mut offset = start;
+            let parent = found.map(|span| span.id);
+            let mut count = 0;
+            found = None;
+            while offset < end {
+                if let Some(parent) = parent {
+                    count += 1;
+                    self.limits.check_children(parent, count)?;
+                }
+                let span = self.element_at(offset, end)?;
+                if span.id == *id {
+                    found = Some(span);
+                    break;
+                }
+                offset = span.end();
+            }
+
+            match found {
+                Some(span) => {
+                    start = span.data_start();
+                    end = span.end();
+                }
+                None => return Ok(None),
+            }
+        }
+
+        Ok(found)
+    }
+
+    /// The number of octets the element at `span` may occupy, including the `Void`
+    /// elements directly after it
+    pub fn available_space(&self, span: &ElementSpan) -> Result<u64, EbmlError> {
+        Ok(self.available_end(span)? - span.offset)
+    }
+
+    /// Replaces the data of the element at `span`, keeping its ID
+    pub fn replace_data(
+        &mut self,
+        span: &ElementSpan,
+        data: &[u8],
+    ) -> Result<ElementSpan, EbmlError> {
+        self.write_in_place(span, span.id, data)
+    }
+
+    /// Replaces the element at `span` with `node`, which may have a different ID.
+    ///
+    /// Writing over a `Void` span is how a new element is added without remuxing.
+    pub fn replace_element(
+        &mut self,
+        span: &ElementSpan,
+        node: &EbmlNode,
+    ) -> Result<ElementSpan, EbmlError> {
+        let mut data = Vec::with_capacity(node.data_size() as usize);
+        node.write_data(&mut data)?;
+        self.write_in_place(span, node.id, &data)
+    }
+
+    fn available_end(&self, span: &ElementSpan) -> Result<u64, EbmlError> {
+        self.check_span(span)?;
+        let mut end = span.end();
+        let mut count = 0;
+        while end < span.parent_end {
+            count += 1;
+            self.limits.check_children(span.id, count)?;
+            let next = self.element_at(end, span.parent_end)?;
+            if next.id != Void::ID {
+                break;
+            }
+            end = next.end();
+        }
+        Ok(end)
+    }
+
+    fn write_in_place(
+        &mut self,
+        span: &ElementSpan,
+        id: u64,
+        data: &[u8],
+    ) -> Result<ElementSpan, EbmlError> {
+        let available_end = self.available_end(span)?;
+        let available = available_end - span.offset;
+
+        // Keep the width of the original size where possible
+        let original_id_length = element_id_length(span.id) as u64;
+        let original_size_length =
+            VarIntLength::new((span.header_length - original_id_length) as usize)?;
+        let data_size = data.len() as u64;
+        let mut size = VarInt::with_length(data_size, original_size_length)
+            .or_else(|_| VarInt::new(data_size))?;
+
+        let id_length = element_id_length(id) as u64;
+        let needed = id_length + size.length.num_bytes() as u64 + data_size;
+        if needed > available {
+            return Err(EbmlError::NotEnoughSpace(needed, available));
+        }
+
+        let mut leftover = available - needed;
+        if leftover > 0 && leftover < MIN_VOID_SIZE {
+            // Too small for a Void, absorb it with a wider size instead
+            let wider = VarIntLength::new(size.length.num_bytes() + leftover as usize)
+                .map_err(|_| EbmlError::NotEnoughSpace(available + MIN_VOID_SIZE, available))?;
+            size = VarInt::with_length(data_size, wider)?;
+            leftover = 0;
+        }
+
+        let mut header = Vec::with_capacity(16);
+        write_element_id(id, &mut header)?;
+        size.write(&mut header)?;
+        let mut void = Vec::with_capacity(leftover as usize);
+        if leftover > 0 {
+            write_void(leftover, &mut void)?;
+        }
+
+        let start = span.offset as usize;
+        let data_start = start + header.len();
+        let data_end = data_start + data.len();
+        self.data[start..data_start].copy_from_slice(&header);
+        self.data[data_start..data_end].copy_from_slice(data);
+        self.data[data_end..available_end as usize].copy_from_slice(&void);
+
+        Ok(ElementSpan {
+            id,
+            offset: span.offset,
+            header_length: header.len() as u64,
+            data_size,
+            parent_end: span.parent_end,
+        })
+    }
+}
+
+/// Writes a zero filled `Void` element occupying exactly `total` octets
+pub(crate) fn write_void(total: u64, out: &mut Vec<u8>) -> Result<(), EbmlError> {
+    for num_bytes in 1..=8 {
+        let header_length = 1 + num_bytes as u64;
+        if total < header_length {
+            break;
+        }
+        if let Ok(size) = VarInt::with_length(total - header_length, VarIntLength::new(num_bytes)?)
+        {
+            write_element_id(Void::ID, out)?;
+            size.write(out)?;
+            out.resize(total as usize, 0);
+            return Ok(());
+        }
+    }
+    Err(EbmlError::NotEnoughSpace(MIN_VOID_SIZE, total))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{EbmlDocument, ParseContext};
+
+    const INFO: u64 = 0x1549A966;
+    const TITLE: u64 = 0x7BA9;
+
+    fn kinds(id: u64) -> bool {
+        id == INFO
+    }
+
+    fn info(children: &[u8]) -> Vec<u8> {
+        let mut data = vec![0x15, 0x49, 0xA9, 0x66, 0x80 | children.len() as u8];
+        data.extend_from_slice(children);
+        data
+    }
+
+    #[test]
+    fn test_grow_into_void() {
+        let mut data = info(&[
+            0x7B, 0xA9, 0x82, b'a', b'b', // Title
+            0xEC, 0x84, 0, 0, 0, 0, // Void
+        ]);
+        let mut mmap = MmapMut::map_anon(data.len()).unwrap();
+        mmap.copy_from_slice(&data);
+
+        let mut editor = InPlaceEditor::from_mmap(&mut mmap);
+        let span = editor.locate(&[INFO, TITLE]).unwrap().unwrap();
+        assert_eq!(editor.available_space(&span).unwrap(), 11);
+        editor.replace_data(&span, b"abcdef").unwrap();
+
+        data.copy_from_slice(&mmap);
+        let mut context = ParseContext::strict();
+        context.keep_void = true;
+        let doc = EbmlDocument::parse_with(&data[..], &kinds, &mut context).unwrap();
+        let info = doc.find(&[INFO]).unwrap();
+        assert_eq!(info.children()[0].data(), Some(&b"abcdef"[..]));
+        assert_eq!(info.children()[1].id, Void::ID);
+        assert_eq!(info.children()[1].data_size(), 0);
+    }
+
+    #[test]
+    fn test_shrink_leaves_void() {
+        let mut data = info(&[0x7B, 0xA9, 0x86, b'a', b'b', b'c', b'd', b'e', b'f']);
+        let mut editor = InPlaceEditor::new(&mut data);
+        let span = editor.locate(&[INFO, TITLE]).unwrap().unwrap();
+        editor.replace_data(&span, b"ab").unwrap();
+
+        assert_eq!(
+            &data[5..],
+            &[0x7B, 0xA9, 0x82, b'a', b'b', 0xEC, 0x82, 0, 0]
+        );
+    }
+
+    #[test]
+    fn test_single_octet_absorbed_by_size() {
+        let mut data = info(&[0x7B, 0xA9, 0x83, b'a', b'b', b'c']);
+        let mut editor = InPlaceEditor::new(&mut data);
+        let span = editor.locate(&[INFO, TITLE]).unwrap().unwrap();
+        let span = editor.replace_data(&span, b"ab").unwrap();
+
+        assert_eq!(span.header_length, 4);
+        assert_eq!(&data[5..], &[0x7B, 0xA9, 0x40, 0x02, b'a', b'b']);
+    }
+
+    #[test]
+    fn test_new_element_in_void() {
+        let mut data = info(&[0xEC, 0x86, 0, 0, 0, 0, 0, 0]);
+        let mut editor = InPlaceEditor::new(&mut data);
+        let span = editor.locate(&[INFO, Void::ID]).unwrap().unwrap();
+        editor
+            .replace_element(&span, &EbmlNode::string(TITLE, "abc"))
+            .unwrap();
+
+        assert_eq!(
+            &data[5..],
+            &[0x7B, 0xA9, 0x83, b'a', b'b', b'c', 0xEC, 0x80]
+        );
+    }
+
+    #[test]
+    fn test_not_enough_space() {
+        // The Void after Info belongs to the top level and must not be consumed
+        let mut data = info(&[0x7B, 0xA9, 0x81, b'a']);
+        data.extend_from_slice(&[0xEC, 0x84, 0, 0, 0, 0]);
+        let original = data.clone();
+
+        let mut editor = InPlaceEditor::new(&mut data);
+        let span = editor.locate(&[INFO, TITLE]).unwrap().unwrap();
+        match editor.replace_data(&span, b"abc") {
+            Err(EbmlError::NotEnoughSpace(6, 4)) => {}
+            other => panic!("Incorrect result: {:?}", other),
+        }
+        assert_eq!(data, original);
+    }
+
+    #[test]
+    fn test_invalid_span() {
+        let mut data = vec![0x15, 0x49, 0xA9, 0x66, 0xFF, 0x7B, 0xA9, 0x81, b'a'];
+        let mut editor = InPlaceEditor::new(&mut data);
+        // The unknown size ends at the end of the buffer
+        let span = editor.element_at(0, u64::MAX).unwrap();
+        assert_eq!(span.parent_end, 9);
+        assert_eq!(span.end(), 9);
+
+        let forged = [
+            ElementSpan {
+                data_size: 100,
+                parent_end: 200,
+                ..span
+            },
+            ElementSpan {
+                header_length: 1,
+                ..span
+            },
+            ElementSpan {
+                offset: u64::MAX,
+                ..span
+            },
+            ElementSpan {
+                data_size: 3,
+                ..span
+            },
+        ];
+        for span in forged {
+            assert!(editor.replace_data(&span, b"a").is_err());
+        }
+
+        editor.replace_data(&span, b"").unwrap();
+
+        // A span taken before the title grew, its old end now parses as a Void
+        let mut data = info(&[0x7B, 0xA9, 0x83, b'a', 0xEC, 0x80]);
+        let mut editor = InPlaceEditor::new(&mut data);
+        let title = editor.locate(&[INFO, TITLE]).unwrap().unwrap();
+        let stale = ElementSpan {
+            data_size: 1,
+            ..title
+        };
+        assert!(editor.replace_data(&stale, b"xy").is_err());
+        editor.replace_data(&title, b"xy").unwrap();
+    }
+
+    #[test]
+    fn test_limits() {
+        let mut data = info(&[0x7B, 0xA9, 0x81, b'a', 0xEC, 0x80, 0xEC, 0x80]);
+        let limits = ParseLimits {
+            max_element_size: 4,
+            ..Default::default()
+        };
+        let editor = InPlaceEditor::with_limits(&mut data, limits);
+        match editor.element_at(0, u64::MAX) {
+            Err(EbmlError::ElementTooLarge(INFO, 8, 4)) => {}
+            other => panic!("Incorrect result: {:?}", other),
+        }
+
+        let limits = ParseLimits {
+            max_depth: 0,
+            ..Default::default()
+        };
+        let editor = InPlaceEditor::with_limits(&mut data, limits);
+        assert!(matches!(
+            editor.locate(&[INFO, TITLE]),
+            Err(EbmlError::MaxDepthExceeded(0))
+        ));
+
+        let limits = ParseLimits {
+            max_children: 1,
+            ..Default::default()
+        };
+        let editor = InPlaceEditor::with_limits(&mut data, limits);
+        let span = editor.locate(&[INFO, TITLE]).unwrap().unwrap();
+        assert!(matches!(
+            editor.available_space(&span),
+            Err(EbmlError::TooManyChildren(TITLE, 1))
+        ));
+    }
+}
@@ -1,8 +1,24 @@
+pub mod codegen;
+pub mod context;
+pub mod crc;
+pub mod inplace;
+pub mod limits;
+#[cfg(feature = "matroska")]
+pub mod matroska;
+pub mod recovery;
+pub mod schema;
+pub mod tree;
 #[allow(unused)]
 mod types;
-use std::io::{Cursor, Seek};
+use std::io::{Cursor, Seek, Write};
 use thiserror::Error;
 
+pub use context::{CrcVerification, EbmlWarning, ParseContext, Strictness};
+pub use inplace::{ElementSpan, InPlaceEditor};
+pub use limits::ParseLimits;
+pub use recovery::{Recovered, Resync, SkippedRange};
+pub use schema::{EbmlSchema, EbmlValue, ElementType, SchemaElement, SchemaRegistry};
+pub use tree::{EbmlBody, EbmlDocument, EbmlNode, ElementKinds};
 pub use types::*;
 
 #[derive(Error, Debug)]
@@ -39,29 +55,98 @@ pub enum EbmlError {
     OverMaximumSize(usize),
     #[error("Couldn't Seek")]
     CouldntSeek,
+    #[error("Element {0:X} is not a master element")]
+    NotMasterElement(u64),
+    #[error("Element {0:X} is a master element")]
+    IsMasterElement(u64),
+    #[error("Child index {0} is out of bounds")]
+    ChildOutOfBounds(usize),
+    #[error("Elements are nested deeper than the limit of {0}")]
+    MaxDepthExceeded(usize),
+    #[error("Element {0:X} has a size of {1} which is over the limit of {2}")]
+    ElementTooLarge(u64, u64, u64),
+    #[error("Master element {0:X} has more children than the limit of {1}")]
+    TooManyChildren(u64, usize),
+    #[error("String of {0} octets is over the limit of {1}")]
+    StringTooLong(usize, usize),
+    #[error("Invalid data length {0} for a {1} element")]
+    InvalidDataLength(usize, &'static str),
+    #[error("Invalid schema: {0}")]
+    InvalidSchema(String),
+    #[error("Not enough space to edit in place, needed {0} octets but only {1} are available")]
+    NotEnoughSpace(u64, u64),
+    #[error("Expected element {0:X} but found {1:X}")]
+    UnexpectedElement(u64, u64),
+    #[error("CRC-32 of element {0:X} is {1:08X} but the data has {2:08X}")]
+    CrcMismatch(u64, u32, u32),
+    #[error("No schema is registered for DocType {0:?}")]
+    UnknownDocType(String),
+    #[error("Reading {0} needs version {1} but only version {2} is supported")]
+    UnsupportedReadVersion(String, u64, u64),
+    #[error("{0} of {1} in the EBML header is out of range")]
+    InvalidHeaderValue(&'static str, u64),
+    #[error("Invalid block: {0}")]
+    InvalidBlock(&'static str),
+    #[error("Mandatory element {0} is missing")]
+    MissingElement(&'static str),
 }
 
 #[inline]
 pub fn get_element_id(cursor: &mut Cursor<&[u8]>) -> Result<u64, EbmlError> {
+    read_element_id(cursor, &mut ParseContext::strict())
+}
+
+/// Reads an element ID, IDs longer than needed are reported to the context instead of
+/// always failing
+#[inline]
+pub fn read_element_id(
+    cursor: &mut Cursor<&[u8]>,
+    context: &mut ParseContext,
+) -> Result<u64, EbmlError> {
+    let allow_zero_id = context.allow_zero_id;
+    read_id(cursor, context, allow_zero_id)
+}
+
+#[inline]
+pub(crate) fn read_id(
+    cursor: &mut Cursor<&[u8]>,
+    context: &mut ParseContext,
+    allow_zero_id: bool,
+) -> Result<u64, EbmlError> {
+    let start = cursor.position();
     let varint = VarInt::get_var_int(cursor)?;
     if varint.length > VarIntLength::Four {
         // TODO: Can be configured in the EBMLMaxIDLength header field
         return Err(EbmlError::InvalidElementIdSize);
     }
 
-    if varint.value == 0 {
-        return Err(EbmlError::ElementIdAllZeros);
+    if varint.value == 0 && !allow_zero_id {
+        // Reserved, unless the schema defines it like Matroska's ChapterDisplay
+        context.violation(start, EbmlError::ElementIdAllZeros)?;
     }
 
     if varint.all_ones() {
         return Err(EbmlError::ElementIdAllOnes);
     }
 
-    if varint.is_shortest_valid_element_id_length() {
-        Ok(varint.raw_value)
-    } else {
-        Err(EbmlError::ElementIdLongerThanNeeded)
+    if !varint.is_shortest_valid_element_id_length() {
+        context.violation(start, EbmlError::ElementIdLongerThanNeeded)?;
     }
+    Ok(varint.raw_value)
+}
+
+/// The number of octets an element ID occupies when encoded
+#[inline]
+pub fn element_id_length(id: u64) -> usize {
+    (8 - id.leading_zeros() as usize / 8).max(1)
+}
+
+/// Writes an element ID (including its marker bits), returning the number of octets written
+#[inline]
+pub fn write_element_id<W: Write>(id: u64, writer: &mut W) -> Result<usize, EbmlError> {
+    let length = element_id_length(id);
+    writer.write_all(&id.to_be_bytes()[8 - length..])?;
+    Ok(length)
 }
 
 #[inline]
@@ -69,7 +154,8 @@ pub fn get_data_size(cursor: &mut Cursor<&[u8]>) -> Result<VarInt, EbmlError> {
     // 1-8 unless EBMLMaxSizeLength
     VarInt::get_var_int(cursor)
     // can have all bits set to zero unless the element ID mandates otherwise
-    // if all zeros (aka empty element) and there's a default, default should be returned
+    // if all zeros (aka empty element) and there's a default, default should be returned,
+    // see EbmlSchema::value
     // if all bits are one, the size of the element is unknown
     // spec: Only a Master Element is allowed to be of unknown size, and it can only be so if the unknownsizeallowed attribute of its EBML Schema is set to true
 }
@@ -77,7 +163,10 @@ pub fn get_data_size(cursor: &mut Cursor<&[u8]>) -> Result<VarInt, EbmlError> {
 #[inline]
 pub fn get_data<'a>(size: u64, cursor: &mut Cursor<&'a [u8]>) -> Result<&'a [u8], EbmlError> {
   let start = cursor.position() as usize;
-  let end = start + size as usize;
+  let end = start.checked_add(size as usize).ok_or(EbmlError::NoData)?;
+  if end > cursor.get_ref().len() {
+      return Err(EbmlError::NoData);
+  }
   let data = &cursor.get_ref()[start..end];
   cursor
       .seek_relative(data.len() as i64)
@@ -87,6 +176,15 @@ pub fn get_data<'a>(size: u64, cursor: &mut Cursor<&'a [u8]>) -> Result<&'a [u8]
 
 
 
+/// The data from the cursor's position up to `end`, without moving the cursor
+#[inline]
+pub(crate) fn get_range<'a>(cursor: &Cursor<&'a [u8]>, end: u64) -> Result<&'a [u8], EbmlError> {
+    cursor
+        .get_ref()
+        .get(cursor.position() as usize..end as usize)
+        .ok_or(EbmlError::NoData)
+}
+
 #[cfg(test)]
 mod tests {
     use crate::VarIntLength;
@@ -147,6 +245,25 @@ mod tests {
             assert_eq!(vi.value, 2);
         }
 
+        #[test]
+        fn test_write() {
+            let vi = VarInt::new(513).unwrap();
+            let mut out = Vec::new();
+            assert_eq!(vi.write(&mut out).unwrap(), 2);
+            assert_eq!(out, [0b01000010, 0b00000001]);
+
+            let vi = VarInt::with_length(1, crate::VarIntLength::Four).unwrap();
+            let mut out = Vec::new();
+            vi.write(&mut out).unwrap();
+            let mut c = Cursor::new(&out[..]);
+            assert_eq!(VarInt::get_var_int(&mut c).unwrap(), vi);
+
+            // All ones is reserved for unknown sizes
+            assert!(VarInt::new(127).unwrap().length > crate::VarIntLength::One);
+            assert!(VarInt::with_length(127, crate::VarIntLength::One).is_err());
+            assert!(VarInt::unknown(crate::VarIntLength::One).all_ones());
+        }
+
         #[test]
         fn test_get_var_int_incorrect() {
             let data = [0b01000010];
@@ -194,6 +311,11 @@ mod tests {
                     _ => panic!("Incorrect error: {:#?}", e),
                 },
             }
+
+            let mut context = crate::ParseContext::strict();
+            context.allow_zero_id = true;
+            let mut c = Cursor::new(&data[..]);
+            assert_eq!(crate::read_element_id(&mut c, &mut context).unwrap(), 0x80);
         }
 
         #[test]
@@ -235,4 +357,197 @@ mod tests {
             }
         }
     }
-}
\ No newline at end of file
+
+    mod header {
+        use crate::{
+            Crc32, CrcVerification, DocType, DocTypeExtension, DocTypeExtensionName,
+            DocTypeExtensionVersion, Ebml, EbmlError, EbmlHeader, EbmlMaxIdLength,
+            EbmlMaxSizeLength, EbmlNode, EbmlReadVersion, ParseContext, ParseLimits, Strictness,
+            Void,
+        };
+        use std::io::Cursor;
+
+        fn header_bytes(doc_type: &str, extensions: usize) -> Vec<u8> {
+            let mut children = vec![EbmlNode::string(DocType::ID, doc_type)];
+            for _ in 0..extensions {
+                children.push(EbmlNode::master(
+                    DocTypeExtension::ID,
+                    vec![
+                        EbmlNode::string(DocTypeExtensionName::ID, "ext"),
+                        EbmlNode::unsigned(DocTypeExtensionVersion::ID, 1),
+                    ],
+                ));
+            }
+            let mut bytes = Vec::new();
+            EbmlNode::master(Ebml::ID, children).write(&mut bytes).unwrap();
+            bytes
+        }
+
+        #[test]
+        fn test_parse() {
+            let data = header_bytes("webm", 2);
+            let mut c = Cursor::new(&data[..]);
+            EbmlHeader::try_from(&mut c).unwrap();
+            assert_eq!(c.position(), data.len() as u64);
+        }
+
+        #[test]
+        fn test_limits() {
+            let data = header_bytes("webm", 10);
+            let limits = ParseLimits {
+                max_children: 4,
+                ..Default::default()
+            };
+            let mut context = ParseContext::new(limits, Strictness::Strict);
+            match EbmlHeader::parse(&mut Cursor::new(&data[..]), &mut context) {
+                Err(EbmlError::TooManyChildren(Ebml::ID, 4)) => {}
+                other => panic!("Incorrect result: {:?}", other),
+            }
+
+            // The extension's children count too
+            let mut data = Vec::new();
+            let extension = EbmlNode::master(
+                DocTypeExtension::ID,
+                vec![
+                    EbmlNode::string(DocTypeExtensionName::ID, "ext"),
+                    EbmlNode::unsigned(DocTypeExtensionVersion::ID, 1),
+                ],
+            );
+            EbmlNode::master(Ebml::ID, vec![extension]).write(&mut data).unwrap();
+            let limits = ParseLimits {
+                max_children: 1,
+                ..Default::default()
+            };
+            let mut context = ParseContext::new(limits, Strictness::Strict);
+            match EbmlHeader::parse(&mut Cursor::new(&data[..]), &mut context) {
+                Err(EbmlError::TooManyChildren(DocTypeExtension::ID, 1)) => {}
+                other => panic!("Incorrect result: {:?}", other),
+            }
+
+            let data = header_bytes("matroska", 0);
+            let limits = ParseLimits {
+                max_string_length: 4,
+                ..Default::default()
+            };
+            let mut context = ParseContext::new(limits, Strictness::Strict);
+            match EbmlHeader::parse(&mut Cursor::new(&data[..]), &mut context) {
+                Err(EbmlError::StringTooLong(8, 4)) => {}
+                other => panic!("Incorrect result: {:?}", other),
+            }
+        }
+
+        #[test]
+        fn test_oversized_extension() {
+            let mut data = header_bytes("webm", 1);
+            let header_end = data.len() as u64;
+            // The extension claims the name following the header
+            data[14] += 4;
+            data.extend_from_slice(&[0x42, 0x83, 0x81, b'x']);
+
+            let mut c = Cursor::new(&data[..]);
+            let header = EbmlHeader::try_from(&mut c).unwrap();
+            assert_eq!(c.position(), header_end);
+            assert_eq!(header.doc_type_extensions()[0].name(), "ext");
+        }
+
+        #[test]
+        fn test_lenient() {
+            let children = vec![
+                EbmlNode::binary(DocType::ID, &b"web\x01m"[..]),
+                EbmlNode::unsigned(0x4299, 1),
+            ];
+            let mut data = Vec::new();
+            EbmlNode::master(Ebml::ID, children).write(&mut data).unwrap();
+
+            match EbmlHeader::try_from(&mut Cursor::new(&data[..])) {
+                Err(EbmlError::InvalidString) => {}
+                other => panic!("Incorrect result: {:?}", other),
+            }
+
+            let mut context = ParseContext::lenient();
+            let mut c = Cursor::new(&data[..]);
+            EbmlHeader::parse(&mut c, &mut context).unwrap();
+            assert_eq!(c.position(), data.len() as u64);
+            let warnings = context.take_warnings();
+            assert_eq!(warnings.len(), 2);
+            assert!(matches!(warnings[0].error, EbmlError::InvalidString));
+            assert_eq!(warnings[0].offset, 8);
+            assert!(matches!(warnings[1].error, EbmlError::InvalidElement(_)));
+        }
+
+        #[test]
+        fn test_versions() {
+            let data = header_bytes("webm", 0);
+            let header = EbmlHeader::try_from(&mut Cursor::new(&data[..])).unwrap();
+            assert_eq!(header.doc_type(), Some("webm"));
+            assert_eq!(header.read_version(), 1);
+            assert_eq!(header.max_id_length(), 4);
+            assert_eq!(header.max_size_length(), 8);
+
+            let children = vec![
+                EbmlNode::unsigned(EbmlReadVersion::ID, 2),
+                EbmlNode::unsigned(EbmlMaxIdLength::ID, 3),
+                EbmlNode::unsigned(EbmlMaxSizeLength::ID, 9),
+                EbmlNode::string(DocType::ID, ""),
+            ];
+            let mut data = Vec::new();
+            EbmlNode::master(Ebml::ID, children).write(&mut data).unwrap();
+            match EbmlHeader::try_from(&mut Cursor::new(&data[..])) {
+                Err(EbmlError::UnsupportedReadVersion(name, 2, 1)) if name == "EBML" => {}
+                other => panic!("Incorrect result: {:?}", other),
+            }
+
+            let mut context = ParseContext::lenient();
+            let header = EbmlHeader::parse(&mut Cursor::new(&data[..]), &mut context).unwrap();
+            assert_eq!(header.read_version(), 2);
+            let warnings = context.take_warnings();
+            assert_eq!(warnings.len(), 4);
+            assert!(matches!(
+                warnings[1].error,
+                EbmlError::InvalidHeaderValue("EBMLMaxIDLength", 3)
+            ));
+            // Reported at the offending element
+            assert_eq!(warnings[1].offset, warnings[0].offset + 4);
+            assert!(matches!(
+                warnings[3].error,
+                EbmlError::InvalidHeaderValue("DocType length", 0)
+            ));
+
+            assert!(header.check_doc_type_read_version(1, &mut context).is_ok());
+            assert!(header
+                .check_doc_type_read_version(0, &mut ParseContext::strict())
+                .is_err());
+        }
+
+        #[test]
+        fn test_globals() {
+            let children = vec![
+                EbmlNode::binary(Crc32::ID, vec![0; 4]),
+                EbmlNode::string(DocType::ID, "webm"),
+                EbmlNode::binary(Void::ID, vec![0; 3]),
+            ];
+            let mut data = Vec::new();
+            EbmlNode::master(Ebml::ID, children).write(&mut data).unwrap();
+
+            let mut context = ParseContext::strict();
+            context.crc = CrcVerification::Verify;
+            let mut c = Cursor::new(&data[..]);
+            EbmlHeader::parse(&mut c, &mut context).unwrap();
+            assert_eq!(c.position(), data.len() as u64);
+
+            // Corrupt the DocType
+            data[14] = b'x';
+            match EbmlHeader::parse(&mut Cursor::new(&data[..]), &mut context) {
+                Err(EbmlError::CrcMismatch(Ebml::ID, _, _)) => {}
+                other => panic!("Incorrect result: {:?}", other),
+            }
+            // Ignored unless asked for
+            EbmlHeader::try_from(&mut Cursor::new(&data[..])).unwrap();
+
+            let mut context = ParseContext::lenient();
+            context.crc = CrcVerification::Verify;
+            EbmlHeader::parse(&mut Cursor::new(&data[..]), &mut context).unwrap();
+            assert_eq!(context.warnings().len(), 1);
+        }
+    }
+}
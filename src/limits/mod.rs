@@ -0,0 +1,73 @@
+use crate::EbmlError;
+
+/// Bounds applied while reading, so hostile input can't exhaust the stack or memory.
+///
+/// The defaults accept any well formed file seen in practice, tighten them for untrusted
+/// uploads.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseLimits {
+    /// How many master elements may be nested, top level elements are at depth 0
+    pub max_depth: usize,
+    /// Largest data size accepted for a single element, masters included. Unlimited by
+    /// default, sizes past the end of the data are rejected regardless.
+    pub max_element_size: u64,
+    /// Most children a single master element may contain
+    pub max_children: usize,
+    /// Longest string (in octets) that will be decoded
+    pub max_string_length: usize,
+}
+
+impl Default for ParseLimits {
+    fn default() -> Self {
+        Self {
+            max_depth: 64,
+            max_element_size: u64::MAX,
+            max_children: 1 << 20,
+            max_string_length: 1 << 20,
+        }
+    }
+}
+
+impl ParseLimits {
+    /// No limits at all, only use this for trusted data
+    pub fn unlimited() -> Self {
+        Self {
+            max_depth: usize::MAX,
+            max_element_size: u64::MAX,
+            max_children: usize::MAX,
+            max_string_length: usize::MAX,
+        }
+    }
+
+    #[inline]
+    pub fn check_depth(&self, depth: usize) -> Result<(), EbmlError> {
+        if depth > self.max_depth {
+            return Err(EbmlError::MaxDepthExceeded(self.max_depth));
+        }
+        Ok(())
+    }
+
+    #[inline]
+    pub fn check_element_size(&self, id: u64, size: u64) -> Result<(), EbmlError> {
+        if size > self.max_element_size {
+            return Err(EbmlError::ElementTooLarge(id, size, self.max_element_size));
+        }
+        Ok(())
+    }
+
+    #[inline]
+    pub fn check_children(&self, id: u64, count: usize) -> Result<(), EbmlError> {
+        if count > self.max_children {
+            return Err(EbmlError::TooManyChildren(id, self.max_children));
+        }
+        Ok(())
+    }
+
+    #[inline]
+    pub fn check_string_length(&self, length: usize) -> Result<(), EbmlError> {
+        if length > self.max_string_length {
+            return Err(EbmlError::StringTooLong(length, self.max_string_length));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,206 @@
+use super::segment::{remove_in_place, write_in_place};
+use super::{
+    AttachedFile, Attachments, FileData, FileDescription, FileMediaType, FileName, FileUID,
+    SegmentReader,
+};
+use crate::{element_id_length, EbmlBinary, EbmlError, EbmlNode};
+use std::io::Cursor;
+
+/// An `AttachedFile` whose data is borrowed from the buffer being read
+#[derive(Debug, Clone)]
+pub struct AttachmentView<'a> {
+    pub uid: u64,
+    pub name: String,
+    pub media_type: String,
+    pub description: Option<String>,
+    pub data: EbmlBinary<'a>,
+}
+
+impl<'a> AttachmentView<'a> {
+    /// The content of the file
+    pub fn data(&self) -> &'a [u8] {
+        self.data.data()
+    }
+
+    /// An `AttachedFile` holding a copy of the data
+    pub fn to_attached_file(&self) -> AttachedFile {
+        AttachedFile {
+            file_description: self.description.as_deref().map(FileDescription::new),
+            file_name: Some(FileName::new(self.name.as_str())),
+            file_media_type: Some(FileMediaType::new(self.media_type.as_str())),
+            file_data: Some(FileData::new(self.data().to_vec())),
+            file_uid: Some(FileUID::new(self.uid)),
+            ..Default::default()
+        }
+    }
+}
+
+impl<'a> SegmentReader<'a> {
+    /// Lists the files attached to the Segment without copying their data
+    pub fn attachments(&mut self) -> Result<Vec<AttachmentView<'a>>, EbmlError> {
+        let attachments = match self.seek_to(Attachments::ID)? {
+            Some(attachments) => attachments,
+            None => return Ok(Vec::new()),
+        };
+        attachments
+            .children()
+            .iter()
+            .filter(|c| c.id == AttachedFile::ID)
+            .map(|file| self.attachment(file))
+            .collect()
+    }
+
+    fn attachment(&self, file: &EbmlNode) -> Result<AttachmentView<'a>, EbmlError> {
+        let (mut uid, mut name, mut media_type, mut description, mut data) =
+            (None, None, None, None, None);
+        for child in file.children() {
+            match child.id {
+                FileUID::ID => uid = Some(FileUID::from_node(child)?.value),
+                FileName::ID => name = Some(FileName::from_node(child)?.value),
+                FileMediaType::ID => media_type = Some(FileMediaType::from_node(child)?.value),
+                FileDescription::ID => description = Some(FileDescription::from_node(child)?.value),
+                FileData::ID => data = Some(self.binary(child)?),
+                _ => {}
+            }
+        }
+        Ok(AttachmentView {
+            uid: uid.ok_or(EbmlError::MissingElement(FileUID::NAME))?,
+            name: name.ok_or(EbmlError::MissingElement(FileName::NAME))?,
+            media_type: media_type.ok_or(EbmlError::MissingElement(FileMediaType::NAME))?,
+            description,
+            data: data.ok_or(EbmlError::MissingElement(FileData::NAME))?,
+        })
+    }
+
+    /// The data of a parsed binary element as a view into the buffer
+    fn binary(&self, node: &EbmlNode) -> Result<EbmlBinary<'a>, EbmlError> {
+        let (offset, size) = match (node.offset(), node.size()) {
+            (Some(offset), Some(size)) => (offset, size),
+            _ => {
+                return Err(EbmlError::InvalidElement(format!(
+                    "{:X} wasn't read",
+                    node.id
+                )))
+            }
+        };
+        let mut cursor = Cursor::new(self.data());
+        cursor.set_position(
+            offset + element_id_length(node.id) as u64 + size.length.num_bytes() as u64,
+        );
+        EbmlBinary::new(size, &mut cursor)
+    }
+}
+
+/// Adds a file to the `Attachments` of the Segment in `data` without moving any other
+/// data. The Attachments grow over the `Void` after them, or are written into a top
+/// level `Void` and added to the `SeekHead` when the Segment has none.
+pub fn add_attachment(data: &mut [u8], file: AttachedFile) -> Result<(), EbmlError> {
+    let mut attachments = read_attachments(data)?;
+    attachments.attached_file.push(file);
+    write_in_place(data, &attachments.to_node())
+}
+
+/// Removes the file with the given UID from the Segment in `data`, returning whether
+/// there was one. The space it took becomes `Void`, removing the last file replaces the
+/// whole `Attachments` element and drops it from the `SeekHead`.
+pub fn remove_attachment(data: &mut [u8], uid: u64) -> Result<bool, EbmlError> {
+    let mut attachments = read_attachments(data)?;
+    let count = attachments.attached_file.len();
+    attachments
+        .attached_file
+        .retain(|f| f.file_uid.as_ref().map(|u| u.value) != Some(uid));
+    if attachments.attached_file.len() == count {
+        return Ok(false);
+    }
+    if attachments.attached_file.is_empty() && attachments.unknown.is_empty() {
+        remove_in_place(data, Attachments::ID)
+    } else {
+        write_in_place(data, &attachments.to_node())?;
+        Ok(true)
+    }
+}
+
+fn read_attachments(data: &[u8]) -> Result<Attachments, EbmlError> {
+    match SegmentReader::new(data)?.seek_to(Attachments::ID)? {
+        Some(node) => Attachments::from_node(&node),
+        None => Ok(Attachments::default()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matroska::segment::tests::{file, seek_head};
+    use crate::matroska::{Info, SeekHead};
+    use crate::Void;
+
+    fn attached(uid: u64, name: &str, data: &[u8]) -> AttachedFile {
+        AttachedFile {
+            file_name: Some(FileName::new(name)),
+            file_media_type: Some(FileMediaType::new("font/ttf")),
+            file_data: Some(FileData::new(data.to_vec())),
+            file_uid: Some(FileUID::new(uid)),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_list() {
+        let mut cover = attached(2, "cover.jpg", &[0xFF, 0xD8, 0xFF]);
+        cover.file_media_type = Some(FileMediaType::new("image/jpeg"));
+        cover.file_description = Some(FileDescription::new("Front cover"));
+        let attachments = Attachments {
+            attached_file: vec![attached(1, "font.ttf", &[1, 2, 3, 4]), cover],
+            ..Default::default()
+        };
+        let data = file(vec![attachments.to_node()]);
+
+        let mut reader = SegmentReader::new(&data).unwrap();
+        let files = reader.attachments().unwrap();
+        assert_eq!(files.len(), 2);
+        assert_eq!(files[0].name, "font.ttf");
+        assert_eq!(files[0].data(), &[1, 2, 3, 4]);
+        assert_eq!(files[1].description.as_deref(), Some("Front cover"));
+        assert_eq!(files[1].media_type, "image/jpeg");
+        // Borrowed from the buffer
+        let offset = files[1].data.offset() as usize;
+        assert_eq!(&data[offset..offset + 3], files[1].data());
+        assert_eq!(files[1].to_attached_file(), attachments.attached_file[1]);
+    }
+
+    #[test]
+    fn test_add_remove() {
+        let mut data = file(vec![
+            seek_head(&[Info::ID]),
+            EbmlNode::binary(Void::ID, vec![0; 40]),
+            Info::default().to_node(),
+            EbmlNode::binary(Void::ID, vec![0; 200]),
+        ]);
+        add_attachment(&mut data, attached(1, "a.ttf", &[1; 20])).unwrap();
+        add_attachment(&mut data, attached(2, "b.ttf", &[2; 30])).unwrap();
+        let mut reader = SegmentReader::new(&data).unwrap();
+        let files = reader.attachments().unwrap();
+        assert_eq!(files.len(), 2);
+        assert_eq!(files[1].data(), &[2; 30]);
+
+        assert!(remove_attachment(&mut data, 1).unwrap());
+        assert!(!remove_attachment(&mut data, 1).unwrap());
+        let names: Vec<_> = SegmentReader::new(&data)
+            .unwrap()
+            .attachments()
+            .unwrap()
+            .into_iter()
+            .map(|f| f.name)
+            .collect();
+        assert_eq!(names, ["b.ttf"]);
+
+        assert!(remove_attachment(&mut data, 2).unwrap());
+        let mut reader = SegmentReader::new(&data).unwrap();
+        assert!(reader.position(Attachments::ID).is_none());
+        let head = reader.seek_to(SeekHead::ID).unwrap().unwrap();
+        assert_eq!(head.children().len(), 1);
+        assert!(reader.seek_to(Info::ID).unwrap().is_some());
+
+        assert!(add_attachment(&mut data, attached(3, "big.ttf", &[3; 500])).is_err());
+    }
+}
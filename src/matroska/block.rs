@@ -0,0 +1,180 @@
+use crate::{EbmlError, EbmlNode, VarInt};
+use std::io::{Cursor, Write};
+
+/// How the frames of a block are packed together
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Lacing {
+    None,
+    Xiph,
+    FixedSize,
+    Ebml,
+}
+
+impl Lacing {
+    fn from_flags(flags: u8) -> Self {
+        match (flags >> 1) & 0x03 {
+            0 => Lacing::None,
+            1 => Lacing::Xiph,
+            2 => Lacing::FixedSize,
+            _ => Lacing::Ebml,
+        }
+    }
+
+    fn flags(&self) -> u8 {
+        match self {
+            Lacing::None => 0,
+            Lacing::Xiph => 1 << 1,
+            Lacing::FixedSize => 2 << 1,
+            Lacing::Ebml => 3 << 1,
+        }
+    }
+}
+
+/// The structure inside a `SimpleBlock` or `Block` element, borrowing the frame data
+/// from the element's data.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlockView<'a> {
+    /// Whether the block is a `SimpleBlock`, only those have keyframe and discardable
+    /// flags
+    pub simple: bool,
+    pub track_number: u64,
+    /// Relative to the Cluster's timestamp, in TimestampScale units
+    pub timestamp: i16,
+    pub keyframe: bool,
+    pub invisible: bool,
+    pub discardable: bool,
+    pub lacing: Lacing,
+    /// The frame data, starting with the lacing header when the block is laced
+    pub data: &'a [u8],
+}
+
+impl<'a> BlockView<'a> {
+    const KEYFRAME: u8 = 0x80;
+    const INVISIBLE: u8 = 0x08;
+    const DISCARDABLE: u8 = 0x01;
+
+    /// Parses the data of a `SimpleBlock` (`simple`) or of a `Block`
+    pub fn parse(data: &'a [u8], simple: bool) -> Result<Self, EbmlError> {
+        let mut cursor = Cursor::new(data);
+        let track_number = VarInt::get_var_int(&mut cursor)?.value;
+        let position = cursor.position() as usize;
+        let header = data
+            .get(position..position + 3)
+            .ok_or(EbmlError::InvalidBlock("the header is truncated"))?;
+        let timestamp = i16::from_be_bytes([header[0], header[1]]);
+        let flags = header[2];
+
+        Ok(Self {
+            simple,
+            track_number,
+            timestamp,
+            keyframe: simple && flags & Self::KEYFRAME != 0,
+            invisible: flags & Self::INVISIBLE != 0,
+            discardable: simple && flags & Self::DISCARDABLE != 0,
+            lacing: Lacing::from_flags(flags),
+            data: &data[position + 3..],
+        })
+    }
+
+    /// Parses a `SimpleBlock` or `Block` node
+    pub fn from_node(node: &'a EbmlNode) -> Result<Self, EbmlError> {
+        let simple = match node.id {
+            super::SimpleBlock::ID => true,
+            super::Block::ID => false,
+            id => return Err(EbmlError::UnexpectedElement(super::SimpleBlock::ID, id)),
+        };
+        let data = node.data().ok_or(EbmlError::IsMasterElement(node.id))?;
+        Self::parse(data, simple)
+    }
+
+    /// An unlaced block holding a single frame
+    pub fn new(simple: bool, track_number: u64, timestamp: i16, frame: &'a [u8]) -> Self {
+        Self {
+            simple,
+            track_number,
+            timestamp,
+            keyframe: false,
+            invisible: false,
+            discardable: false,
+            lacing: Lacing::None,
+            data: frame,
+        }
+    }
+
+    fn flags(&self) -> u8 {
+        let mut flags = self.lacing.flags();
+        if self.simple && self.keyframe {
+            flags |= Self::KEYFRAME;
+        }
+        if self.invisible {
+            flags |= Self::INVISIBLE;
+        }
+        if self.simple && self.discardable {
+            flags |= Self::DISCARDABLE;
+        }
+        flags
+    }
+
+    /// Writes the element data, returning the number of octets written
+    pub fn write<W: Write>(&self, writer: &mut W) -> Result<usize, EbmlError> {
+        let mut written = VarInt::new(self.track_number)?.write(writer)?;
+        writer.write_all(&self.timestamp.to_be_bytes())?;
+        writer.write_all(&[self.flags()])?;
+        writer.write_all(self.data)?;
+        written += 3 + self.data.len();
+        Ok(written)
+    }
+
+    /// A `SimpleBlock` or `Block` node holding the encoded block
+    pub fn to_node(&self) -> Result<EbmlNode<'static>, EbmlError> {
+        let mut data = Vec::with_capacity(self.data.len() + 4);
+        self.write(&mut data)?;
+        let id = if self.simple {
+            super::SimpleBlock::ID
+        } else {
+            super::Block::ID
+        };
+        Ok(EbmlNode::binary(id, data))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        // Track 1, timestamp -2, keyframe and discardable
+        let data = [0x81, 0xFF, 0xFE, 0x81, 0xAA, 0xBB];
+        let block = BlockView::parse(&data, true).unwrap();
+        assert_eq!(block.track_number, 1);
+        assert_eq!(block.timestamp, -2);
+        assert!(block.keyframe && block.discardable && !block.invisible);
+        assert_eq!(block.lacing, Lacing::None);
+        assert_eq!(block.data, &[0xAA, 0xBB]);
+
+        // A Block has no keyframe flag, track 300 needs two octets
+        let data = [0x41, 0x2C, 0x00, 0x10, 0x8E, 0x03, 0x01];
+        let block = BlockView::parse(&data, false).unwrap();
+        assert_eq!(block.track_number, 300);
+        assert_eq!(block.timestamp, 16);
+        assert!(!block.keyframe && block.invisible);
+        assert_eq!(block.lacing, Lacing::Ebml);
+        assert_eq!(block.data, &[0x03, 0x01]);
+
+        assert!(BlockView::parse(&[0x81, 0x00], true).is_err());
+        assert!(BlockView::parse(&[], true).is_err());
+    }
+
+    #[test]
+    fn test_write() {
+        let mut block = BlockView::new(true, 2, 100, &[1, 2, 3]);
+        block.keyframe = true;
+        let node = block.to_node().unwrap();
+        assert_eq!(node.id, crate::matroska::SimpleBlock::ID);
+        assert_eq!(node.data(), Some(&[0x82, 0x00, 0x64, 0x80, 1, 2, 3][..]));
+        assert_eq!(BlockView::from_node(&node).unwrap(), block);
+
+        assert!(BlockView::from_node(&EbmlNode::binary(0x4242, vec![])).is_err());
+    }
+}
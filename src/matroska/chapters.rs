@@ -0,0 +1,324 @@
+use super::segment::write_in_place;
+use super::{
+    ChapCountry, ChapLanguage, ChapLanguageBCP47, ChapString, ChapterAtom, ChapterDisplay,
+    ChapterFlagEnabled, ChapterFlagHidden, ChapterStringUID, ChapterTimeEnd, ChapterTimeStart,
+    ChapterUID, Chapters, EditionEntry, EditionFlagDefault, EditionFlagHidden, EditionFlagOrdered,
+    EditionUID, SegmentReader,
+};
+use crate::{EbmlError, EbmlNode};
+use std::time::Duration;
+
+/// The `Chapters` of a Segment
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ChapterList {
+    pub editions: Vec<Edition>,
+}
+
+/// An `EditionEntry`: one way of splitting the Segment into chapters
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Edition {
+    pub uid: Option<u64>,
+    pub hidden: bool,
+    pub default: bool,
+    pub ordered: bool,
+    pub chapters: Vec<Chapter>,
+}
+
+/// A `ChapterAtom`, which may be split into further chapters
+#[derive(Debug, Clone, PartialEq)]
+pub struct Chapter {
+    pub uid: u64,
+    pub string_uid: Option<String>,
+    /// In nanoseconds, not scaled by the TimestampScale
+    pub start: u64,
+    pub end: Option<u64>,
+    pub hidden: bool,
+    pub enabled: bool,
+    pub displays: Vec<ChapterName>,
+    pub children: Vec<Chapter>,
+}
+
+/// A `ChapterDisplay`: the title of a chapter in some languages
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChapterName {
+    pub string: String,
+    pub languages: Vec<String>,
+    pub languages_bcp47: Vec<String>,
+    pub countries: Vec<String>,
+}
+
+impl ChapterList {
+    /// Reads a `Chapters` node
+    pub fn from_node(node: &EbmlNode) -> Result<Self, EbmlError> {
+        Self::from_chapters(Chapters::from_node(node)?)
+    }
+
+    pub fn from_chapters(chapters: Chapters) -> Result<Self, EbmlError> {
+        let editions = chapters.edition_entry.into_iter().map(Edition::from_entry);
+        Ok(Self {
+            editions: editions.collect::<Result<_, _>>()?,
+        })
+    }
+
+    pub fn to_chapters(&self) -> Chapters {
+        Chapters {
+            edition_entry: self.editions.iter().map(Edition::to_entry).collect(),
+            ..Default::default()
+        }
+    }
+
+    pub fn to_node(&self) -> EbmlNode<'static> {
+        self.to_chapters().to_node()
+    }
+
+    /// The edition flagged as default, or the first one
+    pub fn default_edition(&self) -> Option<&Edition> {
+        self.editions
+            .iter()
+            .find(|e| e.default)
+            .or(self.editions.first())
+    }
+
+    /// Writes the chapters over the Segment's `Chapters` without moving any other data,
+    /// or into a top level `Void` when it has none
+    pub fn write_in_place(&self, data: &mut [u8]) -> Result<(), EbmlError> {
+        write_in_place(data, &self.to_node())
+    }
+}
+
+impl Edition {
+    pub fn from_entry(entry: EditionEntry) -> Result<Self, EbmlError> {
+        let chapters = entry.chapter_atom.into_iter().map(Chapter::from_atom);
+        let flag = |flag: Option<u64>, default: u64| flag.unwrap_or(default) != 0;
+        Ok(Self {
+            uid: entry.edition_uid.map(|u| u.value),
+            hidden: flag(
+                entry.edition_flag_hidden.map(|f| f.value),
+                EditionFlagHidden::DEFAULT,
+            ),
+            default: flag(
+                entry.edition_flag_default.map(|f| f.value),
+                EditionFlagDefault::DEFAULT,
+            ),
+            ordered: flag(
+                entry.edition_flag_ordered.map(|f| f.value),
+                EditionFlagOrdered::DEFAULT,
+            ),
+            chapters: chapters.collect::<Result<_, _>>()?,
+        })
+    }
+
+    pub fn to_entry(&self) -> EditionEntry {
+        EditionEntry {
+            edition_uid: self.uid.map(EditionUID::new),
+            edition_flag_hidden: Some(EditionFlagHidden::new(self.hidden as u64)),
+            edition_flag_default: Some(EditionFlagDefault::new(self.default as u64)),
+            edition_flag_ordered: Some(EditionFlagOrdered::new(self.ordered as u64)),
+            chapter_atom: self.chapters.iter().map(Chapter::to_atom).collect(),
+            ..Default::default()
+        }
+    }
+}
+
+impl Chapter {
+    /// An enabled chapter with a title in the default language
+    pub fn new(uid: u64, start: u64, title: &str) -> Self {
+        Self {
+            uid,
+            string_uid: None,
+            start,
+            end: None,
+            hidden: false,
+            enabled: true,
+            displays: vec![ChapterName::new(title)],
+            children: Vec::new(),
+        }
+    }
+
+    /// The first title of the chapter
+    pub fn title(&self) -> Option<&str> {
+        self.displays.first().map(|d| d.string.as_str())
+    }
+
+    pub fn start_time(&self) -> Duration {
+        Duration::from_nanos(self.start)
+    }
+
+    pub fn end_time(&self) -> Option<Duration> {
+        self.end.map(Duration::from_nanos)
+    }
+
+    pub fn from_atom(atom: ChapterAtom) -> Result<Self, EbmlError> {
+        let children = atom.chapter_atom.into_iter().map(Self::from_atom);
+        let displays = atom
+            .chapter_display
+            .into_iter()
+            .map(ChapterName::from_display);
+        Ok(Self {
+            uid: atom
+                .chapter_uid
+                .ok_or(EbmlError::MissingElement(ChapterUID::NAME))?
+                .value,
+            string_uid: atom.chapter_string_uid.map(|u| u.value),
+            start: atom
+                .chapter_time_start
+                .ok_or(EbmlError::MissingElement(ChapterTimeStart::NAME))?
+                .value,
+            end: atom.chapter_time_end.map(|t| t.value),
+            hidden: atom
+                .chapter_flag_hidden
+                .map_or(ChapterFlagHidden::DEFAULT, |f| f.value)
+                != 0,
+            enabled: atom
+                .chapter_flag_enabled
+                .map_or(ChapterFlagEnabled::DEFAULT, |f| f.value)
+                != 0,
+            displays: displays.collect::<Result<_, _>>()?,
+            children: children.collect::<Result<_, _>>()?,
+        })
+    }
+
+    pub fn to_atom(&self) -> ChapterAtom {
+        ChapterAtom {
+            chapter_atom: self.children.iter().map(Self::to_atom).collect(),
+            chapter_uid: Some(ChapterUID::new(self.uid)),
+            chapter_string_uid: self.string_uid.as_deref().map(ChapterStringUID::new),
+            chapter_time_start: Some(ChapterTimeStart::new(self.start)),
+            chapter_time_end: self.end.map(ChapterTimeEnd::new),
+            chapter_flag_hidden: Some(ChapterFlagHidden::new(self.hidden as u64)),
+            chapter_flag_enabled: Some(ChapterFlagEnabled::new(self.enabled as u64)),
+            chapter_display: self.displays.iter().map(ChapterName::to_display).collect(),
+            ..Default::default()
+        }
+    }
+}
+
+impl ChapterName {
+    /// A title in the default language
+    pub fn new(string: &str) -> Self {
+        Self {
+            string: string.to_string(),
+            languages: vec![ChapLanguage::DEFAULT.to_string()],
+            languages_bcp47: Vec::new(),
+            countries: Vec::new(),
+        }
+    }
+
+    pub fn from_display(display: ChapterDisplay) -> Result<Self, EbmlError> {
+        let mut languages: Vec<_> = display.chap_language.into_iter().map(|l| l.value).collect();
+        if languages.is_empty() {
+            languages.push(ChapLanguage::DEFAULT.to_string());
+        }
+        Ok(Self {
+            string: display
+                .chap_string
+                .ok_or(EbmlError::MissingElement(ChapString::NAME))?
+                .value,
+            languages,
+            languages_bcp47: display
+                .chap_language_bcp47
+                .into_iter()
+                .map(|l| l.value)
+                .collect(),
+            countries: display.chap_country.into_iter().map(|c| c.value).collect(),
+        })
+    }
+
+    pub fn to_display(&self) -> ChapterDisplay {
+        ChapterDisplay {
+            chap_string: Some(ChapString::new(self.string.as_str())),
+            chap_language: self.languages.iter().map(ChapLanguage::new).collect(),
+            chap_language_bcp47: self
+                .languages_bcp47
+                .iter()
+                .map(ChapLanguageBCP47::new)
+                .collect(),
+            chap_country: self.countries.iter().map(ChapCountry::new).collect(),
+            ..Default::default()
+        }
+    }
+}
+
+impl<'a> SegmentReader<'a> {
+    /// Reads the `Chapters` of the Segment
+    pub fn chapters(&mut self) -> Result<Option<ChapterList>, EbmlError> {
+        match self.seek_to(Chapters::ID)? {
+            Some(chapters) => ChapterList::from_node(&chapters).map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matroska::segment::tests::{file, seek_head};
+    use crate::matroska::Info;
+    use crate::Void;
+
+    fn sample() -> ChapterList {
+        let mut intro = Chapter::new(1, 0, "Intro");
+        intro.end = Some(5_000_000_000);
+        let mut main = Chapter::new(2, 5_000_000_000, "Main");
+        main.displays.push(ChapterName {
+            languages: vec!["ger".to_string()],
+            languages_bcp47: vec!["de".to_string()],
+            ..ChapterName::new("Haupt")
+        });
+        main.children.push(Chapter::new(3, 6_000_000_000, "Part"));
+        ChapterList {
+            editions: vec![
+                Edition {
+                    uid: Some(9),
+                    chapters: vec![intro.clone()],
+                    ..Default::default()
+                },
+                Edition {
+                    default: true,
+                    chapters: vec![intro, main],
+                    ..Default::default()
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let chapters = sample();
+        // ChapterDisplay's 0x80 ID is read strictly
+        let data = file(vec![
+            seek_head(&[Info::ID]),
+            chapters.to_node(),
+            Info::default().to_node(),
+        ]);
+        let mut reader = SegmentReader::new(&data).unwrap();
+        let read = reader.chapters().unwrap().unwrap();
+        assert_eq!(read, chapters);
+
+        let edition = read.default_edition().unwrap();
+        assert_eq!(edition.chapters.len(), 2);
+        let main = &edition.chapters[1];
+        assert_eq!(main.title(), Some("Main"));
+        assert_eq!(main.displays[1].languages_bcp47, ["de"]);
+        assert_eq!(main.children[0].start_time(), Duration::from_secs(6));
+        assert_eq!(edition.chapters[0].end_time(), Some(Duration::from_secs(5)));
+        assert!(main.enabled && !main.hidden);
+    }
+
+    #[test]
+    fn test_write_in_place() {
+        let mut data = file(vec![
+            seek_head(&[Info::ID]),
+            EbmlNode::binary(Void::ID, vec![0; 40]),
+            Info::default().to_node(),
+            EbmlNode::binary(Void::ID, vec![0; 300]),
+        ]);
+        let mut chapters = sample();
+        chapters.write_in_place(&mut data).unwrap();
+        chapters.editions[0].chapters[0].displays[0].string = "Opening".to_string();
+        chapters.write_in_place(&mut data).unwrap();
+
+        let read = SegmentReader::new(&data).unwrap().chapters().unwrap();
+        assert_eq!(read, Some(chapters));
+    }
+}
@@ -0,0 +1,287 @@
+use super::{
+    CueBlockNumber, CueClusterPosition, CueDuration, CuePoint, CueRelativePosition, CueTime,
+    CueTrack, CueTrackPositions, Cues, Info, SegmentReader, TimestampScale,
+};
+use crate::{EbmlError, EbmlNode};
+
+/// Where a track can start decoding from, one per `CueTrackPositions`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CueEntry {
+    /// In TimestampScale units
+    pub time: u64,
+    pub track: u64,
+    /// Position of the Cluster relative to the start of the Segment's data
+    pub cluster_position: u64,
+    /// Position of the block relative to the start of the Cluster's data
+    pub relative_position: Option<u64>,
+    /// The number of the block in its Cluster, starting at 1
+    pub block_number: Option<u64>,
+    pub duration: Option<u64>,
+}
+
+/// The entries of a `Cues` element sorted by time
+#[derive(Debug, Clone, PartialEq)]
+pub struct CueIndex {
+    /// Nanoseconds per time unit
+    pub timestamp_scale: u64,
+    entries: Vec<CueEntry>,
+}
+
+impl CueIndex {
+    pub fn new(timestamp_scale: u64) -> Self {
+        Self {
+            timestamp_scale,
+            entries: Vec::new(),
+        }
+    }
+
+    /// Reads a `Cues` element, CuePoints without a time or positions without a track or
+    /// Cluster position are skipped
+    pub fn from_node(node: &EbmlNode, timestamp_scale: u64) -> Result<Self, EbmlError> {
+        let mut index = Self::new(timestamp_scale);
+        for point in Cues::from_node(node)?.cue_point {
+            let time = match point.cue_time {
+                Some(time) => time.value,
+                None => continue,
+            };
+            for positions in point.cue_track_positions {
+                let (track, cluster_position) =
+                    match (positions.cue_track, positions.cue_cluster_position) {
+                        (Some(track), Some(position)) => (track.value, position.value),
+                        _ => continue,
+                    };
+                index.entries.push(CueEntry {
+                    time,
+                    track,
+                    cluster_position,
+                    relative_position: positions.cue_relative_position.map(|p| p.value),
+                    block_number: positions.cue_block_number.map(|n| n.value),
+                    duration: positions.cue_duration.map(|d| d.value),
+                });
+            }
+        }
+        index.entries.sort_by_key(|e| e.time);
+        Ok(index)
+    }
+
+    pub fn entries(&self) -> &[CueEntry] {
+        &self.entries
+    }
+
+    /// Adds an entry after those with the same or an earlier time
+    pub fn push(&mut self, entry: CueEntry) {
+        let index = self.entries.partition_point(|e| e.time <= entry.time);
+        self.entries.insert(index, entry);
+    }
+
+    /// A `Cues` element with one CuePoint per distinct time
+    pub fn to_node(&self) -> EbmlNode<'static> {
+        let mut cues = Cues::default();
+        for entry in &self.entries {
+            let positions = CueTrackPositions {
+                cue_track: Some(CueTrack::new(entry.track)),
+                cue_cluster_position: Some(CueClusterPosition::new(entry.cluster_position)),
+                cue_relative_position: entry.relative_position.map(CueRelativePosition::new),
+                cue_duration: entry.duration.map(CueDuration::new),
+                cue_block_number: entry.block_number.map(CueBlockNumber::new),
+                ..Default::default()
+            };
+            match cues.cue_point.last_mut() {
+                Some(point) if point.cue_time == Some(CueTime::new(entry.time)) => {
+                    point.cue_track_positions.push(positions)
+                }
+                _ => cues.cue_point.push(CuePoint {
+                    cue_time: Some(CueTime::new(entry.time)),
+                    cue_track_positions: vec![positions],
+                    ..Default::default()
+                }),
+            }
+        }
+        cues.to_node()
+    }
+
+    /// The last entry of the track at or before `timestamp` (in nanoseconds), or its
+    /// first entry when the timestamp is before every one of them
+    pub fn find(&self, track: u64, timestamp: u64) -> Option<&CueEntry> {
+        let time = timestamp / self.timestamp_scale.max(1);
+        let mut entries = self.entries.iter().filter(|e| e.track == track);
+        let first = entries.next()?;
+        Some(
+            entries
+                .take_while(|e| e.time <= time)
+                .last()
+                .unwrap_or(first),
+        )
+    }
+}
+
+/// Where to start reading to decode a track from a timestamp
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CueTarget {
+    /// The time of the cue in nanoseconds
+    pub timestamp: u64,
+    /// Position of the Cluster element
+    pub cluster: u64,
+    /// Position of the block element, when the cue has a relative position
+    pub block: Option<u64>,
+    /// The number of the block in its Cluster, starting at 1
+    pub block_number: Option<u64>,
+}
+
+impl<'a> SegmentReader<'a> {
+    /// Reads the `Cues` with the Segment's TimestampScale, `None` when there are none
+    pub fn cues(&mut self) -> Result<Option<CueIndex>, EbmlError> {
+        let cues = match self.seek_to(Cues::ID)? {
+            Some(cues) => cues,
+            None => return Ok(None),
+        };
+        let timestamp_scale = match self.seek_to(Info::ID)? {
+            Some(info) => Info::from_node(&info)?
+                .timestamp_scale
+                .map_or(TimestampScale::DEFAULT, |scale| scale.value),
+            None => TimestampScale::DEFAULT,
+        };
+        CueIndex::from_node(&cues, timestamp_scale).map(Some)
+    }
+
+    /// Finds where to start decoding `track` to reach `timestamp` (in nanoseconds)
+    /// through the Cues, `None` when they have no entry for the track
+    pub fn seek_timestamp(
+        &mut self,
+        track: u64,
+        timestamp: u64,
+    ) -> Result<Option<CueTarget>, EbmlError> {
+        let cues = match self.cues()? {
+            Some(cues) => cues,
+            None => return Ok(None),
+        };
+        let entry = match cues.find(track, timestamp) {
+            Some(entry) => *entry,
+            None => return Ok(None),
+        };
+
+        let overflow = || EbmlError::InvalidElement("Cue position out of range".to_string());
+        let cluster = self
+            .data_start()
+            .checked_add(entry.cluster_position)
+            .ok_or_else(overflow)?;
+        let block = match entry.relative_position {
+            Some(relative) => {
+                let header = self.header_at(entry.cluster_position)?;
+                let block = cluster
+                    .checked_add(header.length)
+                    .and_then(|start| start.checked_add(relative))
+                    .ok_or_else(overflow)?;
+                Some(block)
+            }
+            None => None,
+        };
+        let timestamp = entry
+            .time
+            .checked_mul(cues.timestamp_scale)
+            .ok_or_else(|| EbmlError::InvalidElement("CueTime out of range".to_string()))?;
+        Ok(Some(CueTarget {
+            timestamp,
+            cluster,
+            block,
+            block_number: entry.block_number,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matroska::segment::tests::{file, seek_head};
+    use crate::matroska::{BlockView, Cluster, Timestamp};
+
+    fn cue_point(time: u64, track: u64, cluster: u64, relative: Option<u64>) -> CuePoint {
+        CuePoint {
+            cue_time: Some(CueTime::new(time)),
+            cue_track_positions: vec![CueTrackPositions {
+                cue_track: Some(CueTrack::new(track)),
+                cue_cluster_position: Some(CueClusterPosition::new(cluster)),
+                cue_relative_position: relative.map(CueRelativePosition::new),
+                cue_block_number: Some(CueBlockNumber::new(1)),
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_find() {
+        let cues = Cues {
+            cue_point: vec![
+                cue_point(2000, 1, 300, None),
+                cue_point(0, 1, 100, None),
+                cue_point(1000, 1, 200, None),
+                cue_point(500, 2, 150, None),
+            ],
+            ..Default::default()
+        };
+        let index = CueIndex::from_node(&cues.to_node(), 1_000_000).unwrap();
+        assert_eq!(index.entries().len(), 4);
+        let find = |track, ms: u64| {
+            index
+                .find(track, ms * 1_000_000)
+                .map(|e| e.cluster_position)
+        };
+        assert_eq!(find(1, 0), Some(100));
+        assert_eq!(find(1, 1500), Some(200));
+        assert_eq!(find(1, 2000), Some(300));
+        assert_eq!(find(1, 9000), Some(300));
+        // Before the first cue of the track
+        assert_eq!(find(2, 100), Some(150));
+        assert_eq!(find(3, 0), None);
+
+        let mut built = CueIndex::new(1_000_000);
+        for entry in index.entries().iter().rev() {
+            built.push(*entry);
+        }
+        assert_eq!(built, index);
+        assert_eq!(CueIndex::from_node(&built.to_node(), 1_000_000).unwrap(), index);
+    }
+
+    #[test]
+    fn test_seek_timestamp() {
+        let block = BlockView::new(true, 1, 0, &[1, 2, 3]).to_node().unwrap();
+        let timestamp = EbmlNode::unsigned(Timestamp::ID, 0);
+        // The block follows the three octet Timestamp
+        let cluster = EbmlNode::master(Cluster::ID, vec![timestamp, block]);
+
+        let head = seek_head(&[Cues::ID]);
+        let cluster_position = head.encoded_size();
+        let cues = Cues {
+            cue_point: vec![cue_point(0, 1, cluster_position, Some(3))],
+            ..Default::default()
+        };
+        let data = file(vec![head, cluster, cues.to_node()]);
+
+        let mut reader = SegmentReader::new(&data).unwrap();
+        let target = reader.seek_timestamp(1, 5_000_000).unwrap().unwrap();
+        assert_eq!(target.cluster, reader.data_start() + cluster_position);
+        assert_eq!(target.block_number, Some(1));
+        let block = target.block.unwrap() as usize;
+        assert_eq!(&data[block..block + 2], &[0xA3, 0x87]);
+        assert!(reader.seek_timestamp(2, 0).unwrap().is_none());
+
+        // Values from a damaged file are errors rather than overflows
+        let cues = Cues {
+            cue_point: vec![
+                cue_point(u64::MAX, 1, cluster_position, None),
+                cue_point(0, 2, u64::MAX, None),
+                cue_point(0, 3, cluster_position, Some(u64::MAX)),
+            ],
+            ..Default::default()
+        };
+        let data = file(vec![seek_head(&[Cues::ID]), cues.to_node()]);
+        let mut reader = SegmentReader::new(&data).unwrap();
+        for track in 1..=3 {
+            assert!(matches!(
+                reader.seek_timestamp(track, u64::MAX),
+                Err(EbmlError::InvalidElement(_))
+            ));
+        }
+    }
+}
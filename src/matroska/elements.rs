@@ -0,0 +1,10289 @@
+// Generated by rebml::codegen from the "matroska" schema version 4, do not edit
+
+/// The Root Element that contains all other Top-Level Elements.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Segment {
+    pub seek_head: Vec<SeekHead>,
+    pub info: Option<Info>,
+    pub cluster: Vec<Cluster>,
+    pub tracks: Option<Tracks>,
+    pub cues: Option<Cues>,
+    pub attachments: Option<Attachments>,
+    pub chapters: Option<Chapters>,
+    pub tags: Vec<Tags>,
+    /// Children the schema doesn't define for this element
+    pub unknown: Vec<crate::EbmlNode<'static>>,
+}
+
+impl Segment {
+    pub const ID: u64 = 0x18538067;
+    pub const NAME: &str = "Segment";
+    pub const PATH: &str = "\\Segment";
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        if !node.is_master() {
+            return Err(crate::EbmlError::NotMasterElement(node.id));
+        }
+        let mut value = Self::default();
+        for child in node.children() {
+            match child.id {
+                SeekHead::ID => value.seek_head.push(SeekHead::from_node(child)?),
+                Info::ID => value.info = Some(Info::from_node(child)?),
+                Cluster::ID => value.cluster.push(Cluster::from_node(child)?),
+                Tracks::ID => value.tracks = Some(Tracks::from_node(child)?),
+                Cues::ID => value.cues = Some(Cues::from_node(child)?),
+                Attachments::ID => value.attachments = Some(Attachments::from_node(child)?),
+                Chapters::ID => value.chapters = Some(Chapters::from_node(child)?),
+                Tags::ID => value.tags.push(Tags::from_node(child)?),
+                0xBF | 0xEC => {}
+                _ => value.unknown.push(child.clone().into_owned()),
+            }
+        }
+        Ok(value)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        let mut children = Vec::new();
+        for child in &self.seek_head {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.info {
+            children.push(child.to_node());
+        }
+        for child in &self.cluster {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.tracks {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.cues {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.attachments {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.chapters {
+            children.push(child.to_node());
+        }
+        for child in &self.tags {
+            children.push(child.to_node());
+        }
+        children.extend(self.unknown.iter().cloned());
+        crate::EbmlNode::master(Self::ID, children)
+    }
+}
+
+/// Contains seeking information of Top-Level Elements.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SeekHead {
+    pub seek: Vec<Seek>,
+    /// Children the schema doesn't define for this element
+    pub unknown: Vec<crate::EbmlNode<'static>>,
+}
+
+impl SeekHead {
+    pub const ID: u64 = 0x114D9B74;
+    pub const NAME: &str = "SeekHead";
+    pub const PATH: &str = "\\Segment\\SeekHead";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 2;
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        if !node.is_master() {
+            return Err(crate::EbmlError::NotMasterElement(node.id));
+        }
+        let mut value = Self::default();
+        for child in node.children() {
+            match child.id {
+                Seek::ID => value.seek.push(Seek::from_node(child)?),
+                0xBF | 0xEC => {}
+                _ => value.unknown.push(child.clone().into_owned()),
+            }
+        }
+        Ok(value)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        let mut children = Vec::new();
+        for child in &self.seek {
+            children.push(child.to_node());
+        }
+        children.extend(self.unknown.iter().cloned());
+        crate::EbmlNode::master(Self::ID, children)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Seek {
+    pub seek_id: Option<SeekID>,
+    pub seek_position: Option<SeekPosition>,
+    /// Children the schema doesn't define for this element
+    pub unknown: Vec<crate::EbmlNode<'static>>,
+}
+
+impl Seek {
+    pub const ID: u64 = 0x4DBB;
+    pub const NAME: &str = "Seek";
+    pub const PATH: &str = "\\Segment\\SeekHead\\Seek";
+    pub const MIN_OCCURS: u64 = 1;
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        if !node.is_master() {
+            return Err(crate::EbmlError::NotMasterElement(node.id));
+        }
+        let mut value = Self::default();
+        for child in node.children() {
+            match child.id {
+                SeekID::ID => value.seek_id = Some(SeekID::from_node(child)?),
+                SeekPosition::ID => value.seek_position = Some(SeekPosition::from_node(child)?),
+                0xBF | 0xEC => {}
+                _ => value.unknown.push(child.clone().into_owned()),
+            }
+        }
+        Ok(value)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        let mut children = Vec::new();
+        if let Some(child) = &self.seek_id {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.seek_position {
+            children.push(child.to_node());
+        }
+        children.extend(self.unknown.iter().cloned());
+        crate::EbmlNode::master(Self::ID, children)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SeekID {
+    pub value: Vec<u8>,
+}
+
+impl SeekID {
+    pub const ID: u64 = 0x53AB;
+    pub const NAME: &str = "SeekID";
+    pub const PATH: &str = "\\Segment\\SeekHead\\Seek\\SeekID";
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: Vec<u8>) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(data.to_vec()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::binary(Self::ID, self.value.clone())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SeekPosition {
+    pub value: u64,
+}
+
+impl SeekPosition {
+    pub const ID: u64 = 0x53AC;
+    pub const NAME: &str = "SeekPosition";
+    pub const PATH: &str = "\\Segment\\SeekHead\\Seek\\SeekPosition";
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+/// Contains general information about the Segment.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Info {
+    pub segment_uuid: Option<SegmentUUID>,
+    pub segment_filename: Option<SegmentFilename>,
+    pub prev_uuid: Option<PrevUUID>,
+    pub prev_filename: Option<PrevFilename>,
+    pub next_uuid: Option<NextUUID>,
+    pub next_filename: Option<NextFilename>,
+    pub segment_family: Vec<SegmentFamily>,
+    pub chapter_translate: Vec<ChapterTranslate>,
+    pub timestamp_scale: Option<TimestampScale>,
+    pub duration: Option<Duration>,
+    pub date_utc: Option<DateUTC>,
+    pub title: Option<Title>,
+    pub muxing_app: Option<MuxingApp>,
+    pub writing_app: Option<WritingApp>,
+    /// Children the schema doesn't define for this element
+    pub unknown: Vec<crate::EbmlNode<'static>>,
+}
+
+impl Info {
+    pub const ID: u64 = 0x1549A966;
+    pub const NAME: &str = "Info";
+    pub const PATH: &str = "\\Segment\\Info";
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        if !node.is_master() {
+            return Err(crate::EbmlError::NotMasterElement(node.id));
+        }
+        let mut value = Self::default();
+        for child in node.children() {
+            match child.id {
+                SegmentUUID::ID => value.segment_uuid = Some(SegmentUUID::from_node(child)?),
+                SegmentFilename::ID => value.segment_filename = Some(SegmentFilename::from_node(child)?),
+                PrevUUID::ID => value.prev_uuid = Some(PrevUUID::from_node(child)?),
+                PrevFilename::ID => value.prev_filename = Some(PrevFilename::from_node(child)?),
+                NextUUID::ID => value.next_uuid = Some(NextUUID::from_node(child)?),
+                NextFilename::ID => value.next_filename = Some(NextFilename::from_node(child)?),
+                SegmentFamily::ID => value.segment_family.push(SegmentFamily::from_node(child)?),
+                ChapterTranslate::ID => value.chapter_translate.push(ChapterTranslate::from_node(child)?),
+                TimestampScale::ID => value.timestamp_scale = Some(TimestampScale::from_node(child)?),
+                Duration::ID => value.duration = Some(Duration::from_node(child)?),
+                DateUTC::ID => value.date_utc = Some(DateUTC::from_node(child)?),
+                Title::ID => value.title = Some(Title::from_node(child)?),
+                MuxingApp::ID => value.muxing_app = Some(MuxingApp::from_node(child)?),
+                WritingApp::ID => value.writing_app = Some(WritingApp::from_node(child)?),
+                0xBF | 0xEC => {}
+                _ => value.unknown.push(child.clone().into_owned()),
+            }
+        }
+        Ok(value)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        let mut children = Vec::new();
+        if let Some(child) = &self.segment_uuid {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.segment_filename {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.prev_uuid {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.prev_filename {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.next_uuid {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.next_filename {
+            children.push(child.to_node());
+        }
+        for child in &self.segment_family {
+            children.push(child.to_node());
+        }
+        for child in &self.chapter_translate {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.timestamp_scale {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.duration {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.date_utc {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.title {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.muxing_app {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.writing_app {
+            children.push(child.to_node());
+        }
+        children.extend(self.unknown.iter().cloned());
+        crate::EbmlNode::master(Self::ID, children)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SegmentUUID {
+    pub value: Vec<u8>,
+}
+
+impl SegmentUUID {
+    pub const ID: u64 = 0x73A4;
+    pub const NAME: &str = "SegmentUUID";
+    pub const PATH: &str = "\\Segment\\Info\\SegmentUUID";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: Vec<u8>) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(data.to_vec()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::binary(Self::ID, self.value.clone())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SegmentFilename {
+    pub value: String,
+}
+
+impl SegmentFilename {
+    pub const ID: u64 = 0x7384;
+    pub const NAME: &str = "SegmentFilename";
+    pub const PATH: &str = "\\Segment\\Info\\SegmentFilename";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: impl Into<String>) -> Self {
+        Self { value: value.into() }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUtf8::new(data)?.as_str()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::string(Self::ID, &self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrevUUID {
+    pub value: Vec<u8>,
+}
+
+impl PrevUUID {
+    pub const ID: u64 = 0x3CB923;
+    pub const NAME: &str = "PrevUUID";
+    pub const PATH: &str = "\\Segment\\Info\\PrevUUID";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: Vec<u8>) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(data.to_vec()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::binary(Self::ID, self.value.clone())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrevFilename {
+    pub value: String,
+}
+
+impl PrevFilename {
+    pub const ID: u64 = 0x3C83AB;
+    pub const NAME: &str = "PrevFilename";
+    pub const PATH: &str = "\\Segment\\Info\\PrevFilename";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: impl Into<String>) -> Self {
+        Self { value: value.into() }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUtf8::new(data)?.as_str()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::string(Self::ID, &self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct NextUUID {
+    pub value: Vec<u8>,
+}
+
+impl NextUUID {
+    pub const ID: u64 = 0x3EB923;
+    pub const NAME: &str = "NextUUID";
+    pub const PATH: &str = "\\Segment\\Info\\NextUUID";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: Vec<u8>) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(data.to_vec()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::binary(Self::ID, self.value.clone())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct NextFilename {
+    pub value: String,
+}
+
+impl NextFilename {
+    pub const ID: u64 = 0x3E83BB;
+    pub const NAME: &str = "NextFilename";
+    pub const PATH: &str = "\\Segment\\Info\\NextFilename";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: impl Into<String>) -> Self {
+        Self { value: value.into() }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUtf8::new(data)?.as_str()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::string(Self::ID, &self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SegmentFamily {
+    pub value: Vec<u8>,
+}
+
+impl SegmentFamily {
+    pub const ID: u64 = 0x4444;
+    pub const NAME: &str = "SegmentFamily";
+    pub const PATH: &str = "\\Segment\\Info\\SegmentFamily";
+    pub const MIN_OCCURS: u64 = 0;
+
+    pub fn new(value: Vec<u8>) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(data.to_vec()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::binary(Self::ID, self.value.clone())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ChapterTranslate {
+    pub chapter_translate_id: Option<ChapterTranslateID>,
+    pub chapter_translate_codec: Option<ChapterTranslateCodec>,
+    pub chapter_translate_edition_uid: Vec<ChapterTranslateEditionUID>,
+    /// Children the schema doesn't define for this element
+    pub unknown: Vec<crate::EbmlNode<'static>>,
+}
+
+impl ChapterTranslate {
+    pub const ID: u64 = 0x6924;
+    pub const NAME: &str = "ChapterTranslate";
+    pub const PATH: &str = "\\Segment\\Info\\ChapterTranslate";
+    pub const MIN_OCCURS: u64 = 0;
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        if !node.is_master() {
+            return Err(crate::EbmlError::NotMasterElement(node.id));
+        }
+        let mut value = Self::default();
+        for child in node.children() {
+            match child.id {
+                ChapterTranslateID::ID => value.chapter_translate_id = Some(ChapterTranslateID::from_node(child)?),
+                ChapterTranslateCodec::ID => value.chapter_translate_codec = Some(ChapterTranslateCodec::from_node(child)?),
+                ChapterTranslateEditionUID::ID => value.chapter_translate_edition_uid.push(ChapterTranslateEditionUID::from_node(child)?),
+                0xBF | 0xEC => {}
+                _ => value.unknown.push(child.clone().into_owned()),
+            }
+        }
+        Ok(value)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        let mut children = Vec::new();
+        if let Some(child) = &self.chapter_translate_id {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.chapter_translate_codec {
+            children.push(child.to_node());
+        }
+        for child in &self.chapter_translate_edition_uid {
+            children.push(child.to_node());
+        }
+        children.extend(self.unknown.iter().cloned());
+        crate::EbmlNode::master(Self::ID, children)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChapterTranslateID {
+    pub value: Vec<u8>,
+}
+
+impl ChapterTranslateID {
+    pub const ID: u64 = 0x69A5;
+    pub const NAME: &str = "ChapterTranslateID";
+    pub const PATH: &str = "\\Segment\\Info\\ChapterTranslate\\ChapterTranslateID";
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: Vec<u8>) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(data.to_vec()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::binary(Self::ID, self.value.clone())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChapterTranslateCodec {
+    pub value: u64,
+}
+
+impl ChapterTranslateCodec {
+    pub const ID: u64 = 0x69BF;
+    pub const NAME: &str = "ChapterTranslateCodec";
+    pub const PATH: &str = "\\Segment\\Info\\ChapterTranslate\\ChapterTranslateCodec";
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChapterTranslateEditionUID {
+    pub value: u64,
+}
+
+impl ChapterTranslateEditionUID {
+    pub const ID: u64 = 0x69FC;
+    pub const NAME: &str = "ChapterTranslateEditionUID";
+    pub const PATH: &str = "\\Segment\\Info\\ChapterTranslate\\ChapterTranslateEditionUID";
+    pub const MIN_OCCURS: u64 = 0;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimestampScale {
+    pub value: u64,
+}
+
+impl TimestampScale {
+    pub const ID: u64 = 0x2AD7B1;
+    pub const NAME: &str = "TimestampScale";
+    pub const PATH: &str = "\\Segment\\Info\\TimestampScale";
+    pub const DEFAULT: u64 = 1000000;
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data, an empty element stands for `DEFAULT`
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        if data.is_empty() {
+            return Ok(Self::new(Self::DEFAULT));
+        }
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Duration {
+    pub value: f64,
+}
+
+impl Duration {
+    pub const ID: u64 = 0x4489;
+    pub const NAME: &str = "Duration";
+    pub const PATH: &str = "\\Segment\\Info\\Duration";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: f64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlFloat::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::float(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DateUTC {
+    pub value: i64,
+}
+
+impl DateUTC {
+    pub const ID: u64 = 0x4461;
+    pub const NAME: &str = "DateUTC";
+    pub const PATH: &str = "\\Segment\\Info\\DateUTC";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: i64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlDate::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::date(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Title {
+    pub value: String,
+}
+
+impl Title {
+    pub const ID: u64 = 0x7BA9;
+    pub const NAME: &str = "Title";
+    pub const PATH: &str = "\\Segment\\Info\\Title";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: impl Into<String>) -> Self {
+        Self { value: value.into() }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUtf8::new(data)?.as_str()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::string(Self::ID, &self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MuxingApp {
+    pub value: String,
+}
+
+impl MuxingApp {
+    pub const ID: u64 = 0x4D80;
+    pub const NAME: &str = "MuxingApp";
+    pub const PATH: &str = "\\Segment\\Info\\MuxingApp";
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: impl Into<String>) -> Self {
+        Self { value: value.into() }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUtf8::new(data)?.as_str()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::string(Self::ID, &self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct WritingApp {
+    pub value: String,
+}
+
+impl WritingApp {
+    pub const ID: u64 = 0x5741;
+    pub const NAME: &str = "WritingApp";
+    pub const PATH: &str = "\\Segment\\Info\\WritingApp";
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: impl Into<String>) -> Self {
+        Self { value: value.into() }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUtf8::new(data)?.as_str()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::string(Self::ID, &self.value)
+    }
+}
+
+/// The Top-Level Element containing the Block structure.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Cluster {
+    pub timestamp: Option<Timestamp>,
+    pub silent_tracks: Option<SilentTracks>,
+    pub position: Option<Position>,
+    pub prev_size: Option<PrevSize>,
+    pub simple_block: Vec<SimpleBlock>,
+    pub block_group: Vec<BlockGroup>,
+    pub encrypted_block: Vec<EncryptedBlock>,
+    /// Children the schema doesn't define for this element
+    pub unknown: Vec<crate::EbmlNode<'static>>,
+}
+
+impl Cluster {
+    pub const ID: u64 = 0x1F43B675;
+    pub const NAME: &str = "Cluster";
+    pub const PATH: &str = "\\Segment\\Cluster";
+    pub const MIN_OCCURS: u64 = 0;
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        if !node.is_master() {
+            return Err(crate::EbmlError::NotMasterElement(node.id));
+        }
+        let mut value = Self::default();
+        for child in node.children() {
+            match child.id {
+                Timestamp::ID => value.timestamp = Some(Timestamp::from_node(child)?),
+                SilentTracks::ID => value.silent_tracks = Some(SilentTracks::from_node(child)?),
+                Position::ID => value.position = Some(Position::from_node(child)?),
+                PrevSize::ID => value.prev_size = Some(PrevSize::from_node(child)?),
+                SimpleBlock::ID => value.simple_block.push(SimpleBlock::from_node(child)?),
+                BlockGroup::ID => value.block_group.push(BlockGroup::from_node(child)?),
+                EncryptedBlock::ID => value.encrypted_block.push(EncryptedBlock::from_node(child)?),
+                0xBF | 0xEC => {}
+                _ => value.unknown.push(child.clone().into_owned()),
+            }
+        }
+        Ok(value)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        let mut children = Vec::new();
+        if let Some(child) = &self.timestamp {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.silent_tracks {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.position {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.prev_size {
+            children.push(child.to_node());
+        }
+        for child in &self.simple_block {
+            children.push(child.to_node());
+        }
+        for child in &self.block_group {
+            children.push(child.to_node());
+        }
+        for child in &self.encrypted_block {
+            children.push(child.to_node());
+        }
+        children.extend(self.unknown.iter().cloned());
+        crate::EbmlNode::master(Self::ID, children)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Timestamp {
+    pub value: u64,
+}
+
+impl Timestamp {
+    pub const ID: u64 = 0xE7;
+    pub const NAME: &str = "Timestamp";
+    pub const PATH: &str = "\\Segment\\Cluster\\Timestamp";
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SilentTracks {
+    pub silent_track_number: Vec<SilentTrackNumber>,
+    /// Children the schema doesn't define for this element
+    pub unknown: Vec<crate::EbmlNode<'static>>,
+}
+
+impl SilentTracks {
+    pub const ID: u64 = 0x5854;
+    pub const NAME: &str = "SilentTracks";
+    pub const PATH: &str = "\\Segment\\Cluster\\SilentTracks";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        if !node.is_master() {
+            return Err(crate::EbmlError::NotMasterElement(node.id));
+        }
+        let mut value = Self::default();
+        for child in node.children() {
+            match child.id {
+                SilentTrackNumber::ID => value.silent_track_number.push(SilentTrackNumber::from_node(child)?),
+                0xBF | 0xEC => {}
+                _ => value.unknown.push(child.clone().into_owned()),
+            }
+        }
+        Ok(value)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        let mut children = Vec::new();
+        for child in &self.silent_track_number {
+            children.push(child.to_node());
+        }
+        children.extend(self.unknown.iter().cloned());
+        crate::EbmlNode::master(Self::ID, children)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SilentTrackNumber {
+    pub value: u64,
+}
+
+impl SilentTrackNumber {
+    pub const ID: u64 = 0x58D7;
+    pub const NAME: &str = "SilentTrackNumber";
+    pub const PATH: &str = "\\Segment\\Cluster\\SilentTracks\\SilentTrackNumber";
+    pub const MIN_OCCURS: u64 = 0;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Position {
+    pub value: u64,
+}
+
+impl Position {
+    pub const ID: u64 = 0xA7;
+    pub const NAME: &str = "Position";
+    pub const PATH: &str = "\\Segment\\Cluster\\Position";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrevSize {
+    pub value: u64,
+}
+
+impl PrevSize {
+    pub const ID: u64 = 0xAB;
+    pub const NAME: &str = "PrevSize";
+    pub const PATH: &str = "\\Segment\\Cluster\\PrevSize";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SimpleBlock {
+    pub value: Vec<u8>,
+}
+
+impl SimpleBlock {
+    pub const ID: u64 = 0xA3;
+    pub const NAME: &str = "SimpleBlock";
+    pub const PATH: &str = "\\Segment\\Cluster\\SimpleBlock";
+    pub const MIN_OCCURS: u64 = 0;
+
+    pub fn new(value: Vec<u8>) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(data.to_vec()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::binary(Self::ID, self.value.clone())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct BlockGroup {
+    pub block: Option<Block>,
+    pub block_virtual: Option<BlockVirtual>,
+    pub block_additions: Option<BlockAdditions>,
+    pub block_duration: Option<BlockDuration>,
+    pub reference_priority: Option<ReferencePriority>,
+    pub reference_block: Vec<ReferenceBlock>,
+    pub reference_virtual: Option<ReferenceVirtual>,
+    pub codec_state: Option<CodecState>,
+    pub discard_padding: Option<DiscardPadding>,
+    pub slices: Option<Slices>,
+    pub reference_frame: Option<ReferenceFrame>,
+    /// Children the schema doesn't define for this element
+    pub unknown: Vec<crate::EbmlNode<'static>>,
+}
+
+impl BlockGroup {
+    pub const ID: u64 = 0xA0;
+    pub const NAME: &str = "BlockGroup";
+    pub const PATH: &str = "\\Segment\\Cluster\\BlockGroup";
+    pub const MIN_OCCURS: u64 = 0;
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        if !node.is_master() {
+            return Err(crate::EbmlError::NotMasterElement(node.id));
+        }
+        let mut value = Self::default();
+        for child in node.children() {
+            match child.id {
+                Block::ID => value.block = Some(Block::from_node(child)?),
+                BlockVirtual::ID => value.block_virtual = Some(BlockVirtual::from_node(child)?),
+                BlockAdditions::ID => value.block_additions = Some(BlockAdditions::from_node(child)?),
+                BlockDuration::ID => value.block_duration = Some(BlockDuration::from_node(child)?),
+                ReferencePriority::ID => value.reference_priority = Some(ReferencePriority::from_node(child)?),
+                ReferenceBlock::ID => value.reference_block.push(ReferenceBlock::from_node(child)?),
+                ReferenceVirtual::ID => value.reference_virtual = Some(ReferenceVirtual::from_node(child)?),
+                CodecState::ID => value.codec_state = Some(CodecState::from_node(child)?),
+                DiscardPadding::ID => value.discard_padding = Some(DiscardPadding::from_node(child)?),
+                Slices::ID => value.slices = Some(Slices::from_node(child)?),
+                ReferenceFrame::ID => value.reference_frame = Some(ReferenceFrame::from_node(child)?),
+                0xBF | 0xEC => {}
+                _ => value.unknown.push(child.clone().into_owned()),
+            }
+        }
+        Ok(value)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        let mut children = Vec::new();
+        if let Some(child) = &self.block {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.block_virtual {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.block_additions {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.block_duration {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.reference_priority {
+            children.push(child.to_node());
+        }
+        for child in &self.reference_block {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.reference_virtual {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.codec_state {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.discard_padding {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.slices {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.reference_frame {
+            children.push(child.to_node());
+        }
+        children.extend(self.unknown.iter().cloned());
+        crate::EbmlNode::master(Self::ID, children)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Block {
+    pub value: Vec<u8>,
+}
+
+impl Block {
+    pub const ID: u64 = 0xA1;
+    pub const NAME: &str = "Block";
+    pub const PATH: &str = "\\Segment\\Cluster\\BlockGroup\\Block";
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: Vec<u8>) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(data.to_vec()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::binary(Self::ID, self.value.clone())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlockVirtual {
+    pub value: Vec<u8>,
+}
+
+impl BlockVirtual {
+    pub const ID: u64 = 0xA2;
+    pub const NAME: &str = "BlockVirtual";
+    pub const PATH: &str = "\\Segment\\Cluster\\BlockGroup\\BlockVirtual";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: Vec<u8>) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(data.to_vec()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::binary(Self::ID, self.value.clone())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct BlockAdditions {
+    pub block_more: Vec<BlockMore>,
+    /// Children the schema doesn't define for this element
+    pub unknown: Vec<crate::EbmlNode<'static>>,
+}
+
+impl BlockAdditions {
+    pub const ID: u64 = 0x75A1;
+    pub const NAME: &str = "BlockAdditions";
+    pub const PATH: &str = "\\Segment\\Cluster\\BlockGroup\\BlockAdditions";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        if !node.is_master() {
+            return Err(crate::EbmlError::NotMasterElement(node.id));
+        }
+        let mut value = Self::default();
+        for child in node.children() {
+            match child.id {
+                BlockMore::ID => value.block_more.push(BlockMore::from_node(child)?),
+                0xBF | 0xEC => {}
+                _ => value.unknown.push(child.clone().into_owned()),
+            }
+        }
+        Ok(value)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        let mut children = Vec::new();
+        for child in &self.block_more {
+            children.push(child.to_node());
+        }
+        children.extend(self.unknown.iter().cloned());
+        crate::EbmlNode::master(Self::ID, children)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct BlockMore {
+    pub block_additional: Option<BlockAdditional>,
+    pub block_add_id: Option<BlockAddID>,
+    /// Children the schema doesn't define for this element
+    pub unknown: Vec<crate::EbmlNode<'static>>,
+}
+
+impl BlockMore {
+    pub const ID: u64 = 0xA6;
+    pub const NAME: &str = "BlockMore";
+    pub const PATH: &str = "\\Segment\\Cluster\\BlockGroup\\BlockAdditions\\BlockMore";
+    pub const MIN_OCCURS: u64 = 1;
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        if !node.is_master() {
+            return Err(crate::EbmlError::NotMasterElement(node.id));
+        }
+        let mut value = Self::default();
+        for child in node.children() {
+            match child.id {
+                BlockAdditional::ID => value.block_additional = Some(BlockAdditional::from_node(child)?),
+                BlockAddID::ID => value.block_add_id = Some(BlockAddID::from_node(child)?),
+                0xBF | 0xEC => {}
+                _ => value.unknown.push(child.clone().into_owned()),
+            }
+        }
+        Ok(value)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        let mut children = Vec::new();
+        if let Some(child) = &self.block_additional {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.block_add_id {
+            children.push(child.to_node());
+        }
+        children.extend(self.unknown.iter().cloned());
+        crate::EbmlNode::master(Self::ID, children)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlockAdditional {
+    pub value: Vec<u8>,
+}
+
+impl BlockAdditional {
+    pub const ID: u64 = 0xA5;
+    pub const NAME: &str = "BlockAdditional";
+    pub const PATH: &str = "\\Segment\\Cluster\\BlockGroup\\BlockAdditions\\BlockMore\\BlockAdditional";
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: Vec<u8>) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(data.to_vec()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::binary(Self::ID, self.value.clone())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlockAddID {
+    pub value: u64,
+}
+
+impl BlockAddID {
+    pub const ID: u64 = 0xEE;
+    pub const NAME: &str = "BlockAddID";
+    pub const PATH: &str = "\\Segment\\Cluster\\BlockGroup\\BlockAdditions\\BlockMore\\BlockAddID";
+    pub const DEFAULT: u64 = 1;
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data, an empty element stands for `DEFAULT`
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        if data.is_empty() {
+            return Ok(Self::new(Self::DEFAULT));
+        }
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlockDuration {
+    pub value: u64,
+}
+
+impl BlockDuration {
+    pub const ID: u64 = 0x9B;
+    pub const NAME: &str = "BlockDuration";
+    pub const PATH: &str = "\\Segment\\Cluster\\BlockGroup\\BlockDuration";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReferencePriority {
+    pub value: u64,
+}
+
+impl ReferencePriority {
+    pub const ID: u64 = 0xFA;
+    pub const NAME: &str = "ReferencePriority";
+    pub const PATH: &str = "\\Segment\\Cluster\\BlockGroup\\ReferencePriority";
+    pub const DEFAULT: u64 = 0;
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data, an empty element stands for `DEFAULT`
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        if data.is_empty() {
+            return Ok(Self::new(Self::DEFAULT));
+        }
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReferenceBlock {
+    pub value: i64,
+}
+
+impl ReferenceBlock {
+    pub const ID: u64 = 0xFB;
+    pub const NAME: &str = "ReferenceBlock";
+    pub const PATH: &str = "\\Segment\\Cluster\\BlockGroup\\ReferenceBlock";
+    pub const MIN_OCCURS: u64 = 0;
+
+    pub fn new(value: i64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlSignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::signed(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReferenceVirtual {
+    pub value: i64,
+}
+
+impl ReferenceVirtual {
+    pub const ID: u64 = 0xFD;
+    pub const NAME: &str = "ReferenceVirtual";
+    pub const PATH: &str = "\\Segment\\Cluster\\BlockGroup\\ReferenceVirtual";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: i64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlSignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::signed(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CodecState {
+    pub value: Vec<u8>,
+}
+
+impl CodecState {
+    pub const ID: u64 = 0xA4;
+    pub const NAME: &str = "CodecState";
+    pub const PATH: &str = "\\Segment\\Cluster\\BlockGroup\\CodecState";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: Vec<u8>) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(data.to_vec()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::binary(Self::ID, self.value.clone())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DiscardPadding {
+    pub value: i64,
+}
+
+impl DiscardPadding {
+    pub const ID: u64 = 0x75A2;
+    pub const NAME: &str = "DiscardPadding";
+    pub const PATH: &str = "\\Segment\\Cluster\\BlockGroup\\DiscardPadding";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: i64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlSignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::signed(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Slices {
+    pub time_slice: Vec<TimeSlice>,
+    /// Children the schema doesn't define for this element
+    pub unknown: Vec<crate::EbmlNode<'static>>,
+}
+
+impl Slices {
+    pub const ID: u64 = 0x8E;
+    pub const NAME: &str = "Slices";
+    pub const PATH: &str = "\\Segment\\Cluster\\BlockGroup\\Slices";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        if !node.is_master() {
+            return Err(crate::EbmlError::NotMasterElement(node.id));
+        }
+        let mut value = Self::default();
+        for child in node.children() {
+            match child.id {
+                TimeSlice::ID => value.time_slice.push(TimeSlice::from_node(child)?),
+                0xBF | 0xEC => {}
+                _ => value.unknown.push(child.clone().into_owned()),
+            }
+        }
+        Ok(value)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        let mut children = Vec::new();
+        for child in &self.time_slice {
+            children.push(child.to_node());
+        }
+        children.extend(self.unknown.iter().cloned());
+        crate::EbmlNode::master(Self::ID, children)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TimeSlice {
+    pub lace_number: Option<LaceNumber>,
+    pub frame_number: Option<FrameNumber>,
+    pub block_addition_id: Option<BlockAdditionID>,
+    pub delay: Option<Delay>,
+    pub slice_duration: Option<SliceDuration>,
+    /// Children the schema doesn't define for this element
+    pub unknown: Vec<crate::EbmlNode<'static>>,
+}
+
+impl TimeSlice {
+    pub const ID: u64 = 0xE8;
+    pub const NAME: &str = "TimeSlice";
+    pub const PATH: &str = "\\Segment\\Cluster\\BlockGroup\\Slices\\TimeSlice";
+    pub const MIN_OCCURS: u64 = 0;
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        if !node.is_master() {
+            return Err(crate::EbmlError::NotMasterElement(node.id));
+        }
+        let mut value = Self::default();
+        for child in node.children() {
+            match child.id {
+                LaceNumber::ID => value.lace_number = Some(LaceNumber::from_node(child)?),
+                FrameNumber::ID => value.frame_number = Some(FrameNumber::from_node(child)?),
+                BlockAdditionID::ID => value.block_addition_id = Some(BlockAdditionID::from_node(child)?),
+                Delay::ID => value.delay = Some(Delay::from_node(child)?),
+                SliceDuration::ID => value.slice_duration = Some(SliceDuration::from_node(child)?),
+                0xBF | 0xEC => {}
+                _ => value.unknown.push(child.clone().into_owned()),
+            }
+        }
+        Ok(value)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        let mut children = Vec::new();
+        if let Some(child) = &self.lace_number {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.frame_number {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.block_addition_id {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.delay {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.slice_duration {
+            children.push(child.to_node());
+        }
+        children.extend(self.unknown.iter().cloned());
+        crate::EbmlNode::master(Self::ID, children)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LaceNumber {
+    pub value: u64,
+}
+
+impl LaceNumber {
+    pub const ID: u64 = 0xCC;
+    pub const NAME: &str = "LaceNumber";
+    pub const PATH: &str = "\\Segment\\Cluster\\BlockGroup\\Slices\\TimeSlice\\LaceNumber";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FrameNumber {
+    pub value: u64,
+}
+
+impl FrameNumber {
+    pub const ID: u64 = 0xCD;
+    pub const NAME: &str = "FrameNumber";
+    pub const PATH: &str = "\\Segment\\Cluster\\BlockGroup\\Slices\\TimeSlice\\FrameNumber";
+    pub const DEFAULT: u64 = 0;
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data, an empty element stands for `DEFAULT`
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        if data.is_empty() {
+            return Ok(Self::new(Self::DEFAULT));
+        }
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlockAdditionID {
+    pub value: u64,
+}
+
+impl BlockAdditionID {
+    pub const ID: u64 = 0xCB;
+    pub const NAME: &str = "BlockAdditionID";
+    pub const PATH: &str = "\\Segment\\Cluster\\BlockGroup\\Slices\\TimeSlice\\BlockAdditionID";
+    pub const DEFAULT: u64 = 0;
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data, an empty element stands for `DEFAULT`
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        if data.is_empty() {
+            return Ok(Self::new(Self::DEFAULT));
+        }
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Delay {
+    pub value: u64,
+}
+
+impl Delay {
+    pub const ID: u64 = 0xCE;
+    pub const NAME: &str = "Delay";
+    pub const PATH: &str = "\\Segment\\Cluster\\BlockGroup\\Slices\\TimeSlice\\Delay";
+    pub const DEFAULT: u64 = 0;
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data, an empty element stands for `DEFAULT`
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        if data.is_empty() {
+            return Ok(Self::new(Self::DEFAULT));
+        }
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SliceDuration {
+    pub value: u64,
+}
+
+impl SliceDuration {
+    pub const ID: u64 = 0xCF;
+    pub const NAME: &str = "SliceDuration";
+    pub const PATH: &str = "\\Segment\\Cluster\\BlockGroup\\Slices\\TimeSlice\\SliceDuration";
+    pub const DEFAULT: u64 = 0;
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data, an empty element stands for `DEFAULT`
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        if data.is_empty() {
+            return Ok(Self::new(Self::DEFAULT));
+        }
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ReferenceFrame {
+    pub reference_offset: Option<ReferenceOffset>,
+    pub reference_timestamp: Option<ReferenceTimestamp>,
+    /// Children the schema doesn't define for this element
+    pub unknown: Vec<crate::EbmlNode<'static>>,
+}
+
+impl ReferenceFrame {
+    pub const ID: u64 = 0xC8;
+    pub const NAME: &str = "ReferenceFrame";
+    pub const PATH: &str = "\\Segment\\Cluster\\BlockGroup\\ReferenceFrame";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        if !node.is_master() {
+            return Err(crate::EbmlError::NotMasterElement(node.id));
+        }
+        let mut value = Self::default();
+        for child in node.children() {
+            match child.id {
+                ReferenceOffset::ID => value.reference_offset = Some(ReferenceOffset::from_node(child)?),
+                ReferenceTimestamp::ID => value.reference_timestamp = Some(ReferenceTimestamp::from_node(child)?),
+                0xBF | 0xEC => {}
+                _ => value.unknown.push(child.clone().into_owned()),
+            }
+        }
+        Ok(value)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        let mut children = Vec::new();
+        if let Some(child) = &self.reference_offset {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.reference_timestamp {
+            children.push(child.to_node());
+        }
+        children.extend(self.unknown.iter().cloned());
+        crate::EbmlNode::master(Self::ID, children)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReferenceOffset {
+    pub value: u64,
+}
+
+impl ReferenceOffset {
+    pub const ID: u64 = 0xC9;
+    pub const NAME: &str = "ReferenceOffset";
+    pub const PATH: &str = "\\Segment\\Cluster\\BlockGroup\\ReferenceFrame\\ReferenceOffset";
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReferenceTimestamp {
+    pub value: u64,
+}
+
+impl ReferenceTimestamp {
+    pub const ID: u64 = 0xCA;
+    pub const NAME: &str = "ReferenceTimestamp";
+    pub const PATH: &str = "\\Segment\\Cluster\\BlockGroup\\ReferenceFrame\\ReferenceTimestamp";
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EncryptedBlock {
+    pub value: Vec<u8>,
+}
+
+impl EncryptedBlock {
+    pub const ID: u64 = 0xAF;
+    pub const NAME: &str = "EncryptedBlock";
+    pub const PATH: &str = "\\Segment\\Cluster\\EncryptedBlock";
+    pub const MIN_OCCURS: u64 = 0;
+
+    pub fn new(value: Vec<u8>) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(data.to_vec()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::binary(Self::ID, self.value.clone())
+    }
+}
+
+/// A Top-Level Element of information with many tracks described.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Tracks {
+    pub track_entry: Vec<TrackEntry>,
+    /// Children the schema doesn't define for this element
+    pub unknown: Vec<crate::EbmlNode<'static>>,
+}
+
+impl Tracks {
+    pub const ID: u64 = 0x1654AE6B;
+    pub const NAME: &str = "Tracks";
+    pub const PATH: &str = "\\Segment\\Tracks";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        if !node.is_master() {
+            return Err(crate::EbmlError::NotMasterElement(node.id));
+        }
+        let mut value = Self::default();
+        for child in node.children() {
+            match child.id {
+                TrackEntry::ID => value.track_entry.push(TrackEntry::from_node(child)?),
+                0xBF | 0xEC => {}
+                _ => value.unknown.push(child.clone().into_owned()),
+            }
+        }
+        Ok(value)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        let mut children = Vec::new();
+        for child in &self.track_entry {
+            children.push(child.to_node());
+        }
+        children.extend(self.unknown.iter().cloned());
+        crate::EbmlNode::master(Self::ID, children)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TrackEntry {
+    pub track_number: Option<TrackNumber>,
+    pub track_uid: Option<TrackUID>,
+    pub track_type: Option<TrackType>,
+    pub flag_enabled: Option<FlagEnabled>,
+    pub flag_default: Option<FlagDefault>,
+    pub flag_forced: Option<FlagForced>,
+    pub flag_hearing_impaired: Option<FlagHearingImpaired>,
+    pub flag_visual_impaired: Option<FlagVisualImpaired>,
+    pub flag_text_descriptions: Option<FlagTextDescriptions>,
+    pub flag_original: Option<FlagOriginal>,
+    pub flag_commentary: Option<FlagCommentary>,
+    pub flag_lacing: Option<FlagLacing>,
+    pub min_cache: Option<MinCache>,
+    pub max_cache: Option<MaxCache>,
+    pub default_duration: Option<DefaultDuration>,
+    pub default_decoded_field_duration: Option<DefaultDecodedFieldDuration>,
+    pub track_timestamp_scale: Option<TrackTimestampScale>,
+    pub track_offset: Option<TrackOffset>,
+    pub max_block_addition_id: Option<MaxBlockAdditionID>,
+    pub block_addition_mapping: Vec<BlockAdditionMapping>,
+    pub name: Option<Name>,
+    pub language: Option<Language>,
+    pub language_bcp47: Option<LanguageBCP47>,
+    pub codec_id: Option<CodecID>,
+    pub codec_private: Option<CodecPrivate>,
+    pub codec_name: Option<CodecName>,
+    pub attachment_link: Option<AttachmentLink>,
+    pub codec_settings: Option<CodecSettings>,
+    pub codec_info_url: Vec<CodecInfoURL>,
+    pub codec_download_url: Vec<CodecDownloadURL>,
+    pub codec_decode_all: Option<CodecDecodeAll>,
+    pub track_overlay: Vec<TrackOverlay>,
+    pub codec_delay: Option<CodecDelay>,
+    pub seek_pre_roll: Option<SeekPreRoll>,
+    pub track_translate: Vec<TrackTranslate>,
+    pub video: Option<Video>,
+    pub audio: Option<Audio>,
+    pub track_operation: Option<TrackOperation>,
+    pub trick_track_uid: Option<TrickTrackUID>,
+    pub trick_track_segment_uid: Option<TrickTrackSegmentUID>,
+    pub trick_track_flag: Option<TrickTrackFlag>,
+    pub trick_master_track_uid: Option<TrickMasterTrackUID>,
+    pub trick_master_track_segment_uid: Option<TrickMasterTrackSegmentUID>,
+    pub content_encodings: Option<ContentEncodings>,
+    /// Children the schema doesn't define for this element
+    pub unknown: Vec<crate::EbmlNode<'static>>,
+}
+
+impl TrackEntry {
+    pub const ID: u64 = 0xAE;
+    pub const NAME: &str = "TrackEntry";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry";
+    pub const MIN_OCCURS: u64 = 1;
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        if !node.is_master() {
+            return Err(crate::EbmlError::NotMasterElement(node.id));
+        }
+        let mut value = Self::default();
+        for child in node.children() {
+            match child.id {
+                TrackNumber::ID => value.track_number = Some(TrackNumber::from_node(child)?),
+                TrackUID::ID => value.track_uid = Some(TrackUID::from_node(child)?),
+                TrackType::ID => value.track_type = Some(TrackType::from_node(child)?),
+                FlagEnabled::ID => value.flag_enabled = Some(FlagEnabled::from_node(child)?),
+                FlagDefault::ID => value.flag_default = Some(FlagDefault::from_node(child)?),
+                FlagForced::ID => value.flag_forced = Some(FlagForced::from_node(child)?),
+                FlagHearingImpaired::ID => value.flag_hearing_impaired = Some(FlagHearingImpaired::from_node(child)?),
+                FlagVisualImpaired::ID => value.flag_visual_impaired = Some(FlagVisualImpaired::from_node(child)?),
+                FlagTextDescriptions::ID => value.flag_text_descriptions = Some(FlagTextDescriptions::from_node(child)?),
+                FlagOriginal::ID => value.flag_original = Some(FlagOriginal::from_node(child)?),
+                FlagCommentary::ID => value.flag_commentary = Some(FlagCommentary::from_node(child)?),
+                FlagLacing::ID => value.flag_lacing = Some(FlagLacing::from_node(child)?),
+                MinCache::ID => value.min_cache = Some(MinCache::from_node(child)?),
+                MaxCache::ID => value.max_cache = Some(MaxCache::from_node(child)?),
+                DefaultDuration::ID => value.default_duration = Some(DefaultDuration::from_node(child)?),
+                DefaultDecodedFieldDuration::ID => value.default_decoded_field_duration = Some(DefaultDecodedFieldDuration::from_node(child)?),
+                TrackTimestampScale::ID => value.track_timestamp_scale = Some(TrackTimestampScale::from_node(child)?),
+                TrackOffset::ID => value.track_offset = Some(TrackOffset::from_node(child)?),
+                MaxBlockAdditionID::ID => value.max_block_addition_id = Some(MaxBlockAdditionID::from_node(child)?),
+                BlockAdditionMapping::ID => value.block_addition_mapping.push(BlockAdditionMapping::from_node(child)?),
+                Name::ID => value.name = Some(Name::from_node(child)?),
+                Language::ID => value.language = Some(Language::from_node(child)?),
+                LanguageBCP47::ID => value.language_bcp47 = Some(LanguageBCP47::from_node(child)?),
+                CodecID::ID => value.codec_id = Some(CodecID::from_node(child)?),
+                CodecPrivate::ID => value.codec_private = Some(CodecPrivate::from_node(child)?),
+                CodecName::ID => value.codec_name = Some(CodecName::from_node(child)?),
+                AttachmentLink::ID => value.attachment_link = Some(AttachmentLink::from_node(child)?),
+                CodecSettings::ID => value.codec_settings = Some(CodecSettings::from_node(child)?),
+                CodecInfoURL::ID => value.codec_info_url.push(CodecInfoURL::from_node(child)?),
+                CodecDownloadURL::ID => value.codec_download_url.push(CodecDownloadURL::from_node(child)?),
+                CodecDecodeAll::ID => value.codec_decode_all = Some(CodecDecodeAll::from_node(child)?),
+                TrackOverlay::ID => value.track_overlay.push(TrackOverlay::from_node(child)?),
+                CodecDelay::ID => value.codec_delay = Some(CodecDelay::from_node(child)?),
+                SeekPreRoll::ID => value.seek_pre_roll = Some(SeekPreRoll::from_node(child)?),
+                TrackTranslate::ID => value.track_translate.push(TrackTranslate::from_node(child)?),
+                Video::ID => value.video = Some(Video::from_node(child)?),
+                Audio::ID => value.audio = Some(Audio::from_node(child)?),
+                TrackOperation::ID => value.track_operation = Some(TrackOperation::from_node(child)?),
+                TrickTrackUID::ID => value.trick_track_uid = Some(TrickTrackUID::from_node(child)?),
+                TrickTrackSegmentUID::ID => value.trick_track_segment_uid = Some(TrickTrackSegmentUID::from_node(child)?),
+                TrickTrackFlag::ID => value.trick_track_flag = Some(TrickTrackFlag::from_node(child)?),
+                TrickMasterTrackUID::ID => value.trick_master_track_uid = Some(TrickMasterTrackUID::from_node(child)?),
+                TrickMasterTrackSegmentUID::ID => value.trick_master_track_segment_uid = Some(TrickMasterTrackSegmentUID::from_node(child)?),
+                ContentEncodings::ID => value.content_encodings = Some(ContentEncodings::from_node(child)?),
+                0xBF | 0xEC => {}
+                _ => value.unknown.push(child.clone().into_owned()),
+            }
+        }
+        Ok(value)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        let mut children = Vec::new();
+        if let Some(child) = &self.track_number {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.track_uid {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.track_type {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.flag_enabled {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.flag_default {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.flag_forced {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.flag_hearing_impaired {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.flag_visual_impaired {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.flag_text_descriptions {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.flag_original {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.flag_commentary {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.flag_lacing {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.min_cache {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.max_cache {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.default_duration {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.default_decoded_field_duration {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.track_timestamp_scale {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.track_offset {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.max_block_addition_id {
+            children.push(child.to_node());
+        }
+        for child in &self.block_addition_mapping {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.name {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.language {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.language_bcp47 {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.codec_id {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.codec_private {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.codec_name {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.attachment_link {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.codec_settings {
+            children.push(child.to_node());
+        }
+        for child in &self.codec_info_url {
+            children.push(child.to_node());
+        }
+        for child in &self.codec_download_url {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.codec_decode_all {
+            children.push(child.to_node());
+        }
+        for child in &self.track_overlay {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.codec_delay {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.seek_pre_roll {
+            children.push(child.to_node());
+        }
+        for child in &self.track_translate {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.video {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.audio {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.track_operation {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.trick_track_uid {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.trick_track_segment_uid {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.trick_track_flag {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.trick_master_track_uid {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.trick_master_track_segment_uid {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.content_encodings {
+            children.push(child.to_node());
+        }
+        children.extend(self.unknown.iter().cloned());
+        crate::EbmlNode::master(Self::ID, children)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrackNumber {
+    pub value: u64,
+}
+
+impl TrackNumber {
+    pub const ID: u64 = 0xD7;
+    pub const NAME: &str = "TrackNumber";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\TrackNumber";
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrackUID {
+    pub value: u64,
+}
+
+impl TrackUID {
+    pub const ID: u64 = 0x73C5;
+    pub const NAME: &str = "TrackUID";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\TrackUID";
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrackType {
+    pub value: u64,
+}
+
+impl TrackType {
+    pub const ID: u64 = 0x83;
+    pub const NAME: &str = "TrackType";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\TrackType";
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FlagEnabled {
+    pub value: u64,
+}
+
+impl FlagEnabled {
+    pub const ID: u64 = 0xB9;
+    pub const NAME: &str = "FlagEnabled";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\FlagEnabled";
+    pub const DEFAULT: u64 = 1;
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data, an empty element stands for `DEFAULT`
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        if data.is_empty() {
+            return Ok(Self::new(Self::DEFAULT));
+        }
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FlagDefault {
+    pub value: u64,
+}
+
+impl FlagDefault {
+    pub const ID: u64 = 0x88;
+    pub const NAME: &str = "FlagDefault";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\FlagDefault";
+    pub const DEFAULT: u64 = 1;
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data, an empty element stands for `DEFAULT`
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        if data.is_empty() {
+            return Ok(Self::new(Self::DEFAULT));
+        }
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FlagForced {
+    pub value: u64,
+}
+
+impl FlagForced {
+    pub const ID: u64 = 0x55AA;
+    pub const NAME: &str = "FlagForced";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\FlagForced";
+    pub const DEFAULT: u64 = 0;
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data, an empty element stands for `DEFAULT`
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        if data.is_empty() {
+            return Ok(Self::new(Self::DEFAULT));
+        }
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FlagHearingImpaired {
+    pub value: u64,
+}
+
+impl FlagHearingImpaired {
+    pub const ID: u64 = 0x55AB;
+    pub const NAME: &str = "FlagHearingImpaired";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\FlagHearingImpaired";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FlagVisualImpaired {
+    pub value: u64,
+}
+
+impl FlagVisualImpaired {
+    pub const ID: u64 = 0x55AC;
+    pub const NAME: &str = "FlagVisualImpaired";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\FlagVisualImpaired";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FlagTextDescriptions {
+    pub value: u64,
+}
+
+impl FlagTextDescriptions {
+    pub const ID: u64 = 0x55AD;
+    pub const NAME: &str = "FlagTextDescriptions";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\FlagTextDescriptions";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FlagOriginal {
+    pub value: u64,
+}
+
+impl FlagOriginal {
+    pub const ID: u64 = 0x55AE;
+    pub const NAME: &str = "FlagOriginal";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\FlagOriginal";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FlagCommentary {
+    pub value: u64,
+}
+
+impl FlagCommentary {
+    pub const ID: u64 = 0x55AF;
+    pub const NAME: &str = "FlagCommentary";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\FlagCommentary";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FlagLacing {
+    pub value: u64,
+}
+
+impl FlagLacing {
+    pub const ID: u64 = 0x9C;
+    pub const NAME: &str = "FlagLacing";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\FlagLacing";
+    pub const DEFAULT: u64 = 1;
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data, an empty element stands for `DEFAULT`
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        if data.is_empty() {
+            return Ok(Self::new(Self::DEFAULT));
+        }
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MinCache {
+    pub value: u64,
+}
+
+impl MinCache {
+    pub const ID: u64 = 0x6DE7;
+    pub const NAME: &str = "MinCache";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\MinCache";
+    pub const DEFAULT: u64 = 0;
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data, an empty element stands for `DEFAULT`
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        if data.is_empty() {
+            return Ok(Self::new(Self::DEFAULT));
+        }
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MaxCache {
+    pub value: u64,
+}
+
+impl MaxCache {
+    pub const ID: u64 = 0x6DF8;
+    pub const NAME: &str = "MaxCache";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\MaxCache";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DefaultDuration {
+    pub value: u64,
+}
+
+impl DefaultDuration {
+    pub const ID: u64 = 0x23E383;
+    pub const NAME: &str = "DefaultDuration";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\DefaultDuration";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DefaultDecodedFieldDuration {
+    pub value: u64,
+}
+
+impl DefaultDecodedFieldDuration {
+    pub const ID: u64 = 0x234E7A;
+    pub const NAME: &str = "DefaultDecodedFieldDuration";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\DefaultDecodedFieldDuration";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrackTimestampScale {
+    pub value: f64,
+}
+
+impl TrackTimestampScale {
+    pub const ID: u64 = 0x23314F;
+    pub const NAME: &str = "TrackTimestampScale";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\TrackTimestampScale";
+    pub const DEFAULT: f64 = 1.0;
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: f64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data, an empty element stands for `DEFAULT`
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        if data.is_empty() {
+            return Ok(Self::new(Self::DEFAULT));
+        }
+        Ok(Self::new(crate::EbmlFloat::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::float(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrackOffset {
+    pub value: i64,
+}
+
+impl TrackOffset {
+    pub const ID: u64 = 0x537F;
+    pub const NAME: &str = "TrackOffset";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\TrackOffset";
+    pub const DEFAULT: i64 = 0;
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: i64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data, an empty element stands for `DEFAULT`
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        if data.is_empty() {
+            return Ok(Self::new(Self::DEFAULT));
+        }
+        Ok(Self::new(crate::EbmlSignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::signed(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MaxBlockAdditionID {
+    pub value: u64,
+}
+
+impl MaxBlockAdditionID {
+    pub const ID: u64 = 0x55EE;
+    pub const NAME: &str = "MaxBlockAdditionID";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\MaxBlockAdditionID";
+    pub const DEFAULT: u64 = 0;
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data, an empty element stands for `DEFAULT`
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        if data.is_empty() {
+            return Ok(Self::new(Self::DEFAULT));
+        }
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct BlockAdditionMapping {
+    pub block_add_id_value: Option<BlockAddIDValue>,
+    pub block_add_id_name: Option<BlockAddIDName>,
+    pub block_add_id_type: Option<BlockAddIDType>,
+    pub block_add_id_extra_data: Option<BlockAddIDExtraData>,
+    /// Children the schema doesn't define for this element
+    pub unknown: Vec<crate::EbmlNode<'static>>,
+}
+
+impl BlockAdditionMapping {
+    pub const ID: u64 = 0x41E4;
+    pub const NAME: &str = "BlockAdditionMapping";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\BlockAdditionMapping";
+    pub const MIN_OCCURS: u64 = 0;
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        if !node.is_master() {
+            return Err(crate::EbmlError::NotMasterElement(node.id));
+        }
+        let mut value = Self::default();
+        for child in node.children() {
+            match child.id {
+                BlockAddIDValue::ID => value.block_add_id_value = Some(BlockAddIDValue::from_node(child)?),
+                BlockAddIDName::ID => value.block_add_id_name = Some(BlockAddIDName::from_node(child)?),
+                BlockAddIDType::ID => value.block_add_id_type = Some(BlockAddIDType::from_node(child)?),
+                BlockAddIDExtraData::ID => value.block_add_id_extra_data = Some(BlockAddIDExtraData::from_node(child)?),
+                0xBF | 0xEC => {}
+                _ => value.unknown.push(child.clone().into_owned()),
+            }
+        }
+        Ok(value)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        let mut children = Vec::new();
+        if let Some(child) = &self.block_add_id_value {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.block_add_id_name {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.block_add_id_type {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.block_add_id_extra_data {
+            children.push(child.to_node());
+        }
+        children.extend(self.unknown.iter().cloned());
+        crate::EbmlNode::master(Self::ID, children)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlockAddIDValue {
+    pub value: u64,
+}
+
+impl BlockAddIDValue {
+    pub const ID: u64 = 0x41F0;
+    pub const NAME: &str = "BlockAddIDValue";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\BlockAdditionMapping\\BlockAddIDValue";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlockAddIDName {
+    pub value: String,
+}
+
+impl BlockAddIDName {
+    pub const ID: u64 = 0x41A4;
+    pub const NAME: &str = "BlockAddIDName";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\BlockAdditionMapping\\BlockAddIDName";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: impl Into<String>) -> Self {
+        Self { value: value.into() }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlString::new(data)?.as_str()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::string(Self::ID, &self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlockAddIDType {
+    pub value: u64,
+}
+
+impl BlockAddIDType {
+    pub const ID: u64 = 0x41E7;
+    pub const NAME: &str = "BlockAddIDType";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\BlockAdditionMapping\\BlockAddIDType";
+    pub const DEFAULT: u64 = 0;
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data, an empty element stands for `DEFAULT`
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        if data.is_empty() {
+            return Ok(Self::new(Self::DEFAULT));
+        }
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlockAddIDExtraData {
+    pub value: Vec<u8>,
+}
+
+impl BlockAddIDExtraData {
+    pub const ID: u64 = 0x41ED;
+    pub const NAME: &str = "BlockAddIDExtraData";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\BlockAdditionMapping\\BlockAddIDExtraData";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: Vec<u8>) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(data.to_vec()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::binary(Self::ID, self.value.clone())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Name {
+    pub value: String,
+}
+
+impl Name {
+    pub const ID: u64 = 0x536E;
+    pub const NAME: &str = "Name";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\Name";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: impl Into<String>) -> Self {
+        Self { value: value.into() }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUtf8::new(data)?.as_str()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::string(Self::ID, &self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Language {
+    pub value: String,
+}
+
+impl Language {
+    pub const ID: u64 = 0x22B59C;
+    pub const NAME: &str = "Language";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\Language";
+    pub const DEFAULT: &str = "eng";
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: impl Into<String>) -> Self {
+        Self { value: value.into() }
+    }
+
+    /// Decodes the element's data, an empty element stands for `DEFAULT`
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        if data.is_empty() {
+            return Ok(Self::new(Self::DEFAULT));
+        }
+        Ok(Self::new(crate::EbmlString::new(data)?.as_str()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::string(Self::ID, &self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LanguageBCP47 {
+    pub value: String,
+}
+
+impl LanguageBCP47 {
+    pub const ID: u64 = 0x22B59D;
+    pub const NAME: &str = "LanguageBCP47";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\LanguageBCP47";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: impl Into<String>) -> Self {
+        Self { value: value.into() }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlString::new(data)?.as_str()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::string(Self::ID, &self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CodecID {
+    pub value: String,
+}
+
+impl CodecID {
+    pub const ID: u64 = 0x86;
+    pub const NAME: &str = "CodecID";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\CodecID";
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: impl Into<String>) -> Self {
+        Self { value: value.into() }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlString::new(data)?.as_str()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::string(Self::ID, &self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CodecPrivate {
+    pub value: Vec<u8>,
+}
+
+impl CodecPrivate {
+    pub const ID: u64 = 0x63A2;
+    pub const NAME: &str = "CodecPrivate";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\CodecPrivate";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: Vec<u8>) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(data.to_vec()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::binary(Self::ID, self.value.clone())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CodecName {
+    pub value: String,
+}
+
+impl CodecName {
+    pub const ID: u64 = 0x258688;
+    pub const NAME: &str = "CodecName";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\CodecName";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: impl Into<String>) -> Self {
+        Self { value: value.into() }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUtf8::new(data)?.as_str()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::string(Self::ID, &self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AttachmentLink {
+    pub value: u64,
+}
+
+impl AttachmentLink {
+    pub const ID: u64 = 0x7446;
+    pub const NAME: &str = "AttachmentLink";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\AttachmentLink";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CodecSettings {
+    pub value: String,
+}
+
+impl CodecSettings {
+    pub const ID: u64 = 0x3A9697;
+    pub const NAME: &str = "CodecSettings";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\CodecSettings";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: impl Into<String>) -> Self {
+        Self { value: value.into() }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUtf8::new(data)?.as_str()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::string(Self::ID, &self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CodecInfoURL {
+    pub value: String,
+}
+
+impl CodecInfoURL {
+    pub const ID: u64 = 0x3B4040;
+    pub const NAME: &str = "CodecInfoURL";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\CodecInfoURL";
+    pub const MIN_OCCURS: u64 = 0;
+
+    pub fn new(value: impl Into<String>) -> Self {
+        Self { value: value.into() }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlString::new(data)?.as_str()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::string(Self::ID, &self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CodecDownloadURL {
+    pub value: String,
+}
+
+impl CodecDownloadURL {
+    pub const ID: u64 = 0x26B240;
+    pub const NAME: &str = "CodecDownloadURL";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\CodecDownloadURL";
+    pub const MIN_OCCURS: u64 = 0;
+
+    pub fn new(value: impl Into<String>) -> Self {
+        Self { value: value.into() }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlString::new(data)?.as_str()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::string(Self::ID, &self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CodecDecodeAll {
+    pub value: u64,
+}
+
+impl CodecDecodeAll {
+    pub const ID: u64 = 0xAA;
+    pub const NAME: &str = "CodecDecodeAll";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\CodecDecodeAll";
+    pub const DEFAULT: u64 = 1;
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data, an empty element stands for `DEFAULT`
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        if data.is_empty() {
+            return Ok(Self::new(Self::DEFAULT));
+        }
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrackOverlay {
+    pub value: u64,
+}
+
+impl TrackOverlay {
+    pub const ID: u64 = 0x6FAB;
+    pub const NAME: &str = "TrackOverlay";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\TrackOverlay";
+    pub const MIN_OCCURS: u64 = 0;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CodecDelay {
+    pub value: u64,
+}
+
+impl CodecDelay {
+    pub const ID: u64 = 0x56AA;
+    pub const NAME: &str = "CodecDelay";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\CodecDelay";
+    pub const DEFAULT: u64 = 0;
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data, an empty element stands for `DEFAULT`
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        if data.is_empty() {
+            return Ok(Self::new(Self::DEFAULT));
+        }
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SeekPreRoll {
+    pub value: u64,
+}
+
+impl SeekPreRoll {
+    pub const ID: u64 = 0x56BB;
+    pub const NAME: &str = "SeekPreRoll";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\SeekPreRoll";
+    pub const DEFAULT: u64 = 0;
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data, an empty element stands for `DEFAULT`
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        if data.is_empty() {
+            return Ok(Self::new(Self::DEFAULT));
+        }
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TrackTranslate {
+    pub track_translate_track_id: Option<TrackTranslateTrackID>,
+    pub track_translate_codec: Option<TrackTranslateCodec>,
+    pub track_translate_edition_uid: Vec<TrackTranslateEditionUID>,
+    /// Children the schema doesn't define for this element
+    pub unknown: Vec<crate::EbmlNode<'static>>,
+}
+
+impl TrackTranslate {
+    pub const ID: u64 = 0x6624;
+    pub const NAME: &str = "TrackTranslate";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\TrackTranslate";
+    pub const MIN_OCCURS: u64 = 0;
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        if !node.is_master() {
+            return Err(crate::EbmlError::NotMasterElement(node.id));
+        }
+        let mut value = Self::default();
+        for child in node.children() {
+            match child.id {
+                TrackTranslateTrackID::ID => value.track_translate_track_id = Some(TrackTranslateTrackID::from_node(child)?),
+                TrackTranslateCodec::ID => value.track_translate_codec = Some(TrackTranslateCodec::from_node(child)?),
+                TrackTranslateEditionUID::ID => value.track_translate_edition_uid.push(TrackTranslateEditionUID::from_node(child)?),
+                0xBF | 0xEC => {}
+                _ => value.unknown.push(child.clone().into_owned()),
+            }
+        }
+        Ok(value)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        let mut children = Vec::new();
+        if let Some(child) = &self.track_translate_track_id {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.track_translate_codec {
+            children.push(child.to_node());
+        }
+        for child in &self.track_translate_edition_uid {
+            children.push(child.to_node());
+        }
+        children.extend(self.unknown.iter().cloned());
+        crate::EbmlNode::master(Self::ID, children)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrackTranslateTrackID {
+    pub value: Vec<u8>,
+}
+
+impl TrackTranslateTrackID {
+    pub const ID: u64 = 0x66A5;
+    pub const NAME: &str = "TrackTranslateTrackID";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\TrackTranslate\\TrackTranslateTrackID";
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: Vec<u8>) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(data.to_vec()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::binary(Self::ID, self.value.clone())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrackTranslateCodec {
+    pub value: u64,
+}
+
+impl TrackTranslateCodec {
+    pub const ID: u64 = 0x66BF;
+    pub const NAME: &str = "TrackTranslateCodec";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\TrackTranslate\\TrackTranslateCodec";
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrackTranslateEditionUID {
+    pub value: u64,
+}
+
+impl TrackTranslateEditionUID {
+    pub const ID: u64 = 0x66FC;
+    pub const NAME: &str = "TrackTranslateEditionUID";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\TrackTranslate\\TrackTranslateEditionUID";
+    pub const MIN_OCCURS: u64 = 0;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Video {
+    pub flag_interlaced: Option<FlagInterlaced>,
+    pub field_order: Option<FieldOrder>,
+    pub stereo_mode: Option<StereoMode>,
+    pub alpha_mode: Option<AlphaMode>,
+    pub old_stereo_mode: Option<OldStereoMode>,
+    pub pixel_width: Option<PixelWidth>,
+    pub pixel_height: Option<PixelHeight>,
+    pub pixel_crop_bottom: Option<PixelCropBottom>,
+    pub pixel_crop_top: Option<PixelCropTop>,
+    pub pixel_crop_left: Option<PixelCropLeft>,
+    pub pixel_crop_right: Option<PixelCropRight>,
+    pub display_width: Option<DisplayWidth>,
+    pub display_height: Option<DisplayHeight>,
+    pub display_unit: Option<DisplayUnit>,
+    pub aspect_ratio_type: Option<AspectRatioType>,
+    pub uncompressed_four_cc: Option<UncompressedFourCC>,
+    pub gamma_value: Option<GammaValue>,
+    pub frame_rate: Option<FrameRate>,
+    pub colour: Option<Colour>,
+    pub projection: Option<Projection>,
+    /// Children the schema doesn't define for this element
+    pub unknown: Vec<crate::EbmlNode<'static>>,
+}
+
+impl Video {
+    pub const ID: u64 = 0xE0;
+    pub const NAME: &str = "Video";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\Video";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        if !node.is_master() {
+            return Err(crate::EbmlError::NotMasterElement(node.id));
+        }
+        let mut value = Self::default();
+        for child in node.children() {
+            match child.id {
+                FlagInterlaced::ID => value.flag_interlaced = Some(FlagInterlaced::from_node(child)?),
+                FieldOrder::ID => value.field_order = Some(FieldOrder::from_node(child)?),
+                StereoMode::ID => value.stereo_mode = Some(StereoMode::from_node(child)?),
+                AlphaMode::ID => value.alpha_mode = Some(AlphaMode::from_node(child)?),
+                OldStereoMode::ID => value.old_stereo_mode = Some(OldStereoMode::from_node(child)?),
+                PixelWidth::ID => value.pixel_width = Some(PixelWidth::from_node(child)?),
+                PixelHeight::ID => value.pixel_height = Some(PixelHeight::from_node(child)?),
+                PixelCropBottom::ID => value.pixel_crop_bottom = Some(PixelCropBottom::from_node(child)?),
+                PixelCropTop::ID => value.pixel_crop_top = Some(PixelCropTop::from_node(child)?),
+                PixelCropLeft::ID => value.pixel_crop_left = Some(PixelCropLeft::from_node(child)?),
+                PixelCropRight::ID => value.pixel_crop_right = Some(PixelCropRight::from_node(child)?),
+                DisplayWidth::ID => value.display_width = Some(DisplayWidth::from_node(child)?),
+                DisplayHeight::ID => value.display_height = Some(DisplayHeight::from_node(child)?),
+                DisplayUnit::ID => value.display_unit = Some(DisplayUnit::from_node(child)?),
+                AspectRatioType::ID => value.aspect_ratio_type = Some(AspectRatioType::from_node(child)?),
+                UncompressedFourCC::ID => value.uncompressed_four_cc = Some(UncompressedFourCC::from_node(child)?),
+                GammaValue::ID => value.gamma_value = Some(GammaValue::from_node(child)?),
+                FrameRate::ID => value.frame_rate = Some(FrameRate::from_node(child)?),
+                Colour::ID => value.colour = Some(Colour::from_node(child)?),
+                Projection::ID => value.projection = Some(Projection::from_node(child)?),
+                0xBF | 0xEC => {}
+                _ => value.unknown.push(child.clone().into_owned()),
+            }
+        }
+        Ok(value)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        let mut children = Vec::new();
+        if let Some(child) = &self.flag_interlaced {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.field_order {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.stereo_mode {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.alpha_mode {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.old_stereo_mode {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.pixel_width {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.pixel_height {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.pixel_crop_bottom {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.pixel_crop_top {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.pixel_crop_left {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.pixel_crop_right {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.display_width {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.display_height {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.display_unit {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.aspect_ratio_type {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.uncompressed_four_cc {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.gamma_value {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.frame_rate {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.colour {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.projection {
+            children.push(child.to_node());
+        }
+        children.extend(self.unknown.iter().cloned());
+        crate::EbmlNode::master(Self::ID, children)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FlagInterlaced {
+    pub value: u64,
+}
+
+impl FlagInterlaced {
+    pub const ID: u64 = 0x9A;
+    pub const NAME: &str = "FlagInterlaced";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\Video\\FlagInterlaced";
+    pub const DEFAULT: u64 = 0;
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data, an empty element stands for `DEFAULT`
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        if data.is_empty() {
+            return Ok(Self::new(Self::DEFAULT));
+        }
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldOrder {
+    pub value: u64,
+}
+
+impl FieldOrder {
+    pub const ID: u64 = 0x9D;
+    pub const NAME: &str = "FieldOrder";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\Video\\FieldOrder";
+    pub const DEFAULT: u64 = 2;
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data, an empty element stands for `DEFAULT`
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        if data.is_empty() {
+            return Ok(Self::new(Self::DEFAULT));
+        }
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct StereoMode {
+    pub value: u64,
+}
+
+impl StereoMode {
+    pub const ID: u64 = 0x53B8;
+    pub const NAME: &str = "StereoMode";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\Video\\StereoMode";
+    pub const DEFAULT: u64 = 0;
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data, an empty element stands for `DEFAULT`
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        if data.is_empty() {
+            return Ok(Self::new(Self::DEFAULT));
+        }
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AlphaMode {
+    pub value: u64,
+}
+
+impl AlphaMode {
+    pub const ID: u64 = 0x53C0;
+    pub const NAME: &str = "AlphaMode";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\Video\\AlphaMode";
+    pub const DEFAULT: u64 = 0;
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data, an empty element stands for `DEFAULT`
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        if data.is_empty() {
+            return Ok(Self::new(Self::DEFAULT));
+        }
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct OldStereoMode {
+    pub value: u64,
+}
+
+impl OldStereoMode {
+    pub const ID: u64 = 0x53B9;
+    pub const NAME: &str = "OldStereoMode";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\Video\\OldStereoMode";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PixelWidth {
+    pub value: u64,
+}
+
+impl PixelWidth {
+    pub const ID: u64 = 0xB0;
+    pub const NAME: &str = "PixelWidth";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\Video\\PixelWidth";
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PixelHeight {
+    pub value: u64,
+}
+
+impl PixelHeight {
+    pub const ID: u64 = 0xBA;
+    pub const NAME: &str = "PixelHeight";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\Video\\PixelHeight";
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PixelCropBottom {
+    pub value: u64,
+}
+
+impl PixelCropBottom {
+    pub const ID: u64 = 0x54AA;
+    pub const NAME: &str = "PixelCropBottom";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\Video\\PixelCropBottom";
+    pub const DEFAULT: u64 = 0;
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data, an empty element stands for `DEFAULT`
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        if data.is_empty() {
+            return Ok(Self::new(Self::DEFAULT));
+        }
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PixelCropTop {
+    pub value: u64,
+}
+
+impl PixelCropTop {
+    pub const ID: u64 = 0x54BB;
+    pub const NAME: &str = "PixelCropTop";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\Video\\PixelCropTop";
+    pub const DEFAULT: u64 = 0;
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data, an empty element stands for `DEFAULT`
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        if data.is_empty() {
+            return Ok(Self::new(Self::DEFAULT));
+        }
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PixelCropLeft {
+    pub value: u64,
+}
+
+impl PixelCropLeft {
+    pub const ID: u64 = 0x54CC;
+    pub const NAME: &str = "PixelCropLeft";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\Video\\PixelCropLeft";
+    pub const DEFAULT: u64 = 0;
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data, an empty element stands for `DEFAULT`
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        if data.is_empty() {
+            return Ok(Self::new(Self::DEFAULT));
+        }
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PixelCropRight {
+    pub value: u64,
+}
+
+impl PixelCropRight {
+    pub const ID: u64 = 0x54DD;
+    pub const NAME: &str = "PixelCropRight";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\Video\\PixelCropRight";
+    pub const DEFAULT: u64 = 0;
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data, an empty element stands for `DEFAULT`
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        if data.is_empty() {
+            return Ok(Self::new(Self::DEFAULT));
+        }
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DisplayWidth {
+    pub value: u64,
+}
+
+impl DisplayWidth {
+    pub const ID: u64 = 0x54B0;
+    pub const NAME: &str = "DisplayWidth";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\Video\\DisplayWidth";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DisplayHeight {
+    pub value: u64,
+}
+
+impl DisplayHeight {
+    pub const ID: u64 = 0x54BA;
+    pub const NAME: &str = "DisplayHeight";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\Video\\DisplayHeight";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DisplayUnit {
+    pub value: u64,
+}
+
+impl DisplayUnit {
+    pub const ID: u64 = 0x54B2;
+    pub const NAME: &str = "DisplayUnit";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\Video\\DisplayUnit";
+    pub const DEFAULT: u64 = 0;
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data, an empty element stands for `DEFAULT`
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        if data.is_empty() {
+            return Ok(Self::new(Self::DEFAULT));
+        }
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AspectRatioType {
+    pub value: u64,
+}
+
+impl AspectRatioType {
+    pub const ID: u64 = 0x54B3;
+    pub const NAME: &str = "AspectRatioType";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\Video\\AspectRatioType";
+    pub const DEFAULT: u64 = 0;
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data, an empty element stands for `DEFAULT`
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        if data.is_empty() {
+            return Ok(Self::new(Self::DEFAULT));
+        }
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct UncompressedFourCC {
+    pub value: Vec<u8>,
+}
+
+impl UncompressedFourCC {
+    pub const ID: u64 = 0x2EB524;
+    pub const NAME: &str = "UncompressedFourCC";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\Video\\UncompressedFourCC";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: Vec<u8>) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(data.to_vec()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::binary(Self::ID, self.value.clone())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GammaValue {
+    pub value: f64,
+}
+
+impl GammaValue {
+    pub const ID: u64 = 0x2FB523;
+    pub const NAME: &str = "GammaValue";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\Video\\GammaValue";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: f64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlFloat::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::float(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FrameRate {
+    pub value: f64,
+}
+
+impl FrameRate {
+    pub const ID: u64 = 0x2383E3;
+    pub const NAME: &str = "FrameRate";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\Video\\FrameRate";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: f64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlFloat::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::float(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Colour {
+    pub matrix_coefficients: Option<MatrixCoefficients>,
+    pub bits_per_channel: Option<BitsPerChannel>,
+    pub chroma_subsampling_horz: Option<ChromaSubsamplingHorz>,
+    pub chroma_subsampling_vert: Option<ChromaSubsamplingVert>,
+    pub cb_subsampling_horz: Option<CbSubsamplingHorz>,
+    pub cb_subsampling_vert: Option<CbSubsamplingVert>,
+    pub chroma_siting_horz: Option<ChromaSitingHorz>,
+    pub chroma_siting_vert: Option<ChromaSitingVert>,
+    pub range: Option<Range>,
+    pub transfer_characteristics: Option<TransferCharacteristics>,
+    pub primaries: Option<Primaries>,
+    pub max_cll: Option<MaxCLL>,
+    pub max_fall: Option<MaxFALL>,
+    pub mastering_metadata: Option<MasteringMetadata>,
+    /// Children the schema doesn't define for this element
+    pub unknown: Vec<crate::EbmlNode<'static>>,
+}
+
+impl Colour {
+    pub const ID: u64 = 0x55B0;
+    pub const NAME: &str = "Colour";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\Video\\Colour";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        if !node.is_master() {
+            return Err(crate::EbmlError::NotMasterElement(node.id));
+        }
+        let mut value = Self::default();
+        for child in node.children() {
+            match child.id {
+                MatrixCoefficients::ID => value.matrix_coefficients = Some(MatrixCoefficients::from_node(child)?),
+                BitsPerChannel::ID => value.bits_per_channel = Some(BitsPerChannel::from_node(child)?),
+                ChromaSubsamplingHorz::ID => value.chroma_subsampling_horz = Some(ChromaSubsamplingHorz::from_node(child)?),
+                ChromaSubsamplingVert::ID => value.chroma_subsampling_vert = Some(ChromaSubsamplingVert::from_node(child)?),
+                CbSubsamplingHorz::ID => value.cb_subsampling_horz = Some(CbSubsamplingHorz::from_node(child)?),
+                CbSubsamplingVert::ID => value.cb_subsampling_vert = Some(CbSubsamplingVert::from_node(child)?),
+                ChromaSitingHorz::ID => value.chroma_siting_horz = Some(ChromaSitingHorz::from_node(child)?),
+                ChromaSitingVert::ID => value.chroma_siting_vert = Some(ChromaSitingVert::from_node(child)?),
+                Range::ID => value.range = Some(Range::from_node(child)?),
+                TransferCharacteristics::ID => value.transfer_characteristics = Some(TransferCharacteristics::from_node(child)?),
+                Primaries::ID => value.primaries = Some(Primaries::from_node(child)?),
+                MaxCLL::ID => value.max_cll = Some(MaxCLL::from_node(child)?),
+                MaxFALL::ID => value.max_fall = Some(MaxFALL::from_node(child)?),
+                MasteringMetadata::ID => value.mastering_metadata = Some(MasteringMetadata::from_node(child)?),
+                0xBF | 0xEC => {}
+                _ => value.unknown.push(child.clone().into_owned()),
+            }
+        }
+        Ok(value)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        let mut children = Vec::new();
+        if let Some(child) = &self.matrix_coefficients {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.bits_per_channel {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.chroma_subsampling_horz {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.chroma_subsampling_vert {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.cb_subsampling_horz {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.cb_subsampling_vert {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.chroma_siting_horz {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.chroma_siting_vert {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.range {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.transfer_characteristics {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.primaries {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.max_cll {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.max_fall {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.mastering_metadata {
+            children.push(child.to_node());
+        }
+        children.extend(self.unknown.iter().cloned());
+        crate::EbmlNode::master(Self::ID, children)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MatrixCoefficients {
+    pub value: u64,
+}
+
+impl MatrixCoefficients {
+    pub const ID: u64 = 0x55B1;
+    pub const NAME: &str = "MatrixCoefficients";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\Video\\Colour\\MatrixCoefficients";
+    pub const DEFAULT: u64 = 2;
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data, an empty element stands for `DEFAULT`
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        if data.is_empty() {
+            return Ok(Self::new(Self::DEFAULT));
+        }
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BitsPerChannel {
+    pub value: u64,
+}
+
+impl BitsPerChannel {
+    pub const ID: u64 = 0x55B2;
+    pub const NAME: &str = "BitsPerChannel";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\Video\\Colour\\BitsPerChannel";
+    pub const DEFAULT: u64 = 0;
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data, an empty element stands for `DEFAULT`
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        if data.is_empty() {
+            return Ok(Self::new(Self::DEFAULT));
+        }
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChromaSubsamplingHorz {
+    pub value: u64,
+}
+
+impl ChromaSubsamplingHorz {
+    pub const ID: u64 = 0x55B3;
+    pub const NAME: &str = "ChromaSubsamplingHorz";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\Video\\Colour\\ChromaSubsamplingHorz";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChromaSubsamplingVert {
+    pub value: u64,
+}
+
+impl ChromaSubsamplingVert {
+    pub const ID: u64 = 0x55B4;
+    pub const NAME: &str = "ChromaSubsamplingVert";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\Video\\Colour\\ChromaSubsamplingVert";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CbSubsamplingHorz {
+    pub value: u64,
+}
+
+impl CbSubsamplingHorz {
+    pub const ID: u64 = 0x55B5;
+    pub const NAME: &str = "CbSubsamplingHorz";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\Video\\Colour\\CbSubsamplingHorz";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CbSubsamplingVert {
+    pub value: u64,
+}
+
+impl CbSubsamplingVert {
+    pub const ID: u64 = 0x55B6;
+    pub const NAME: &str = "CbSubsamplingVert";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\Video\\Colour\\CbSubsamplingVert";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChromaSitingHorz {
+    pub value: u64,
+}
+
+impl ChromaSitingHorz {
+    pub const ID: u64 = 0x55B7;
+    pub const NAME: &str = "ChromaSitingHorz";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\Video\\Colour\\ChromaSitingHorz";
+    pub const DEFAULT: u64 = 0;
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data, an empty element stands for `DEFAULT`
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        if data.is_empty() {
+            return Ok(Self::new(Self::DEFAULT));
+        }
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChromaSitingVert {
+    pub value: u64,
+}
+
+impl ChromaSitingVert {
+    pub const ID: u64 = 0x55B8;
+    pub const NAME: &str = "ChromaSitingVert";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\Video\\Colour\\ChromaSitingVert";
+    pub const DEFAULT: u64 = 0;
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data, an empty element stands for `DEFAULT`
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        if data.is_empty() {
+            return Ok(Self::new(Self::DEFAULT));
+        }
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Range {
+    pub value: u64,
+}
+
+impl Range {
+    pub const ID: u64 = 0x55B9;
+    pub const NAME: &str = "Range";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\Video\\Colour\\Range";
+    pub const DEFAULT: u64 = 0;
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data, an empty element stands for `DEFAULT`
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        if data.is_empty() {
+            return Ok(Self::new(Self::DEFAULT));
+        }
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransferCharacteristics {
+    pub value: u64,
+}
+
+impl TransferCharacteristics {
+    pub const ID: u64 = 0x55BA;
+    pub const NAME: &str = "TransferCharacteristics";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\Video\\Colour\\TransferCharacteristics";
+    pub const DEFAULT: u64 = 2;
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data, an empty element stands for `DEFAULT`
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        if data.is_empty() {
+            return Ok(Self::new(Self::DEFAULT));
+        }
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Primaries {
+    pub value: u64,
+}
+
+impl Primaries {
+    pub const ID: u64 = 0x55BB;
+    pub const NAME: &str = "Primaries";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\Video\\Colour\\Primaries";
+    pub const DEFAULT: u64 = 2;
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data, an empty element stands for `DEFAULT`
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        if data.is_empty() {
+            return Ok(Self::new(Self::DEFAULT));
+        }
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MaxCLL {
+    pub value: u64,
+}
+
+impl MaxCLL {
+    pub const ID: u64 = 0x55BC;
+    pub const NAME: &str = "MaxCLL";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\Video\\Colour\\MaxCLL";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MaxFALL {
+    pub value: u64,
+}
+
+impl MaxFALL {
+    pub const ID: u64 = 0x55BD;
+    pub const NAME: &str = "MaxFALL";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\Video\\Colour\\MaxFALL";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct MasteringMetadata {
+    pub primary_r_chromaticity_x: Option<PrimaryRChromaticityX>,
+    pub primary_r_chromaticity_y: Option<PrimaryRChromaticityY>,
+    pub primary_g_chromaticity_x: Option<PrimaryGChromaticityX>,
+    pub primary_g_chromaticity_y: Option<PrimaryGChromaticityY>,
+    pub primary_b_chromaticity_x: Option<PrimaryBChromaticityX>,
+    pub primary_b_chromaticity_y: Option<PrimaryBChromaticityY>,
+    pub white_point_chromaticity_x: Option<WhitePointChromaticityX>,
+    pub white_point_chromaticity_y: Option<WhitePointChromaticityY>,
+    pub luminance_max: Option<LuminanceMax>,
+    pub luminance_min: Option<LuminanceMin>,
+    /// Children the schema doesn't define for this element
+    pub unknown: Vec<crate::EbmlNode<'static>>,
+}
+
+impl MasteringMetadata {
+    pub const ID: u64 = 0x55D0;
+    pub const NAME: &str = "MasteringMetadata";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\Video\\Colour\\MasteringMetadata";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        if !node.is_master() {
+            return Err(crate::EbmlError::NotMasterElement(node.id));
+        }
+        let mut value = Self::default();
+        for child in node.children() {
+            match child.id {
+                PrimaryRChromaticityX::ID => value.primary_r_chromaticity_x = Some(PrimaryRChromaticityX::from_node(child)?),
+                PrimaryRChromaticityY::ID => value.primary_r_chromaticity_y = Some(PrimaryRChromaticityY::from_node(child)?),
+                PrimaryGChromaticityX::ID => value.primary_g_chromaticity_x = Some(PrimaryGChromaticityX::from_node(child)?),
+                PrimaryGChromaticityY::ID => value.primary_g_chromaticity_y = Some(PrimaryGChromaticityY::from_node(child)?),
+                PrimaryBChromaticityX::ID => value.primary_b_chromaticity_x = Some(PrimaryBChromaticityX::from_node(child)?),
+                PrimaryBChromaticityY::ID => value.primary_b_chromaticity_y = Some(PrimaryBChromaticityY::from_node(child)?),
+                WhitePointChromaticityX::ID => value.white_point_chromaticity_x = Some(WhitePointChromaticityX::from_node(child)?),
+                WhitePointChromaticityY::ID => value.white_point_chromaticity_y = Some(WhitePointChromaticityY::from_node(child)?),
+                LuminanceMax::ID => value.luminance_max = Some(LuminanceMax::from_node(child)?),
+                LuminanceMin::ID => value.luminance_min = Some(LuminanceMin::from_node(child)?),
+                0xBF | 0xEC => {}
+                _ => value.unknown.push(child.clone().into_owned()),
+            }
+        }
+        Ok(value)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        let mut children = Vec::new();
+        if let Some(child) = &self.primary_r_chromaticity_x {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.primary_r_chromaticity_y {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.primary_g_chromaticity_x {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.primary_g_chromaticity_y {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.primary_b_chromaticity_x {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.primary_b_chromaticity_y {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.white_point_chromaticity_x {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.white_point_chromaticity_y {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.luminance_max {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.luminance_min {
+            children.push(child.to_node());
+        }
+        children.extend(self.unknown.iter().cloned());
+        crate::EbmlNode::master(Self::ID, children)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimaryRChromaticityX {
+    pub value: f64,
+}
+
+impl PrimaryRChromaticityX {
+    pub const ID: u64 = 0x55D1;
+    pub const NAME: &str = "PrimaryRChromaticityX";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\Video\\Colour\\MasteringMetadata\\PrimaryRChromaticityX";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: f64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlFloat::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::float(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimaryRChromaticityY {
+    pub value: f64,
+}
+
+impl PrimaryRChromaticityY {
+    pub const ID: u64 = 0x55D2;
+    pub const NAME: &str = "PrimaryRChromaticityY";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\Video\\Colour\\MasteringMetadata\\PrimaryRChromaticityY";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: f64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlFloat::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::float(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimaryGChromaticityX {
+    pub value: f64,
+}
+
+impl PrimaryGChromaticityX {
+    pub const ID: u64 = 0x55D3;
+    pub const NAME: &str = "PrimaryGChromaticityX";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\Video\\Colour\\MasteringMetadata\\PrimaryGChromaticityX";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: f64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlFloat::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::float(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimaryGChromaticityY {
+    pub value: f64,
+}
+
+impl PrimaryGChromaticityY {
+    pub const ID: u64 = 0x55D4;
+    pub const NAME: &str = "PrimaryGChromaticityY";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\Video\\Colour\\MasteringMetadata\\PrimaryGChromaticityY";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: f64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlFloat::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::float(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimaryBChromaticityX {
+    pub value: f64,
+}
+
+impl PrimaryBChromaticityX {
+    pub const ID: u64 = 0x55D5;
+    pub const NAME: &str = "PrimaryBChromaticityX";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\Video\\Colour\\MasteringMetadata\\PrimaryBChromaticityX";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: f64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlFloat::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::float(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimaryBChromaticityY {
+    pub value: f64,
+}
+
+impl PrimaryBChromaticityY {
+    pub const ID: u64 = 0x55D6;
+    pub const NAME: &str = "PrimaryBChromaticityY";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\Video\\Colour\\MasteringMetadata\\PrimaryBChromaticityY";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: f64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlFloat::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::float(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct WhitePointChromaticityX {
+    pub value: f64,
+}
+
+impl WhitePointChromaticityX {
+    pub const ID: u64 = 0x55D7;
+    pub const NAME: &str = "WhitePointChromaticityX";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\Video\\Colour\\MasteringMetadata\\WhitePointChromaticityX";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: f64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlFloat::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::float(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct WhitePointChromaticityY {
+    pub value: f64,
+}
+
+impl WhitePointChromaticityY {
+    pub const ID: u64 = 0x55D8;
+    pub const NAME: &str = "WhitePointChromaticityY";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\Video\\Colour\\MasteringMetadata\\WhitePointChromaticityY";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: f64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlFloat::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::float(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LuminanceMax {
+    pub value: f64,
+}
+
+impl LuminanceMax {
+    pub const ID: u64 = 0x55D9;
+    pub const NAME: &str = "LuminanceMax";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\Video\\Colour\\MasteringMetadata\\LuminanceMax";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: f64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlFloat::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::float(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LuminanceMin {
+    pub value: f64,
+}
+
+impl LuminanceMin {
+    pub const ID: u64 = 0x55DA;
+    pub const NAME: &str = "LuminanceMin";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\Video\\Colour\\MasteringMetadata\\LuminanceMin";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: f64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlFloat::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::float(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Projection {
+    pub projection_type: Option<ProjectionType>,
+    pub projection_private: Option<ProjectionPrivate>,
+    pub projection_pose_yaw: Option<ProjectionPoseYaw>,
+    pub projection_pose_pitch: Option<ProjectionPosePitch>,
+    pub projection_pose_roll: Option<ProjectionPoseRoll>,
+    /// Children the schema doesn't define for this element
+    pub unknown: Vec<crate::EbmlNode<'static>>,
+}
+
+impl Projection {
+    pub const ID: u64 = 0x7670;
+    pub const NAME: &str = "Projection";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\Video\\Projection";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        if !node.is_master() {
+            return Err(crate::EbmlError::NotMasterElement(node.id));
+        }
+        let mut value = Self::default();
+        for child in node.children() {
+            match child.id {
+                ProjectionType::ID => value.projection_type = Some(ProjectionType::from_node(child)?),
+                ProjectionPrivate::ID => value.projection_private = Some(ProjectionPrivate::from_node(child)?),
+                ProjectionPoseYaw::ID => value.projection_pose_yaw = Some(ProjectionPoseYaw::from_node(child)?),
+                ProjectionPosePitch::ID => value.projection_pose_pitch = Some(ProjectionPosePitch::from_node(child)?),
+                ProjectionPoseRoll::ID => value.projection_pose_roll = Some(ProjectionPoseRoll::from_node(child)?),
+                0xBF | 0xEC => {}
+                _ => value.unknown.push(child.clone().into_owned()),
+            }
+        }
+        Ok(value)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        let mut children = Vec::new();
+        if let Some(child) = &self.projection_type {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.projection_private {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.projection_pose_yaw {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.projection_pose_pitch {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.projection_pose_roll {
+            children.push(child.to_node());
+        }
+        children.extend(self.unknown.iter().cloned());
+        crate::EbmlNode::master(Self::ID, children)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProjectionType {
+    pub value: u64,
+}
+
+impl ProjectionType {
+    pub const ID: u64 = 0x7671;
+    pub const NAME: &str = "ProjectionType";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\Video\\Projection\\ProjectionType";
+    pub const DEFAULT: u64 = 0;
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data, an empty element stands for `DEFAULT`
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        if data.is_empty() {
+            return Ok(Self::new(Self::DEFAULT));
+        }
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProjectionPrivate {
+    pub value: Vec<u8>,
+}
+
+impl ProjectionPrivate {
+    pub const ID: u64 = 0x7672;
+    pub const NAME: &str = "ProjectionPrivate";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\Video\\Projection\\ProjectionPrivate";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: Vec<u8>) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(data.to_vec()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::binary(Self::ID, self.value.clone())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProjectionPoseYaw {
+    pub value: f64,
+}
+
+impl ProjectionPoseYaw {
+    pub const ID: u64 = 0x7673;
+    pub const NAME: &str = "ProjectionPoseYaw";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\Video\\Projection\\ProjectionPoseYaw";
+    pub const DEFAULT: f64 = 0.0;
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: f64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data, an empty element stands for `DEFAULT`
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        if data.is_empty() {
+            return Ok(Self::new(Self::DEFAULT));
+        }
+        Ok(Self::new(crate::EbmlFloat::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::float(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProjectionPosePitch {
+    pub value: f64,
+}
+
+impl ProjectionPosePitch {
+    pub const ID: u64 = 0x7674;
+    pub const NAME: &str = "ProjectionPosePitch";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\Video\\Projection\\ProjectionPosePitch";
+    pub const DEFAULT: f64 = 0.0;
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: f64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data, an empty element stands for `DEFAULT`
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        if data.is_empty() {
+            return Ok(Self::new(Self::DEFAULT));
+        }
+        Ok(Self::new(crate::EbmlFloat::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::float(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProjectionPoseRoll {
+    pub value: f64,
+}
+
+impl ProjectionPoseRoll {
+    pub const ID: u64 = 0x7675;
+    pub const NAME: &str = "ProjectionPoseRoll";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\Video\\Projection\\ProjectionPoseRoll";
+    pub const DEFAULT: f64 = 0.0;
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: f64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data, an empty element stands for `DEFAULT`
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        if data.is_empty() {
+            return Ok(Self::new(Self::DEFAULT));
+        }
+        Ok(Self::new(crate::EbmlFloat::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::float(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Audio {
+    pub sampling_frequency: Option<SamplingFrequency>,
+    pub output_sampling_frequency: Option<OutputSamplingFrequency>,
+    pub channels: Option<Channels>,
+    pub channel_positions: Option<ChannelPositions>,
+    pub bit_depth: Option<BitDepth>,
+    pub emphasis: Option<Emphasis>,
+    /// Children the schema doesn't define for this element
+    pub unknown: Vec<crate::EbmlNode<'static>>,
+}
+
+impl Audio {
+    pub const ID: u64 = 0xE1;
+    pub const NAME: &str = "Audio";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\Audio";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        if !node.is_master() {
+            return Err(crate::EbmlError::NotMasterElement(node.id));
+        }
+        let mut value = Self::default();
+        for child in node.children() {
+            match child.id {
+                SamplingFrequency::ID => value.sampling_frequency = Some(SamplingFrequency::from_node(child)?),
+                OutputSamplingFrequency::ID => value.output_sampling_frequency = Some(OutputSamplingFrequency::from_node(child)?),
+                Channels::ID => value.channels = Some(Channels::from_node(child)?),
+                ChannelPositions::ID => value.channel_positions = Some(ChannelPositions::from_node(child)?),
+                BitDepth::ID => value.bit_depth = Some(BitDepth::from_node(child)?),
+                Emphasis::ID => value.emphasis = Some(Emphasis::from_node(child)?),
+                0xBF | 0xEC => {}
+                _ => value.unknown.push(child.clone().into_owned()),
+            }
+        }
+        Ok(value)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        let mut children = Vec::new();
+        if let Some(child) = &self.sampling_frequency {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.output_sampling_frequency {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.channels {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.channel_positions {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.bit_depth {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.emphasis {
+            children.push(child.to_node());
+        }
+        children.extend(self.unknown.iter().cloned());
+        crate::EbmlNode::master(Self::ID, children)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SamplingFrequency {
+    pub value: f64,
+}
+
+impl SamplingFrequency {
+    pub const ID: u64 = 0xB5;
+    pub const NAME: &str = "SamplingFrequency";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\Audio\\SamplingFrequency";
+    pub const DEFAULT: f64 = 8000.0;
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: f64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data, an empty element stands for `DEFAULT`
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        if data.is_empty() {
+            return Ok(Self::new(Self::DEFAULT));
+        }
+        Ok(Self::new(crate::EbmlFloat::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::float(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct OutputSamplingFrequency {
+    pub value: f64,
+}
+
+impl OutputSamplingFrequency {
+    pub const ID: u64 = 0x78B5;
+    pub const NAME: &str = "OutputSamplingFrequency";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\Audio\\OutputSamplingFrequency";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: f64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlFloat::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::float(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Channels {
+    pub value: u64,
+}
+
+impl Channels {
+    pub const ID: u64 = 0x9F;
+    pub const NAME: &str = "Channels";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\Audio\\Channels";
+    pub const DEFAULT: u64 = 1;
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data, an empty element stands for `DEFAULT`
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        if data.is_empty() {
+            return Ok(Self::new(Self::DEFAULT));
+        }
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChannelPositions {
+    pub value: Vec<u8>,
+}
+
+impl ChannelPositions {
+    pub const ID: u64 = 0x7D7B;
+    pub const NAME: &str = "ChannelPositions";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\Audio\\ChannelPositions";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: Vec<u8>) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(data.to_vec()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::binary(Self::ID, self.value.clone())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BitDepth {
+    pub value: u64,
+}
+
+impl BitDepth {
+    pub const ID: u64 = 0x6264;
+    pub const NAME: &str = "BitDepth";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\Audio\\BitDepth";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Emphasis {
+    pub value: u64,
+}
+
+impl Emphasis {
+    pub const ID: u64 = 0x52F1;
+    pub const NAME: &str = "Emphasis";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\Audio\\Emphasis";
+    pub const DEFAULT: u64 = 0;
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data, an empty element stands for `DEFAULT`
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        if data.is_empty() {
+            return Ok(Self::new(Self::DEFAULT));
+        }
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TrackOperation {
+    pub track_combine_planes: Option<TrackCombinePlanes>,
+    pub track_join_blocks: Option<TrackJoinBlocks>,
+    /// Children the schema doesn't define for this element
+    pub unknown: Vec<crate::EbmlNode<'static>>,
+}
+
+impl TrackOperation {
+    pub const ID: u64 = 0xE2;
+    pub const NAME: &str = "TrackOperation";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\TrackOperation";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        if !node.is_master() {
+            return Err(crate::EbmlError::NotMasterElement(node.id));
+        }
+        let mut value = Self::default();
+        for child in node.children() {
+            match child.id {
+                TrackCombinePlanes::ID => value.track_combine_planes = Some(TrackCombinePlanes::from_node(child)?),
+                TrackJoinBlocks::ID => value.track_join_blocks = Some(TrackJoinBlocks::from_node(child)?),
+                0xBF | 0xEC => {}
+                _ => value.unknown.push(child.clone().into_owned()),
+            }
+        }
+        Ok(value)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        let mut children = Vec::new();
+        if let Some(child) = &self.track_combine_planes {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.track_join_blocks {
+            children.push(child.to_node());
+        }
+        children.extend(self.unknown.iter().cloned());
+        crate::EbmlNode::master(Self::ID, children)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TrackCombinePlanes {
+    pub track_plane: Vec<TrackPlane>,
+    /// Children the schema doesn't define for this element
+    pub unknown: Vec<crate::EbmlNode<'static>>,
+}
+
+impl TrackCombinePlanes {
+    pub const ID: u64 = 0xE3;
+    pub const NAME: &str = "TrackCombinePlanes";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\TrackOperation\\TrackCombinePlanes";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        if !node.is_master() {
+            return Err(crate::EbmlError::NotMasterElement(node.id));
+        }
+        let mut value = Self::default();
+        for child in node.children() {
+            match child.id {
+                TrackPlane::ID => value.track_plane.push(TrackPlane::from_node(child)?),
+                0xBF | 0xEC => {}
+                _ => value.unknown.push(child.clone().into_owned()),
+            }
+        }
+        Ok(value)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        let mut children = Vec::new();
+        for child in &self.track_plane {
+            children.push(child.to_node());
+        }
+        children.extend(self.unknown.iter().cloned());
+        crate::EbmlNode::master(Self::ID, children)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TrackPlane {
+    pub track_plane_uid: Option<TrackPlaneUID>,
+    pub track_plane_type: Option<TrackPlaneType>,
+    /// Children the schema doesn't define for this element
+    pub unknown: Vec<crate::EbmlNode<'static>>,
+}
+
+impl TrackPlane {
+    pub const ID: u64 = 0xE4;
+    pub const NAME: &str = "TrackPlane";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\TrackOperation\\TrackCombinePlanes\\TrackPlane";
+    pub const MIN_OCCURS: u64 = 1;
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        if !node.is_master() {
+            return Err(crate::EbmlError::NotMasterElement(node.id));
+        }
+        let mut value = Self::default();
+        for child in node.children() {
+            match child.id {
+                TrackPlaneUID::ID => value.track_plane_uid = Some(TrackPlaneUID::from_node(child)?),
+                TrackPlaneType::ID => value.track_plane_type = Some(TrackPlaneType::from_node(child)?),
+                0xBF | 0xEC => {}
+                _ => value.unknown.push(child.clone().into_owned()),
+            }
+        }
+        Ok(value)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        let mut children = Vec::new();
+        if let Some(child) = &self.track_plane_uid {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.track_plane_type {
+            children.push(child.to_node());
+        }
+        children.extend(self.unknown.iter().cloned());
+        crate::EbmlNode::master(Self::ID, children)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrackPlaneUID {
+    pub value: u64,
+}
+
+impl TrackPlaneUID {
+    pub const ID: u64 = 0xE5;
+    pub const NAME: &str = "TrackPlaneUID";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\TrackOperation\\TrackCombinePlanes\\TrackPlane\\TrackPlaneUID";
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrackPlaneType {
+    pub value: u64,
+}
+
+impl TrackPlaneType {
+    pub const ID: u64 = 0xE6;
+    pub const NAME: &str = "TrackPlaneType";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\TrackOperation\\TrackCombinePlanes\\TrackPlane\\TrackPlaneType";
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TrackJoinBlocks {
+    pub track_join_uid: Vec<TrackJoinUID>,
+    /// Children the schema doesn't define for this element
+    pub unknown: Vec<crate::EbmlNode<'static>>,
+}
+
+impl TrackJoinBlocks {
+    pub const ID: u64 = 0xE9;
+    pub const NAME: &str = "TrackJoinBlocks";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\TrackOperation\\TrackJoinBlocks";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        if !node.is_master() {
+            return Err(crate::EbmlError::NotMasterElement(node.id));
+        }
+        let mut value = Self::default();
+        for child in node.children() {
+            match child.id {
+                TrackJoinUID::ID => value.track_join_uid.push(TrackJoinUID::from_node(child)?),
+                0xBF | 0xEC => {}
+                _ => value.unknown.push(child.clone().into_owned()),
+            }
+        }
+        Ok(value)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        let mut children = Vec::new();
+        for child in &self.track_join_uid {
+            children.push(child.to_node());
+        }
+        children.extend(self.unknown.iter().cloned());
+        crate::EbmlNode::master(Self::ID, children)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrackJoinUID {
+    pub value: u64,
+}
+
+impl TrackJoinUID {
+    pub const ID: u64 = 0xED;
+    pub const NAME: &str = "TrackJoinUID";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\TrackOperation\\TrackJoinBlocks\\TrackJoinUID";
+    pub const MIN_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrickTrackUID {
+    pub value: u64,
+}
+
+impl TrickTrackUID {
+    pub const ID: u64 = 0xC0;
+    pub const NAME: &str = "TrickTrackUID";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\TrickTrackUID";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrickTrackSegmentUID {
+    pub value: Vec<u8>,
+}
+
+impl TrickTrackSegmentUID {
+    pub const ID: u64 = 0xC1;
+    pub const NAME: &str = "TrickTrackSegmentUID";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\TrickTrackSegmentUID";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: Vec<u8>) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(data.to_vec()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::binary(Self::ID, self.value.clone())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrickTrackFlag {
+    pub value: u64,
+}
+
+impl TrickTrackFlag {
+    pub const ID: u64 = 0xC6;
+    pub const NAME: &str = "TrickTrackFlag";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\TrickTrackFlag";
+    pub const DEFAULT: u64 = 0;
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data, an empty element stands for `DEFAULT`
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        if data.is_empty() {
+            return Ok(Self::new(Self::DEFAULT));
+        }
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrickMasterTrackUID {
+    pub value: u64,
+}
+
+impl TrickMasterTrackUID {
+    pub const ID: u64 = 0xC7;
+    pub const NAME: &str = "TrickMasterTrackUID";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\TrickMasterTrackUID";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrickMasterTrackSegmentUID {
+    pub value: Vec<u8>,
+}
+
+impl TrickMasterTrackSegmentUID {
+    pub const ID: u64 = 0xC4;
+    pub const NAME: &str = "TrickMasterTrackSegmentUID";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\TrickMasterTrackSegmentUID";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: Vec<u8>) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(data.to_vec()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::binary(Self::ID, self.value.clone())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ContentEncodings {
+    pub content_encoding: Vec<ContentEncoding>,
+    /// Children the schema doesn't define for this element
+    pub unknown: Vec<crate::EbmlNode<'static>>,
+}
+
+impl ContentEncodings {
+    pub const ID: u64 = 0x6D80;
+    pub const NAME: &str = "ContentEncodings";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\ContentEncodings";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        if !node.is_master() {
+            return Err(crate::EbmlError::NotMasterElement(node.id));
+        }
+        let mut value = Self::default();
+        for child in node.children() {
+            match child.id {
+                ContentEncoding::ID => value.content_encoding.push(ContentEncoding::from_node(child)?),
+                0xBF | 0xEC => {}
+                _ => value.unknown.push(child.clone().into_owned()),
+            }
+        }
+        Ok(value)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        let mut children = Vec::new();
+        for child in &self.content_encoding {
+            children.push(child.to_node());
+        }
+        children.extend(self.unknown.iter().cloned());
+        crate::EbmlNode::master(Self::ID, children)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ContentEncoding {
+    pub content_encoding_order: Option<ContentEncodingOrder>,
+    pub content_encoding_scope: Option<ContentEncodingScope>,
+    pub content_encoding_type: Option<ContentEncodingType>,
+    pub content_compression: Option<ContentCompression>,
+    pub content_encryption: Option<ContentEncryption>,
+    /// Children the schema doesn't define for this element
+    pub unknown: Vec<crate::EbmlNode<'static>>,
+}
+
+impl ContentEncoding {
+    pub const ID: u64 = 0x6240;
+    pub const NAME: &str = "ContentEncoding";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\ContentEncodings\\ContentEncoding";
+    pub const MIN_OCCURS: u64 = 1;
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        if !node.is_master() {
+            return Err(crate::EbmlError::NotMasterElement(node.id));
+        }
+        let mut value = Self::default();
+        for child in node.children() {
+            match child.id {
+                ContentEncodingOrder::ID => value.content_encoding_order = Some(ContentEncodingOrder::from_node(child)?),
+                ContentEncodingScope::ID => value.content_encoding_scope = Some(ContentEncodingScope::from_node(child)?),
+                ContentEncodingType::ID => value.content_encoding_type = Some(ContentEncodingType::from_node(child)?),
+                ContentCompression::ID => value.content_compression = Some(ContentCompression::from_node(child)?),
+                ContentEncryption::ID => value.content_encryption = Some(ContentEncryption::from_node(child)?),
+                0xBF | 0xEC => {}
+                _ => value.unknown.push(child.clone().into_owned()),
+            }
+        }
+        Ok(value)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        let mut children = Vec::new();
+        if let Some(child) = &self.content_encoding_order {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.content_encoding_scope {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.content_encoding_type {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.content_compression {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.content_encryption {
+            children.push(child.to_node());
+        }
+        children.extend(self.unknown.iter().cloned());
+        crate::EbmlNode::master(Self::ID, children)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContentEncodingOrder {
+    pub value: u64,
+}
+
+impl ContentEncodingOrder {
+    pub const ID: u64 = 0x5031;
+    pub const NAME: &str = "ContentEncodingOrder";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\ContentEncodings\\ContentEncoding\\ContentEncodingOrder";
+    pub const DEFAULT: u64 = 0;
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data, an empty element stands for `DEFAULT`
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        if data.is_empty() {
+            return Ok(Self::new(Self::DEFAULT));
+        }
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContentEncodingScope {
+    pub value: u64,
+}
+
+impl ContentEncodingScope {
+    pub const ID: u64 = 0x5032;
+    pub const NAME: &str = "ContentEncodingScope";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\ContentEncodings\\ContentEncoding\\ContentEncodingScope";
+    pub const DEFAULT: u64 = 1;
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data, an empty element stands for `DEFAULT`
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        if data.is_empty() {
+            return Ok(Self::new(Self::DEFAULT));
+        }
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContentEncodingType {
+    pub value: u64,
+}
+
+impl ContentEncodingType {
+    pub const ID: u64 = 0x5033;
+    pub const NAME: &str = "ContentEncodingType";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\ContentEncodings\\ContentEncoding\\ContentEncodingType";
+    pub const DEFAULT: u64 = 0;
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data, an empty element stands for `DEFAULT`
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        if data.is_empty() {
+            return Ok(Self::new(Self::DEFAULT));
+        }
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ContentCompression {
+    pub content_comp_algo: Option<ContentCompAlgo>,
+    pub content_comp_settings: Option<ContentCompSettings>,
+    /// Children the schema doesn't define for this element
+    pub unknown: Vec<crate::EbmlNode<'static>>,
+}
+
+impl ContentCompression {
+    pub const ID: u64 = 0x5034;
+    pub const NAME: &str = "ContentCompression";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\ContentEncodings\\ContentEncoding\\ContentCompression";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        if !node.is_master() {
+            return Err(crate::EbmlError::NotMasterElement(node.id));
+        }
+        let mut value = Self::default();
+        for child in node.children() {
+            match child.id {
+                ContentCompAlgo::ID => value.content_comp_algo = Some(ContentCompAlgo::from_node(child)?),
+                ContentCompSettings::ID => value.content_comp_settings = Some(ContentCompSettings::from_node(child)?),
+                0xBF | 0xEC => {}
+                _ => value.unknown.push(child.clone().into_owned()),
+            }
+        }
+        Ok(value)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        let mut children = Vec::new();
+        if let Some(child) = &self.content_comp_algo {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.content_comp_settings {
+            children.push(child.to_node());
+        }
+        children.extend(self.unknown.iter().cloned());
+        crate::EbmlNode::master(Self::ID, children)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContentCompAlgo {
+    pub value: u64,
+}
+
+impl ContentCompAlgo {
+    pub const ID: u64 = 0x4254;
+    pub const NAME: &str = "ContentCompAlgo";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\ContentEncodings\\ContentEncoding\\ContentCompression\\ContentCompAlgo";
+    pub const DEFAULT: u64 = 0;
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data, an empty element stands for `DEFAULT`
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        if data.is_empty() {
+            return Ok(Self::new(Self::DEFAULT));
+        }
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContentCompSettings {
+    pub value: Vec<u8>,
+}
+
+impl ContentCompSettings {
+    pub const ID: u64 = 0x4255;
+    pub const NAME: &str = "ContentCompSettings";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\ContentEncodings\\ContentEncoding\\ContentCompression\\ContentCompSettings";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: Vec<u8>) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(data.to_vec()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::binary(Self::ID, self.value.clone())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ContentEncryption {
+    pub content_enc_algo: Option<ContentEncAlgo>,
+    pub content_enc_key_id: Option<ContentEncKeyID>,
+    pub content_enc_aes_settings: Option<ContentEncAESSettings>,
+    pub content_signature: Option<ContentSignature>,
+    pub content_sig_key_id: Option<ContentSigKeyID>,
+    pub content_sig_algo: Option<ContentSigAlgo>,
+    pub content_sig_hash_algo: Option<ContentSigHashAlgo>,
+    /// Children the schema doesn't define for this element
+    pub unknown: Vec<crate::EbmlNode<'static>>,
+}
+
+impl ContentEncryption {
+    pub const ID: u64 = 0x5035;
+    pub const NAME: &str = "ContentEncryption";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\ContentEncodings\\ContentEncoding\\ContentEncryption";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        if !node.is_master() {
+            return Err(crate::EbmlError::NotMasterElement(node.id));
+        }
+        let mut value = Self::default();
+        for child in node.children() {
+            match child.id {
+                ContentEncAlgo::ID => value.content_enc_algo = Some(ContentEncAlgo::from_node(child)?),
+                ContentEncKeyID::ID => value.content_enc_key_id = Some(ContentEncKeyID::from_node(child)?),
+                ContentEncAESSettings::ID => value.content_enc_aes_settings = Some(ContentEncAESSettings::from_node(child)?),
+                ContentSignature::ID => value.content_signature = Some(ContentSignature::from_node(child)?),
+                ContentSigKeyID::ID => value.content_sig_key_id = Some(ContentSigKeyID::from_node(child)?),
+                ContentSigAlgo::ID => value.content_sig_algo = Some(ContentSigAlgo::from_node(child)?),
+                ContentSigHashAlgo::ID => value.content_sig_hash_algo = Some(ContentSigHashAlgo::from_node(child)?),
+                0xBF | 0xEC => {}
+                _ => value.unknown.push(child.clone().into_owned()),
+            }
+        }
+        Ok(value)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        let mut children = Vec::new();
+        if let Some(child) = &self.content_enc_algo {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.content_enc_key_id {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.content_enc_aes_settings {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.content_signature {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.content_sig_key_id {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.content_sig_algo {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.content_sig_hash_algo {
+            children.push(child.to_node());
+        }
+        children.extend(self.unknown.iter().cloned());
+        crate::EbmlNode::master(Self::ID, children)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContentEncAlgo {
+    pub value: u64,
+}
+
+impl ContentEncAlgo {
+    pub const ID: u64 = 0x47E1;
+    pub const NAME: &str = "ContentEncAlgo";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\ContentEncodings\\ContentEncoding\\ContentEncryption\\ContentEncAlgo";
+    pub const DEFAULT: u64 = 0;
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data, an empty element stands for `DEFAULT`
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        if data.is_empty() {
+            return Ok(Self::new(Self::DEFAULT));
+        }
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContentEncKeyID {
+    pub value: Vec<u8>,
+}
+
+impl ContentEncKeyID {
+    pub const ID: u64 = 0x47E2;
+    pub const NAME: &str = "ContentEncKeyID";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\ContentEncodings\\ContentEncoding\\ContentEncryption\\ContentEncKeyID";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: Vec<u8>) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(data.to_vec()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::binary(Self::ID, self.value.clone())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ContentEncAESSettings {
+    pub aes_settings_cipher_mode: Option<AESSettingsCipherMode>,
+    /// Children the schema doesn't define for this element
+    pub unknown: Vec<crate::EbmlNode<'static>>,
+}
+
+impl ContentEncAESSettings {
+    pub const ID: u64 = 0x47E7;
+    pub const NAME: &str = "ContentEncAESSettings";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\ContentEncodings\\ContentEncoding\\ContentEncryption\\ContentEncAESSettings";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        if !node.is_master() {
+            return Err(crate::EbmlError::NotMasterElement(node.id));
+        }
+        let mut value = Self::default();
+        for child in node.children() {
+            match child.id {
+                AESSettingsCipherMode::ID => value.aes_settings_cipher_mode = Some(AESSettingsCipherMode::from_node(child)?),
+                0xBF | 0xEC => {}
+                _ => value.unknown.push(child.clone().into_owned()),
+            }
+        }
+        Ok(value)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        let mut children = Vec::new();
+        if let Some(child) = &self.aes_settings_cipher_mode {
+            children.push(child.to_node());
+        }
+        children.extend(self.unknown.iter().cloned());
+        crate::EbmlNode::master(Self::ID, children)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AESSettingsCipherMode {
+    pub value: u64,
+}
+
+impl AESSettingsCipherMode {
+    pub const ID: u64 = 0x47E8;
+    pub const NAME: &str = "AESSettingsCipherMode";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\ContentEncodings\\ContentEncoding\\ContentEncryption\\ContentEncAESSettings\\AESSettingsCipherMode";
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContentSignature {
+    pub value: Vec<u8>,
+}
+
+impl ContentSignature {
+    pub const ID: u64 = 0x47E3;
+    pub const NAME: &str = "ContentSignature";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\ContentEncodings\\ContentEncoding\\ContentEncryption\\ContentSignature";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: Vec<u8>) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(data.to_vec()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::binary(Self::ID, self.value.clone())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContentSigKeyID {
+    pub value: Vec<u8>,
+}
+
+impl ContentSigKeyID {
+    pub const ID: u64 = 0x47E4;
+    pub const NAME: &str = "ContentSigKeyID";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\ContentEncodings\\ContentEncoding\\ContentEncryption\\ContentSigKeyID";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: Vec<u8>) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(data.to_vec()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::binary(Self::ID, self.value.clone())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContentSigAlgo {
+    pub value: u64,
+}
+
+impl ContentSigAlgo {
+    pub const ID: u64 = 0x47E5;
+    pub const NAME: &str = "ContentSigAlgo";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\ContentEncodings\\ContentEncoding\\ContentEncryption\\ContentSigAlgo";
+    pub const DEFAULT: u64 = 0;
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data, an empty element stands for `DEFAULT`
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        if data.is_empty() {
+            return Ok(Self::new(Self::DEFAULT));
+        }
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContentSigHashAlgo {
+    pub value: u64,
+}
+
+impl ContentSigHashAlgo {
+    pub const ID: u64 = 0x47E6;
+    pub const NAME: &str = "ContentSigHashAlgo";
+    pub const PATH: &str = "\\Segment\\Tracks\\TrackEntry\\ContentEncodings\\ContentEncoding\\ContentEncryption\\ContentSigHashAlgo";
+    pub const DEFAULT: u64 = 0;
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data, an empty element stands for `DEFAULT`
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        if data.is_empty() {
+            return Ok(Self::new(Self::DEFAULT));
+        }
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+/// A Top-Level Element to speed seeking access.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Cues {
+    pub cue_point: Vec<CuePoint>,
+    /// Children the schema doesn't define for this element
+    pub unknown: Vec<crate::EbmlNode<'static>>,
+}
+
+impl Cues {
+    pub const ID: u64 = 0x1C53BB6B;
+    pub const NAME: &str = "Cues";
+    pub const PATH: &str = "\\Segment\\Cues";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        if !node.is_master() {
+            return Err(crate::EbmlError::NotMasterElement(node.id));
+        }
+        let mut value = Self::default();
+        for child in node.children() {
+            match child.id {
+                CuePoint::ID => value.cue_point.push(CuePoint::from_node(child)?),
+                0xBF | 0xEC => {}
+                _ => value.unknown.push(child.clone().into_owned()),
+            }
+        }
+        Ok(value)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        let mut children = Vec::new();
+        for child in &self.cue_point {
+            children.push(child.to_node());
+        }
+        children.extend(self.unknown.iter().cloned());
+        crate::EbmlNode::master(Self::ID, children)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct CuePoint {
+    pub cue_time: Option<CueTime>,
+    pub cue_track_positions: Vec<CueTrackPositions>,
+    /// Children the schema doesn't define for this element
+    pub unknown: Vec<crate::EbmlNode<'static>>,
+}
+
+impl CuePoint {
+    pub const ID: u64 = 0xBB;
+    pub const NAME: &str = "CuePoint";
+    pub const PATH: &str = "\\Segment\\Cues\\CuePoint";
+    pub const MIN_OCCURS: u64 = 1;
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        if !node.is_master() {
+            return Err(crate::EbmlError::NotMasterElement(node.id));
+        }
+        let mut value = Self::default();
+        for child in node.children() {
+            match child.id {
+                CueTime::ID => value.cue_time = Some(CueTime::from_node(child)?),
+                CueTrackPositions::ID => value.cue_track_positions.push(CueTrackPositions::from_node(child)?),
+                0xBF | 0xEC => {}
+                _ => value.unknown.push(child.clone().into_owned()),
+            }
+        }
+        Ok(value)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        let mut children = Vec::new();
+        if let Some(child) = &self.cue_time {
+            children.push(child.to_node());
+        }
+        for child in &self.cue_track_positions {
+            children.push(child.to_node());
+        }
+        children.extend(self.unknown.iter().cloned());
+        crate::EbmlNode::master(Self::ID, children)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CueTime {
+    pub value: u64,
+}
+
+impl CueTime {
+    pub const ID: u64 = 0xB3;
+    pub const NAME: &str = "CueTime";
+    pub const PATH: &str = "\\Segment\\Cues\\CuePoint\\CueTime";
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct CueTrackPositions {
+    pub cue_track: Option<CueTrack>,
+    pub cue_cluster_position: Option<CueClusterPosition>,
+    pub cue_relative_position: Option<CueRelativePosition>,
+    pub cue_duration: Option<CueDuration>,
+    pub cue_block_number: Option<CueBlockNumber>,
+    pub cue_codec_state: Option<CueCodecState>,
+    pub cue_reference: Vec<CueReference>,
+    /// Children the schema doesn't define for this element
+    pub unknown: Vec<crate::EbmlNode<'static>>,
+}
+
+impl CueTrackPositions {
+    pub const ID: u64 = 0xB7;
+    pub const NAME: &str = "CueTrackPositions";
+    pub const PATH: &str = "\\Segment\\Cues\\CuePoint\\CueTrackPositions";
+    pub const MIN_OCCURS: u64 = 1;
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        if !node.is_master() {
+            return Err(crate::EbmlError::NotMasterElement(node.id));
+        }
+        let mut value = Self::default();
+        for child in node.children() {
+            match child.id {
+                CueTrack::ID => value.cue_track = Some(CueTrack::from_node(child)?),
+                CueClusterPosition::ID => value.cue_cluster_position = Some(CueClusterPosition::from_node(child)?),
+                CueRelativePosition::ID => value.cue_relative_position = Some(CueRelativePosition::from_node(child)?),
+                CueDuration::ID => value.cue_duration = Some(CueDuration::from_node(child)?),
+                CueBlockNumber::ID => value.cue_block_number = Some(CueBlockNumber::from_node(child)?),
+                CueCodecState::ID => value.cue_codec_state = Some(CueCodecState::from_node(child)?),
+                CueReference::ID => value.cue_reference.push(CueReference::from_node(child)?),
+                0xBF | 0xEC => {}
+                _ => value.unknown.push(child.clone().into_owned()),
+            }
+        }
+        Ok(value)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        let mut children = Vec::new();
+        if let Some(child) = &self.cue_track {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.cue_cluster_position {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.cue_relative_position {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.cue_duration {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.cue_block_number {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.cue_codec_state {
+            children.push(child.to_node());
+        }
+        for child in &self.cue_reference {
+            children.push(child.to_node());
+        }
+        children.extend(self.unknown.iter().cloned());
+        crate::EbmlNode::master(Self::ID, children)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CueTrack {
+    pub value: u64,
+}
+
+impl CueTrack {
+    pub const ID: u64 = 0xF7;
+    pub const NAME: &str = "CueTrack";
+    pub const PATH: &str = "\\Segment\\Cues\\CuePoint\\CueTrackPositions\\CueTrack";
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CueClusterPosition {
+    pub value: u64,
+}
+
+impl CueClusterPosition {
+    pub const ID: u64 = 0xF1;
+    pub const NAME: &str = "CueClusterPosition";
+    pub const PATH: &str = "\\Segment\\Cues\\CuePoint\\CueTrackPositions\\CueClusterPosition";
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CueRelativePosition {
+    pub value: u64,
+}
+
+impl CueRelativePosition {
+    pub const ID: u64 = 0xF0;
+    pub const NAME: &str = "CueRelativePosition";
+    pub const PATH: &str = "\\Segment\\Cues\\CuePoint\\CueTrackPositions\\CueRelativePosition";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CueDuration {
+    pub value: u64,
+}
+
+impl CueDuration {
+    pub const ID: u64 = 0xB2;
+    pub const NAME: &str = "CueDuration";
+    pub const PATH: &str = "\\Segment\\Cues\\CuePoint\\CueTrackPositions\\CueDuration";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CueBlockNumber {
+    pub value: u64,
+}
+
+impl CueBlockNumber {
+    pub const ID: u64 = 0x5378;
+    pub const NAME: &str = "CueBlockNumber";
+    pub const PATH: &str = "\\Segment\\Cues\\CuePoint\\CueTrackPositions\\CueBlockNumber";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CueCodecState {
+    pub value: u64,
+}
+
+impl CueCodecState {
+    pub const ID: u64 = 0xEA;
+    pub const NAME: &str = "CueCodecState";
+    pub const PATH: &str = "\\Segment\\Cues\\CuePoint\\CueTrackPositions\\CueCodecState";
+    pub const DEFAULT: u64 = 0;
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data, an empty element stands for `DEFAULT`
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        if data.is_empty() {
+            return Ok(Self::new(Self::DEFAULT));
+        }
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct CueReference {
+    pub cue_ref_time: Option<CueRefTime>,
+    pub cue_ref_cluster: Option<CueRefCluster>,
+    pub cue_ref_number: Option<CueRefNumber>,
+    pub cue_ref_codec_state: Option<CueRefCodecState>,
+    /// Children the schema doesn't define for this element
+    pub unknown: Vec<crate::EbmlNode<'static>>,
+}
+
+impl CueReference {
+    pub const ID: u64 = 0xDB;
+    pub const NAME: &str = "CueReference";
+    pub const PATH: &str = "\\Segment\\Cues\\CuePoint\\CueTrackPositions\\CueReference";
+    pub const MIN_OCCURS: u64 = 0;
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        if !node.is_master() {
+            return Err(crate::EbmlError::NotMasterElement(node.id));
+        }
+        let mut value = Self::default();
+        for child in node.children() {
+            match child.id {
+                CueRefTime::ID => value.cue_ref_time = Some(CueRefTime::from_node(child)?),
+                CueRefCluster::ID => value.cue_ref_cluster = Some(CueRefCluster::from_node(child)?),
+                CueRefNumber::ID => value.cue_ref_number = Some(CueRefNumber::from_node(child)?),
+                CueRefCodecState::ID => value.cue_ref_codec_state = Some(CueRefCodecState::from_node(child)?),
+                0xBF | 0xEC => {}
+                _ => value.unknown.push(child.clone().into_owned()),
+            }
+        }
+        Ok(value)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        let mut children = Vec::new();
+        if let Some(child) = &self.cue_ref_time {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.cue_ref_cluster {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.cue_ref_number {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.cue_ref_codec_state {
+            children.push(child.to_node());
+        }
+        children.extend(self.unknown.iter().cloned());
+        crate::EbmlNode::master(Self::ID, children)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CueRefTime {
+    pub value: u64,
+}
+
+impl CueRefTime {
+    pub const ID: u64 = 0x96;
+    pub const NAME: &str = "CueRefTime";
+    pub const PATH: &str = "\\Segment\\Cues\\CuePoint\\CueTrackPositions\\CueReference\\CueRefTime";
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CueRefCluster {
+    pub value: u64,
+}
+
+impl CueRefCluster {
+    pub const ID: u64 = 0x97;
+    pub const NAME: &str = "CueRefCluster";
+    pub const PATH: &str = "\\Segment\\Cues\\CuePoint\\CueTrackPositions\\CueReference\\CueRefCluster";
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CueRefNumber {
+    pub value: u64,
+}
+
+impl CueRefNumber {
+    pub const ID: u64 = 0x535F;
+    pub const NAME: &str = "CueRefNumber";
+    pub const PATH: &str = "\\Segment\\Cues\\CuePoint\\CueTrackPositions\\CueReference\\CueRefNumber";
+    pub const DEFAULT: u64 = 1;
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data, an empty element stands for `DEFAULT`
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        if data.is_empty() {
+            return Ok(Self::new(Self::DEFAULT));
+        }
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CueRefCodecState {
+    pub value: u64,
+}
+
+impl CueRefCodecState {
+    pub const ID: u64 = 0xEB;
+    pub const NAME: &str = "CueRefCodecState";
+    pub const PATH: &str = "\\Segment\\Cues\\CuePoint\\CueTrackPositions\\CueReference\\CueRefCodecState";
+    pub const DEFAULT: u64 = 0;
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data, an empty element stands for `DEFAULT`
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        if data.is_empty() {
+            return Ok(Self::new(Self::DEFAULT));
+        }
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+/// Contain attached files.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Attachments {
+    pub attached_file: Vec<AttachedFile>,
+    /// Children the schema doesn't define for this element
+    pub unknown: Vec<crate::EbmlNode<'static>>,
+}
+
+impl Attachments {
+    pub const ID: u64 = 0x1941A469;
+    pub const NAME: &str = "Attachments";
+    pub const PATH: &str = "\\Segment\\Attachments";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        if !node.is_master() {
+            return Err(crate::EbmlError::NotMasterElement(node.id));
+        }
+        let mut value = Self::default();
+        for child in node.children() {
+            match child.id {
+                AttachedFile::ID => value.attached_file.push(AttachedFile::from_node(child)?),
+                0xBF | 0xEC => {}
+                _ => value.unknown.push(child.clone().into_owned()),
+            }
+        }
+        Ok(value)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        let mut children = Vec::new();
+        for child in &self.attached_file {
+            children.push(child.to_node());
+        }
+        children.extend(self.unknown.iter().cloned());
+        crate::EbmlNode::master(Self::ID, children)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct AttachedFile {
+    pub file_description: Option<FileDescription>,
+    pub file_name: Option<FileName>,
+    pub file_media_type: Option<FileMediaType>,
+    pub file_data: Option<FileData>,
+    pub file_uid: Option<FileUID>,
+    pub file_referral: Option<FileReferral>,
+    pub file_used_start_time: Option<FileUsedStartTime>,
+    pub file_used_end_time: Option<FileUsedEndTime>,
+    /// Children the schema doesn't define for this element
+    pub unknown: Vec<crate::EbmlNode<'static>>,
+}
+
+impl AttachedFile {
+    pub const ID: u64 = 0x61A7;
+    pub const NAME: &str = "AttachedFile";
+    pub const PATH: &str = "\\Segment\\Attachments\\AttachedFile";
+    pub const MIN_OCCURS: u64 = 1;
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        if !node.is_master() {
+            return Err(crate::EbmlError::NotMasterElement(node.id));
+        }
+        let mut value = Self::default();
+        for child in node.children() {
+            match child.id {
+                FileDescription::ID => value.file_description = Some(FileDescription::from_node(child)?),
+                FileName::ID => value.file_name = Some(FileName::from_node(child)?),
+                FileMediaType::ID => value.file_media_type = Some(FileMediaType::from_node(child)?),
+                FileData::ID => value.file_data = Some(FileData::from_node(child)?),
+                FileUID::ID => value.file_uid = Some(FileUID::from_node(child)?),
+                FileReferral::ID => value.file_referral = Some(FileReferral::from_node(child)?),
+                FileUsedStartTime::ID => value.file_used_start_time = Some(FileUsedStartTime::from_node(child)?),
+                FileUsedEndTime::ID => value.file_used_end_time = Some(FileUsedEndTime::from_node(child)?),
+                0xBF | 0xEC => {}
+                _ => value.unknown.push(child.clone().into_owned()),
+            }
+        }
+        Ok(value)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        let mut children = Vec::new();
+        if let Some(child) = &self.file_description {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.file_name {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.file_media_type {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.file_data {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.file_uid {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.file_referral {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.file_used_start_time {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.file_used_end_time {
+            children.push(child.to_node());
+        }
+        children.extend(self.unknown.iter().cloned());
+        crate::EbmlNode::master(Self::ID, children)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileDescription {
+    pub value: String,
+}
+
+impl FileDescription {
+    pub const ID: u64 = 0x467E;
+    pub const NAME: &str = "FileDescription";
+    pub const PATH: &str = "\\Segment\\Attachments\\AttachedFile\\FileDescription";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: impl Into<String>) -> Self {
+        Self { value: value.into() }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUtf8::new(data)?.as_str()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::string(Self::ID, &self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileName {
+    pub value: String,
+}
+
+impl FileName {
+    pub const ID: u64 = 0x466E;
+    pub const NAME: &str = "FileName";
+    pub const PATH: &str = "\\Segment\\Attachments\\AttachedFile\\FileName";
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: impl Into<String>) -> Self {
+        Self { value: value.into() }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUtf8::new(data)?.as_str()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::string(Self::ID, &self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileMediaType {
+    pub value: String,
+}
+
+impl FileMediaType {
+    pub const ID: u64 = 0x4660;
+    pub const NAME: &str = "FileMediaType";
+    pub const PATH: &str = "\\Segment\\Attachments\\AttachedFile\\FileMediaType";
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: impl Into<String>) -> Self {
+        Self { value: value.into() }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlString::new(data)?.as_str()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::string(Self::ID, &self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileData {
+    pub value: Vec<u8>,
+}
+
+impl FileData {
+    pub const ID: u64 = 0x465C;
+    pub const NAME: &str = "FileData";
+    pub const PATH: &str = "\\Segment\\Attachments\\AttachedFile\\FileData";
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: Vec<u8>) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(data.to_vec()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::binary(Self::ID, self.value.clone())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileUID {
+    pub value: u64,
+}
+
+impl FileUID {
+    pub const ID: u64 = 0x46AE;
+    pub const NAME: &str = "FileUID";
+    pub const PATH: &str = "\\Segment\\Attachments\\AttachedFile\\FileUID";
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileReferral {
+    pub value: Vec<u8>,
+}
+
+impl FileReferral {
+    pub const ID: u64 = 0x4675;
+    pub const NAME: &str = "FileReferral";
+    pub const PATH: &str = "\\Segment\\Attachments\\AttachedFile\\FileReferral";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: Vec<u8>) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(data.to_vec()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::binary(Self::ID, self.value.clone())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileUsedStartTime {
+    pub value: u64,
+}
+
+impl FileUsedStartTime {
+    pub const ID: u64 = 0x4661;
+    pub const NAME: &str = "FileUsedStartTime";
+    pub const PATH: &str = "\\Segment\\Attachments\\AttachedFile\\FileUsedStartTime";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileUsedEndTime {
+    pub value: u64,
+}
+
+impl FileUsedEndTime {
+    pub const ID: u64 = 0x4662;
+    pub const NAME: &str = "FileUsedEndTime";
+    pub const PATH: &str = "\\Segment\\Attachments\\AttachedFile\\FileUsedEndTime";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+/// A system to define basic menus and partition data.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Chapters {
+    pub edition_entry: Vec<EditionEntry>,
+    /// Children the schema doesn't define for this element
+    pub unknown: Vec<crate::EbmlNode<'static>>,
+}
+
+impl Chapters {
+    pub const ID: u64 = 0x1043A770;
+    pub const NAME: &str = "Chapters";
+    pub const PATH: &str = "\\Segment\\Chapters";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        if !node.is_master() {
+            return Err(crate::EbmlError::NotMasterElement(node.id));
+        }
+        let mut value = Self::default();
+        for child in node.children() {
+            match child.id {
+                EditionEntry::ID => value.edition_entry.push(EditionEntry::from_node(child)?),
+                0xBF | 0xEC => {}
+                _ => value.unknown.push(child.clone().into_owned()),
+            }
+        }
+        Ok(value)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        let mut children = Vec::new();
+        for child in &self.edition_entry {
+            children.push(child.to_node());
+        }
+        children.extend(self.unknown.iter().cloned());
+        crate::EbmlNode::master(Self::ID, children)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct EditionEntry {
+    pub edition_uid: Option<EditionUID>,
+    pub edition_flag_hidden: Option<EditionFlagHidden>,
+    pub edition_flag_default: Option<EditionFlagDefault>,
+    pub edition_flag_ordered: Option<EditionFlagOrdered>,
+    pub edition_display: Vec<EditionDisplay>,
+    pub chapter_atom: Vec<ChapterAtom>,
+    /// Children the schema doesn't define for this element
+    pub unknown: Vec<crate::EbmlNode<'static>>,
+}
+
+impl EditionEntry {
+    pub const ID: u64 = 0x45B9;
+    pub const NAME: &str = "EditionEntry";
+    pub const PATH: &str = "\\Segment\\Chapters\\EditionEntry";
+    pub const MIN_OCCURS: u64 = 1;
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        if !node.is_master() {
+            return Err(crate::EbmlError::NotMasterElement(node.id));
+        }
+        let mut value = Self::default();
+        for child in node.children() {
+            match child.id {
+                EditionUID::ID => value.edition_uid = Some(EditionUID::from_node(child)?),
+                EditionFlagHidden::ID => value.edition_flag_hidden = Some(EditionFlagHidden::from_node(child)?),
+                EditionFlagDefault::ID => value.edition_flag_default = Some(EditionFlagDefault::from_node(child)?),
+                EditionFlagOrdered::ID => value.edition_flag_ordered = Some(EditionFlagOrdered::from_node(child)?),
+                EditionDisplay::ID => value.edition_display.push(EditionDisplay::from_node(child)?),
+                ChapterAtom::ID => value.chapter_atom.push(ChapterAtom::from_node(child)?),
+                0xBF | 0xEC => {}
+                _ => value.unknown.push(child.clone().into_owned()),
+            }
+        }
+        Ok(value)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        let mut children = Vec::new();
+        if let Some(child) = &self.edition_uid {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.edition_flag_hidden {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.edition_flag_default {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.edition_flag_ordered {
+            children.push(child.to_node());
+        }
+        for child in &self.edition_display {
+            children.push(child.to_node());
+        }
+        for child in &self.chapter_atom {
+            children.push(child.to_node());
+        }
+        children.extend(self.unknown.iter().cloned());
+        crate::EbmlNode::master(Self::ID, children)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EditionUID {
+    pub value: u64,
+}
+
+impl EditionUID {
+    pub const ID: u64 = 0x45BC;
+    pub const NAME: &str = "EditionUID";
+    pub const PATH: &str = "\\Segment\\Chapters\\EditionEntry\\EditionUID";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EditionFlagHidden {
+    pub value: u64,
+}
+
+impl EditionFlagHidden {
+    pub const ID: u64 = 0x45BD;
+    pub const NAME: &str = "EditionFlagHidden";
+    pub const PATH: &str = "\\Segment\\Chapters\\EditionEntry\\EditionFlagHidden";
+    pub const DEFAULT: u64 = 0;
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data, an empty element stands for `DEFAULT`
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        if data.is_empty() {
+            return Ok(Self::new(Self::DEFAULT));
+        }
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EditionFlagDefault {
+    pub value: u64,
+}
+
+impl EditionFlagDefault {
+    pub const ID: u64 = 0x45DB;
+    pub const NAME: &str = "EditionFlagDefault";
+    pub const PATH: &str = "\\Segment\\Chapters\\EditionEntry\\EditionFlagDefault";
+    pub const DEFAULT: u64 = 0;
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data, an empty element stands for `DEFAULT`
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        if data.is_empty() {
+            return Ok(Self::new(Self::DEFAULT));
+        }
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EditionFlagOrdered {
+    pub value: u64,
+}
+
+impl EditionFlagOrdered {
+    pub const ID: u64 = 0x45DD;
+    pub const NAME: &str = "EditionFlagOrdered";
+    pub const PATH: &str = "\\Segment\\Chapters\\EditionEntry\\EditionFlagOrdered";
+    pub const DEFAULT: u64 = 0;
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data, an empty element stands for `DEFAULT`
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        if data.is_empty() {
+            return Ok(Self::new(Self::DEFAULT));
+        }
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct EditionDisplay {
+    pub edition_string: Option<EditionString>,
+    pub edition_language_ietf: Vec<EditionLanguageIETF>,
+    /// Children the schema doesn't define for this element
+    pub unknown: Vec<crate::EbmlNode<'static>>,
+}
+
+impl EditionDisplay {
+    pub const ID: u64 = 0x4520;
+    pub const NAME: &str = "EditionDisplay";
+    pub const PATH: &str = "\\Segment\\Chapters\\EditionEntry\\EditionDisplay";
+    pub const MIN_OCCURS: u64 = 0;
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        if !node.is_master() {
+            return Err(crate::EbmlError::NotMasterElement(node.id));
+        }
+        let mut value = Self::default();
+        for child in node.children() {
+            match child.id {
+                EditionString::ID => value.edition_string = Some(EditionString::from_node(child)?),
+                EditionLanguageIETF::ID => value.edition_language_ietf.push(EditionLanguageIETF::from_node(child)?),
+                0xBF | 0xEC => {}
+                _ => value.unknown.push(child.clone().into_owned()),
+            }
+        }
+        Ok(value)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        let mut children = Vec::new();
+        if let Some(child) = &self.edition_string {
+            children.push(child.to_node());
+        }
+        for child in &self.edition_language_ietf {
+            children.push(child.to_node());
+        }
+        children.extend(self.unknown.iter().cloned());
+        crate::EbmlNode::master(Self::ID, children)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EditionString {
+    pub value: String,
+}
+
+impl EditionString {
+    pub const ID: u64 = 0x4521;
+    pub const NAME: &str = "EditionString";
+    pub const PATH: &str = "\\Segment\\Chapters\\EditionEntry\\EditionDisplay\\EditionString";
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: impl Into<String>) -> Self {
+        Self { value: value.into() }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUtf8::new(data)?.as_str()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::string(Self::ID, &self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EditionLanguageIETF {
+    pub value: String,
+}
+
+impl EditionLanguageIETF {
+    pub const ID: u64 = 0x45E4;
+    pub const NAME: &str = "EditionLanguageIETF";
+    pub const PATH: &str = "\\Segment\\Chapters\\EditionEntry\\EditionDisplay\\EditionLanguageIETF";
+    pub const MIN_OCCURS: u64 = 0;
+
+    pub fn new(value: impl Into<String>) -> Self {
+        Self { value: value.into() }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlString::new(data)?.as_str()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::string(Self::ID, &self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ChapterAtom {
+    pub chapter_atom: Vec<ChapterAtom>,
+    pub chapter_uid: Option<ChapterUID>,
+    pub chapter_string_uid: Option<ChapterStringUID>,
+    pub chapter_time_start: Option<ChapterTimeStart>,
+    pub chapter_time_end: Option<ChapterTimeEnd>,
+    pub chapter_flag_hidden: Option<ChapterFlagHidden>,
+    pub chapter_flag_enabled: Option<ChapterFlagEnabled>,
+    pub chapter_segment_uuid: Option<ChapterSegmentUUID>,
+    pub chapter_skip_type: Option<ChapterSkipType>,
+    pub chapter_segment_edition_uid: Option<ChapterSegmentEditionUID>,
+    pub chapter_physical_equiv: Option<ChapterPhysicalEquiv>,
+    pub chapter_track: Option<ChapterTrack>,
+    pub chapter_display: Vec<ChapterDisplay>,
+    pub chap_process: Vec<ChapProcess>,
+    /// Children the schema doesn't define for this element
+    pub unknown: Vec<crate::EbmlNode<'static>>,
+}
+
+impl ChapterAtom {
+    pub const ID: u64 = 0xB6;
+    pub const NAME: &str = "ChapterAtom";
+    pub const PATH: &str = "\\Segment\\Chapters\\EditionEntry\\+ChapterAtom";
+    pub const MIN_OCCURS: u64 = 1;
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        if !node.is_master() {
+            return Err(crate::EbmlError::NotMasterElement(node.id));
+        }
+        let mut value = Self::default();
+        for child in node.children() {
+            match child.id {
+                ChapterAtom::ID => value.chapter_atom.push(ChapterAtom::from_node(child)?),
+                ChapterUID::ID => value.chapter_uid = Some(ChapterUID::from_node(child)?),
+                ChapterStringUID::ID => value.chapter_string_uid = Some(ChapterStringUID::from_node(child)?),
+                ChapterTimeStart::ID => value.chapter_time_start = Some(ChapterTimeStart::from_node(child)?),
+                ChapterTimeEnd::ID => value.chapter_time_end = Some(ChapterTimeEnd::from_node(child)?),
+                ChapterFlagHidden::ID => value.chapter_flag_hidden = Some(ChapterFlagHidden::from_node(child)?),
+                ChapterFlagEnabled::ID => value.chapter_flag_enabled = Some(ChapterFlagEnabled::from_node(child)?),
+                ChapterSegmentUUID::ID => value.chapter_segment_uuid = Some(ChapterSegmentUUID::from_node(child)?),
+                ChapterSkipType::ID => value.chapter_skip_type = Some(ChapterSkipType::from_node(child)?),
+                ChapterSegmentEditionUID::ID => value.chapter_segment_edition_uid = Some(ChapterSegmentEditionUID::from_node(child)?),
+                ChapterPhysicalEquiv::ID => value.chapter_physical_equiv = Some(ChapterPhysicalEquiv::from_node(child)?),
+                ChapterTrack::ID => value.chapter_track = Some(ChapterTrack::from_node(child)?),
+                ChapterDisplay::ID => value.chapter_display.push(ChapterDisplay::from_node(child)?),
+                ChapProcess::ID => value.chap_process.push(ChapProcess::from_node(child)?),
+                0xBF | 0xEC => {}
+                _ => value.unknown.push(child.clone().into_owned()),
+            }
+        }
+        Ok(value)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        let mut children = Vec::new();
+        for child in &self.chapter_atom {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.chapter_uid {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.chapter_string_uid {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.chapter_time_start {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.chapter_time_end {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.chapter_flag_hidden {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.chapter_flag_enabled {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.chapter_segment_uuid {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.chapter_skip_type {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.chapter_segment_edition_uid {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.chapter_physical_equiv {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.chapter_track {
+            children.push(child.to_node());
+        }
+        for child in &self.chapter_display {
+            children.push(child.to_node());
+        }
+        for child in &self.chap_process {
+            children.push(child.to_node());
+        }
+        children.extend(self.unknown.iter().cloned());
+        crate::EbmlNode::master(Self::ID, children)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChapterUID {
+    pub value: u64,
+}
+
+impl ChapterUID {
+    pub const ID: u64 = 0x73C4;
+    pub const NAME: &str = "ChapterUID";
+    pub const PATH: &str = "\\Segment\\Chapters\\EditionEntry\\+ChapterAtom\\ChapterUID";
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChapterStringUID {
+    pub value: String,
+}
+
+impl ChapterStringUID {
+    pub const ID: u64 = 0x5654;
+    pub const NAME: &str = "ChapterStringUID";
+    pub const PATH: &str = "\\Segment\\Chapters\\EditionEntry\\+ChapterAtom\\ChapterStringUID";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: impl Into<String>) -> Self {
+        Self { value: value.into() }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUtf8::new(data)?.as_str()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::string(Self::ID, &self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChapterTimeStart {
+    pub value: u64,
+}
+
+impl ChapterTimeStart {
+    pub const ID: u64 = 0x91;
+    pub const NAME: &str = "ChapterTimeStart";
+    pub const PATH: &str = "\\Segment\\Chapters\\EditionEntry\\+ChapterAtom\\ChapterTimeStart";
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChapterTimeEnd {
+    pub value: u64,
+}
+
+impl ChapterTimeEnd {
+    pub const ID: u64 = 0x92;
+    pub const NAME: &str = "ChapterTimeEnd";
+    pub const PATH: &str = "\\Segment\\Chapters\\EditionEntry\\+ChapterAtom\\ChapterTimeEnd";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChapterFlagHidden {
+    pub value: u64,
+}
+
+impl ChapterFlagHidden {
+    pub const ID: u64 = 0x98;
+    pub const NAME: &str = "ChapterFlagHidden";
+    pub const PATH: &str = "\\Segment\\Chapters\\EditionEntry\\+ChapterAtom\\ChapterFlagHidden";
+    pub const DEFAULT: u64 = 0;
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data, an empty element stands for `DEFAULT`
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        if data.is_empty() {
+            return Ok(Self::new(Self::DEFAULT));
+        }
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChapterFlagEnabled {
+    pub value: u64,
+}
+
+impl ChapterFlagEnabled {
+    pub const ID: u64 = 0x4598;
+    pub const NAME: &str = "ChapterFlagEnabled";
+    pub const PATH: &str = "\\Segment\\Chapters\\EditionEntry\\+ChapterAtom\\ChapterFlagEnabled";
+    pub const DEFAULT: u64 = 1;
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data, an empty element stands for `DEFAULT`
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        if data.is_empty() {
+            return Ok(Self::new(Self::DEFAULT));
+        }
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChapterSegmentUUID {
+    pub value: Vec<u8>,
+}
+
+impl ChapterSegmentUUID {
+    pub const ID: u64 = 0x6E67;
+    pub const NAME: &str = "ChapterSegmentUUID";
+    pub const PATH: &str = "\\Segment\\Chapters\\EditionEntry\\+ChapterAtom\\ChapterSegmentUUID";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: Vec<u8>) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(data.to_vec()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::binary(Self::ID, self.value.clone())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChapterSkipType {
+    pub value: u64,
+}
+
+impl ChapterSkipType {
+    pub const ID: u64 = 0x4588;
+    pub const NAME: &str = "ChapterSkipType";
+    pub const PATH: &str = "\\Segment\\Chapters\\EditionEntry\\+ChapterAtom\\ChapterSkipType";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChapterSegmentEditionUID {
+    pub value: u64,
+}
+
+impl ChapterSegmentEditionUID {
+    pub const ID: u64 = 0x6EBC;
+    pub const NAME: &str = "ChapterSegmentEditionUID";
+    pub const PATH: &str = "\\Segment\\Chapters\\EditionEntry\\+ChapterAtom\\ChapterSegmentEditionUID";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChapterPhysicalEquiv {
+    pub value: u64,
+}
+
+impl ChapterPhysicalEquiv {
+    pub const ID: u64 = 0x63C3;
+    pub const NAME: &str = "ChapterPhysicalEquiv";
+    pub const PATH: &str = "\\Segment\\Chapters\\EditionEntry\\+ChapterAtom\\ChapterPhysicalEquiv";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ChapterTrack {
+    pub chapter_track_uid: Vec<ChapterTrackUID>,
+    /// Children the schema doesn't define for this element
+    pub unknown: Vec<crate::EbmlNode<'static>>,
+}
+
+impl ChapterTrack {
+    pub const ID: u64 = 0x8F;
+    pub const NAME: &str = "ChapterTrack";
+    pub const PATH: &str = "\\Segment\\Chapters\\EditionEntry\\+ChapterAtom\\ChapterTrack";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        if !node.is_master() {
+            return Err(crate::EbmlError::NotMasterElement(node.id));
+        }
+        let mut value = Self::default();
+        for child in node.children() {
+            match child.id {
+                ChapterTrackUID::ID => value.chapter_track_uid.push(ChapterTrackUID::from_node(child)?),
+                0xBF | 0xEC => {}
+                _ => value.unknown.push(child.clone().into_owned()),
+            }
+        }
+        Ok(value)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        let mut children = Vec::new();
+        for child in &self.chapter_track_uid {
+            children.push(child.to_node());
+        }
+        children.extend(self.unknown.iter().cloned());
+        crate::EbmlNode::master(Self::ID, children)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChapterTrackUID {
+    pub value: u64,
+}
+
+impl ChapterTrackUID {
+    pub const ID: u64 = 0x89;
+    pub const NAME: &str = "ChapterTrackUID";
+    pub const PATH: &str = "\\Segment\\Chapters\\EditionEntry\\+ChapterAtom\\ChapterTrack\\ChapterTrackUID";
+    pub const MIN_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ChapterDisplay {
+    pub chap_string: Option<ChapString>,
+    pub chap_language: Vec<ChapLanguage>,
+    pub chap_language_bcp47: Vec<ChapLanguageBCP47>,
+    pub chap_country: Vec<ChapCountry>,
+    /// Children the schema doesn't define for this element
+    pub unknown: Vec<crate::EbmlNode<'static>>,
+}
+
+impl ChapterDisplay {
+    pub const ID: u64 = 0x80;
+    pub const NAME: &str = "ChapterDisplay";
+    pub const PATH: &str = "\\Segment\\Chapters\\EditionEntry\\+ChapterAtom\\ChapterDisplay";
+    pub const MIN_OCCURS: u64 = 0;
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        if !node.is_master() {
+            return Err(crate::EbmlError::NotMasterElement(node.id));
+        }
+        let mut value = Self::default();
+        for child in node.children() {
+            match child.id {
+                ChapString::ID => value.chap_string = Some(ChapString::from_node(child)?),
+                ChapLanguage::ID => value.chap_language.push(ChapLanguage::from_node(child)?),
+                ChapLanguageBCP47::ID => value.chap_language_bcp47.push(ChapLanguageBCP47::from_node(child)?),
+                ChapCountry::ID => value.chap_country.push(ChapCountry::from_node(child)?),
+                0xBF | 0xEC => {}
+                _ => value.unknown.push(child.clone().into_owned()),
+            }
+        }
+        Ok(value)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        let mut children = Vec::new();
+        if let Some(child) = &self.chap_string {
+            children.push(child.to_node());
+        }
+        for child in &self.chap_language {
+            children.push(child.to_node());
+        }
+        for child in &self.chap_language_bcp47 {
+            children.push(child.to_node());
+        }
+        for child in &self.chap_country {
+            children.push(child.to_node());
+        }
+        children.extend(self.unknown.iter().cloned());
+        crate::EbmlNode::master(Self::ID, children)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChapString {
+    pub value: String,
+}
+
+impl ChapString {
+    pub const ID: u64 = 0x85;
+    pub const NAME: &str = "ChapString";
+    pub const PATH: &str = "\\Segment\\Chapters\\EditionEntry\\+ChapterAtom\\ChapterDisplay\\ChapString";
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: impl Into<String>) -> Self {
+        Self { value: value.into() }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUtf8::new(data)?.as_str()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::string(Self::ID, &self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChapLanguage {
+    pub value: String,
+}
+
+impl ChapLanguage {
+    pub const ID: u64 = 0x437C;
+    pub const NAME: &str = "ChapLanguage";
+    pub const PATH: &str = "\\Segment\\Chapters\\EditionEntry\\+ChapterAtom\\ChapterDisplay\\ChapLanguage";
+    pub const DEFAULT: &str = "eng";
+    pub const MIN_OCCURS: u64 = 1;
+
+    pub fn new(value: impl Into<String>) -> Self {
+        Self { value: value.into() }
+    }
+
+    /// Decodes the element's data, an empty element stands for `DEFAULT`
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        if data.is_empty() {
+            return Ok(Self::new(Self::DEFAULT));
+        }
+        Ok(Self::new(crate::EbmlString::new(data)?.as_str()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::string(Self::ID, &self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChapLanguageBCP47 {
+    pub value: String,
+}
+
+impl ChapLanguageBCP47 {
+    pub const ID: u64 = 0x437D;
+    pub const NAME: &str = "ChapLanguageBCP47";
+    pub const PATH: &str = "\\Segment\\Chapters\\EditionEntry\\+ChapterAtom\\ChapterDisplay\\ChapLanguageBCP47";
+    pub const MIN_OCCURS: u64 = 0;
+
+    pub fn new(value: impl Into<String>) -> Self {
+        Self { value: value.into() }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlString::new(data)?.as_str()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::string(Self::ID, &self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChapCountry {
+    pub value: String,
+}
+
+impl ChapCountry {
+    pub const ID: u64 = 0x437E;
+    pub const NAME: &str = "ChapCountry";
+    pub const PATH: &str = "\\Segment\\Chapters\\EditionEntry\\+ChapterAtom\\ChapterDisplay\\ChapCountry";
+    pub const MIN_OCCURS: u64 = 0;
+
+    pub fn new(value: impl Into<String>) -> Self {
+        Self { value: value.into() }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlString::new(data)?.as_str()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::string(Self::ID, &self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ChapProcess {
+    pub chap_process_codec_id: Option<ChapProcessCodecID>,
+    pub chap_process_private: Option<ChapProcessPrivate>,
+    pub chap_process_command: Vec<ChapProcessCommand>,
+    /// Children the schema doesn't define for this element
+    pub unknown: Vec<crate::EbmlNode<'static>>,
+}
+
+impl ChapProcess {
+    pub const ID: u64 = 0x6944;
+    pub const NAME: &str = "ChapProcess";
+    pub const PATH: &str = "\\Segment\\Chapters\\EditionEntry\\+ChapterAtom\\ChapProcess";
+    pub const MIN_OCCURS: u64 = 0;
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        if !node.is_master() {
+            return Err(crate::EbmlError::NotMasterElement(node.id));
+        }
+        let mut value = Self::default();
+        for child in node.children() {
+            match child.id {
+                ChapProcessCodecID::ID => value.chap_process_codec_id = Some(ChapProcessCodecID::from_node(child)?),
+                ChapProcessPrivate::ID => value.chap_process_private = Some(ChapProcessPrivate::from_node(child)?),
+                ChapProcessCommand::ID => value.chap_process_command.push(ChapProcessCommand::from_node(child)?),
+                0xBF | 0xEC => {}
+                _ => value.unknown.push(child.clone().into_owned()),
+            }
+        }
+        Ok(value)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        let mut children = Vec::new();
+        if let Some(child) = &self.chap_process_codec_id {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.chap_process_private {
+            children.push(child.to_node());
+        }
+        for child in &self.chap_process_command {
+            children.push(child.to_node());
+        }
+        children.extend(self.unknown.iter().cloned());
+        crate::EbmlNode::master(Self::ID, children)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChapProcessCodecID {
+    pub value: u64,
+}
+
+impl ChapProcessCodecID {
+    pub const ID: u64 = 0x6955;
+    pub const NAME: &str = "ChapProcessCodecID";
+    pub const PATH: &str = "\\Segment\\Chapters\\EditionEntry\\+ChapterAtom\\ChapProcess\\ChapProcessCodecID";
+    pub const DEFAULT: u64 = 0;
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data, an empty element stands for `DEFAULT`
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        if data.is_empty() {
+            return Ok(Self::new(Self::DEFAULT));
+        }
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChapProcessPrivate {
+    pub value: Vec<u8>,
+}
+
+impl ChapProcessPrivate {
+    pub const ID: u64 = 0x450D;
+    pub const NAME: &str = "ChapProcessPrivate";
+    pub const PATH: &str = "\\Segment\\Chapters\\EditionEntry\\+ChapterAtom\\ChapProcess\\ChapProcessPrivate";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: Vec<u8>) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(data.to_vec()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::binary(Self::ID, self.value.clone())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ChapProcessCommand {
+    pub chap_process_time: Option<ChapProcessTime>,
+    pub chap_process_data: Option<ChapProcessData>,
+    /// Children the schema doesn't define for this element
+    pub unknown: Vec<crate::EbmlNode<'static>>,
+}
+
+impl ChapProcessCommand {
+    pub const ID: u64 = 0x6911;
+    pub const NAME: &str = "ChapProcessCommand";
+    pub const PATH: &str = "\\Segment\\Chapters\\EditionEntry\\+ChapterAtom\\ChapProcess\\ChapProcessCommand";
+    pub const MIN_OCCURS: u64 = 0;
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        if !node.is_master() {
+            return Err(crate::EbmlError::NotMasterElement(node.id));
+        }
+        let mut value = Self::default();
+        for child in node.children() {
+            match child.id {
+                ChapProcessTime::ID => value.chap_process_time = Some(ChapProcessTime::from_node(child)?),
+                ChapProcessData::ID => value.chap_process_data = Some(ChapProcessData::from_node(child)?),
+                0xBF | 0xEC => {}
+                _ => value.unknown.push(child.clone().into_owned()),
+            }
+        }
+        Ok(value)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        let mut children = Vec::new();
+        if let Some(child) = &self.chap_process_time {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.chap_process_data {
+            children.push(child.to_node());
+        }
+        children.extend(self.unknown.iter().cloned());
+        crate::EbmlNode::master(Self::ID, children)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChapProcessTime {
+    pub value: u64,
+}
+
+impl ChapProcessTime {
+    pub const ID: u64 = 0x6922;
+    pub const NAME: &str = "ChapProcessTime";
+    pub const PATH: &str = "\\Segment\\Chapters\\EditionEntry\\+ChapterAtom\\ChapProcess\\ChapProcessCommand\\ChapProcessTime";
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChapProcessData {
+    pub value: Vec<u8>,
+}
+
+impl ChapProcessData {
+    pub const ID: u64 = 0x6933;
+    pub const NAME: &str = "ChapProcessData";
+    pub const PATH: &str = "\\Segment\\Chapters\\EditionEntry\\+ChapterAtom\\ChapProcess\\ChapProcessCommand\\ChapProcessData";
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: Vec<u8>) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(data.to_vec()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::binary(Self::ID, self.value.clone())
+    }
+}
+
+/// Element containing metadata describing Tracks, Editions, Chapters, Attachments, or the Segment as a whole.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Tags {
+    pub tag: Vec<Tag>,
+    /// Children the schema doesn't define for this element
+    pub unknown: Vec<crate::EbmlNode<'static>>,
+}
+
+impl Tags {
+    pub const ID: u64 = 0x1254C367;
+    pub const NAME: &str = "Tags";
+    pub const PATH: &str = "\\Segment\\Tags";
+    pub const MIN_OCCURS: u64 = 0;
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        if !node.is_master() {
+            return Err(crate::EbmlError::NotMasterElement(node.id));
+        }
+        let mut value = Self::default();
+        for child in node.children() {
+            match child.id {
+                Tag::ID => value.tag.push(Tag::from_node(child)?),
+                0xBF | 0xEC => {}
+                _ => value.unknown.push(child.clone().into_owned()),
+            }
+        }
+        Ok(value)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        let mut children = Vec::new();
+        for child in &self.tag {
+            children.push(child.to_node());
+        }
+        children.extend(self.unknown.iter().cloned());
+        crate::EbmlNode::master(Self::ID, children)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Tag {
+    pub targets: Option<Targets>,
+    pub simple_tag: Vec<SimpleTag>,
+    /// Children the schema doesn't define for this element
+    pub unknown: Vec<crate::EbmlNode<'static>>,
+}
+
+impl Tag {
+    pub const ID: u64 = 0x7373;
+    pub const NAME: &str = "Tag";
+    pub const PATH: &str = "\\Segment\\Tags\\Tag";
+    pub const MIN_OCCURS: u64 = 1;
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        if !node.is_master() {
+            return Err(crate::EbmlError::NotMasterElement(node.id));
+        }
+        let mut value = Self::default();
+        for child in node.children() {
+            match child.id {
+                Targets::ID => value.targets = Some(Targets::from_node(child)?),
+                SimpleTag::ID => value.simple_tag.push(SimpleTag::from_node(child)?),
+                0xBF | 0xEC => {}
+                _ => value.unknown.push(child.clone().into_owned()),
+            }
+        }
+        Ok(value)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        let mut children = Vec::new();
+        if let Some(child) = &self.targets {
+            children.push(child.to_node());
+        }
+        for child in &self.simple_tag {
+            children.push(child.to_node());
+        }
+        children.extend(self.unknown.iter().cloned());
+        crate::EbmlNode::master(Self::ID, children)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Targets {
+    pub target_type_value: Option<TargetTypeValue>,
+    pub target_type: Option<TargetType>,
+    pub tag_track_uid: Vec<TagTrackUID>,
+    pub tag_edition_uid: Vec<TagEditionUID>,
+    pub tag_chapter_uid: Vec<TagChapterUID>,
+    pub tag_attachment_uid: Vec<TagAttachmentUID>,
+    /// Children the schema doesn't define for this element
+    pub unknown: Vec<crate::EbmlNode<'static>>,
+}
+
+impl Targets {
+    pub const ID: u64 = 0x63C0;
+    pub const NAME: &str = "Targets";
+    pub const PATH: &str = "\\Segment\\Tags\\Tag\\Targets";
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        if !node.is_master() {
+            return Err(crate::EbmlError::NotMasterElement(node.id));
+        }
+        let mut value = Self::default();
+        for child in node.children() {
+            match child.id {
+                TargetTypeValue::ID => value.target_type_value = Some(TargetTypeValue::from_node(child)?),
+                TargetType::ID => value.target_type = Some(TargetType::from_node(child)?),
+                TagTrackUID::ID => value.tag_track_uid.push(TagTrackUID::from_node(child)?),
+                TagEditionUID::ID => value.tag_edition_uid.push(TagEditionUID::from_node(child)?),
+                TagChapterUID::ID => value.tag_chapter_uid.push(TagChapterUID::from_node(child)?),
+                TagAttachmentUID::ID => value.tag_attachment_uid.push(TagAttachmentUID::from_node(child)?),
+                0xBF | 0xEC => {}
+                _ => value.unknown.push(child.clone().into_owned()),
+            }
+        }
+        Ok(value)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        let mut children = Vec::new();
+        if let Some(child) = &self.target_type_value {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.target_type {
+            children.push(child.to_node());
+        }
+        for child in &self.tag_track_uid {
+            children.push(child.to_node());
+        }
+        for child in &self.tag_edition_uid {
+            children.push(child.to_node());
+        }
+        for child in &self.tag_chapter_uid {
+            children.push(child.to_node());
+        }
+        for child in &self.tag_attachment_uid {
+            children.push(child.to_node());
+        }
+        children.extend(self.unknown.iter().cloned());
+        crate::EbmlNode::master(Self::ID, children)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TargetTypeValue {
+    pub value: u64,
+}
+
+impl TargetTypeValue {
+    pub const ID: u64 = 0x68CA;
+    pub const NAME: &str = "TargetTypeValue";
+    pub const PATH: &str = "\\Segment\\Tags\\Tag\\Targets\\TargetTypeValue";
+    pub const DEFAULT: u64 = 50;
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data, an empty element stands for `DEFAULT`
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        if data.is_empty() {
+            return Ok(Self::new(Self::DEFAULT));
+        }
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TargetType {
+    pub value: String,
+}
+
+impl TargetType {
+    pub const ID: u64 = 0x63CA;
+    pub const NAME: &str = "TargetType";
+    pub const PATH: &str = "\\Segment\\Tags\\Tag\\Targets\\TargetType";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: impl Into<String>) -> Self {
+        Self { value: value.into() }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlString::new(data)?.as_str()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::string(Self::ID, &self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TagTrackUID {
+    pub value: u64,
+}
+
+impl TagTrackUID {
+    pub const ID: u64 = 0x63C5;
+    pub const NAME: &str = "TagTrackUID";
+    pub const PATH: &str = "\\Segment\\Tags\\Tag\\Targets\\TagTrackUID";
+    pub const DEFAULT: u64 = 0;
+    pub const MIN_OCCURS: u64 = 0;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data, an empty element stands for `DEFAULT`
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        if data.is_empty() {
+            return Ok(Self::new(Self::DEFAULT));
+        }
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TagEditionUID {
+    pub value: u64,
+}
+
+impl TagEditionUID {
+    pub const ID: u64 = 0x63C9;
+    pub const NAME: &str = "TagEditionUID";
+    pub const PATH: &str = "\\Segment\\Tags\\Tag\\Targets\\TagEditionUID";
+    pub const DEFAULT: u64 = 0;
+    pub const MIN_OCCURS: u64 = 0;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data, an empty element stands for `DEFAULT`
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        if data.is_empty() {
+            return Ok(Self::new(Self::DEFAULT));
+        }
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TagChapterUID {
+    pub value: u64,
+}
+
+impl TagChapterUID {
+    pub const ID: u64 = 0x63C4;
+    pub const NAME: &str = "TagChapterUID";
+    pub const PATH: &str = "\\Segment\\Tags\\Tag\\Targets\\TagChapterUID";
+    pub const DEFAULT: u64 = 0;
+    pub const MIN_OCCURS: u64 = 0;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data, an empty element stands for `DEFAULT`
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        if data.is_empty() {
+            return Ok(Self::new(Self::DEFAULT));
+        }
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TagAttachmentUID {
+    pub value: u64,
+}
+
+impl TagAttachmentUID {
+    pub const ID: u64 = 0x63C6;
+    pub const NAME: &str = "TagAttachmentUID";
+    pub const PATH: &str = "\\Segment\\Tags\\Tag\\Targets\\TagAttachmentUID";
+    pub const DEFAULT: u64 = 0;
+    pub const MIN_OCCURS: u64 = 0;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data, an empty element stands for `DEFAULT`
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        if data.is_empty() {
+            return Ok(Self::new(Self::DEFAULT));
+        }
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SimpleTag {
+    pub simple_tag: Vec<SimpleTag>,
+    pub tag_name: Option<TagName>,
+    pub tag_language: Option<TagLanguage>,
+    pub tag_language_bcp47: Option<TagLanguageBCP47>,
+    pub tag_default: Option<TagDefault>,
+    pub tag_default_bogus: Option<TagDefaultBogus>,
+    pub tag_string: Option<TagString>,
+    pub tag_binary: Option<TagBinary>,
+    /// Children the schema doesn't define for this element
+    pub unknown: Vec<crate::EbmlNode<'static>>,
+}
+
+impl SimpleTag {
+    pub const ID: u64 = 0x67C8;
+    pub const NAME: &str = "SimpleTag";
+    pub const PATH: &str = "\\Segment\\Tags\\Tag\\+SimpleTag";
+    pub const MIN_OCCURS: u64 = 1;
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        if !node.is_master() {
+            return Err(crate::EbmlError::NotMasterElement(node.id));
+        }
+        let mut value = Self::default();
+        for child in node.children() {
+            match child.id {
+                SimpleTag::ID => value.simple_tag.push(SimpleTag::from_node(child)?),
+                TagName::ID => value.tag_name = Some(TagName::from_node(child)?),
+                TagLanguage::ID => value.tag_language = Some(TagLanguage::from_node(child)?),
+                TagLanguageBCP47::ID => value.tag_language_bcp47 = Some(TagLanguageBCP47::from_node(child)?),
+                TagDefault::ID => value.tag_default = Some(TagDefault::from_node(child)?),
+                TagDefaultBogus::ID => value.tag_default_bogus = Some(TagDefaultBogus::from_node(child)?),
+                TagString::ID => value.tag_string = Some(TagString::from_node(child)?),
+                TagBinary::ID => value.tag_binary = Some(TagBinary::from_node(child)?),
+                0xBF | 0xEC => {}
+                _ => value.unknown.push(child.clone().into_owned()),
+            }
+        }
+        Ok(value)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        let mut children = Vec::new();
+        for child in &self.simple_tag {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.tag_name {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.tag_language {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.tag_language_bcp47 {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.tag_default {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.tag_default_bogus {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.tag_string {
+            children.push(child.to_node());
+        }
+        if let Some(child) = &self.tag_binary {
+            children.push(child.to_node());
+        }
+        children.extend(self.unknown.iter().cloned());
+        crate::EbmlNode::master(Self::ID, children)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TagName {
+    pub value: String,
+}
+
+impl TagName {
+    pub const ID: u64 = 0x45A3;
+    pub const NAME: &str = "TagName";
+    pub const PATH: &str = "\\Segment\\Tags\\Tag\\+SimpleTag\\TagName";
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: impl Into<String>) -> Self {
+        Self { value: value.into() }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUtf8::new(data)?.as_str()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::string(Self::ID, &self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TagLanguage {
+    pub value: String,
+}
+
+impl TagLanguage {
+    pub const ID: u64 = 0x447A;
+    pub const NAME: &str = "TagLanguage";
+    pub const PATH: &str = "\\Segment\\Tags\\Tag\\+SimpleTag\\TagLanguage";
+    pub const DEFAULT: &str = "und";
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: impl Into<String>) -> Self {
+        Self { value: value.into() }
+    }
+
+    /// Decodes the element's data, an empty element stands for `DEFAULT`
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        if data.is_empty() {
+            return Ok(Self::new(Self::DEFAULT));
+        }
+        Ok(Self::new(crate::EbmlString::new(data)?.as_str()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::string(Self::ID, &self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TagLanguageBCP47 {
+    pub value: String,
+}
+
+impl TagLanguageBCP47 {
+    pub const ID: u64 = 0x447B;
+    pub const NAME: &str = "TagLanguageBCP47";
+    pub const PATH: &str = "\\Segment\\Tags\\Tag\\+SimpleTag\\TagLanguageBCP47";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: impl Into<String>) -> Self {
+        Self { value: value.into() }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlString::new(data)?.as_str()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::string(Self::ID, &self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TagDefault {
+    pub value: u64,
+}
+
+impl TagDefault {
+    pub const ID: u64 = 0x4484;
+    pub const NAME: &str = "TagDefault";
+    pub const PATH: &str = "\\Segment\\Tags\\Tag\\+SimpleTag\\TagDefault";
+    pub const DEFAULT: u64 = 1;
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data, an empty element stands for `DEFAULT`
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        if data.is_empty() {
+            return Ok(Self::new(Self::DEFAULT));
+        }
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TagDefaultBogus {
+    pub value: u64,
+}
+
+impl TagDefaultBogus {
+    pub const ID: u64 = 0x44B4;
+    pub const NAME: &str = "TagDefaultBogus";
+    pub const PATH: &str = "\\Segment\\Tags\\Tag\\+SimpleTag\\TagDefaultBogus";
+    pub const DEFAULT: u64 = 1;
+    pub const MIN_OCCURS: u64 = 1;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: u64) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data, an empty element stands for `DEFAULT`
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        if data.is_empty() {
+            return Ok(Self::new(Self::DEFAULT));
+        }
+        Ok(Self::new(crate::EbmlUnsignedInteger::new(data)?.value()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::unsigned(Self::ID, self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TagString {
+    pub value: String,
+}
+
+impl TagString {
+    pub const ID: u64 = 0x4487;
+    pub const NAME: &str = "TagString";
+    pub const PATH: &str = "\\Segment\\Tags\\Tag\\+SimpleTag\\TagString";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: impl Into<String>) -> Self {
+        Self { value: value.into() }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(crate::EbmlUtf8::new(data)?.as_str()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::string(Self::ID, &self.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TagBinary {
+    pub value: Vec<u8>,
+}
+
+impl TagBinary {
+    pub const ID: u64 = 0x4485;
+    pub const NAME: &str = "TagBinary";
+    pub const PATH: &str = "\\Segment\\Tags\\Tag\\+SimpleTag\\TagBinary";
+    pub const MIN_OCCURS: u64 = 0;
+    pub const MAX_OCCURS: u64 = 1;
+
+    pub fn new(value: Vec<u8>) -> Self {
+        Self { value }
+    }
+
+    /// Decodes the element's data
+    pub fn parse(data: &[u8]) -> Result<Self, crate::EbmlError> {
+        Ok(Self::new(data.to_vec()))
+    }
+
+    pub fn from_node(node: &crate::EbmlNode) -> Result<Self, crate::EbmlError> {
+        if node.id != Self::ID {
+            return Err(crate::EbmlError::UnexpectedElement(Self::ID, node.id));
+        }
+        Self::parse(node.data().ok_or(crate::EbmlError::IsMasterElement(node.id))?)
+    }
+
+    pub fn to_node(&self) -> crate::EbmlNode<'static> {
+        crate::EbmlNode::binary(Self::ID, self.value.clone())
+    }
+}
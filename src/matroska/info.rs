@@ -0,0 +1,126 @@
+use super::{Info, MuxingApp, SegmentReader, TimestampScale, WritingApp};
+use crate::{EbmlDate, EbmlError, EbmlNode};
+use std::time::{self, SystemTime};
+
+/// The `Info` of a Segment with the schema defaults filled in
+#[derive(Debug, Clone, PartialEq)]
+pub struct SegmentInfo {
+    /// Nanoseconds per timestamp unit
+    pub timestamp_scale: u64,
+    /// In TimestampScale units
+    pub duration: Option<f64>,
+    pub date_utc: Option<SystemTime>,
+    pub title: Option<String>,
+    pub muxing_app: String,
+    pub writing_app: String,
+    pub segment_uuid: Option<Vec<u8>>,
+}
+
+impl SegmentInfo {
+    /// Reads an `Info` node
+    pub fn from_node(node: &EbmlNode) -> Result<Self, EbmlError> {
+        Self::from_info(Info::from_node(node)?)
+    }
+
+    /// Takes the values out of a generated `Info`, failing when a mandatory element
+    /// without a default is missing
+    pub fn from_info(info: Info) -> Result<Self, EbmlError> {
+        let date_utc = match info.date_utc {
+            Some(date) => Some(EbmlDate::new(&date.value.to_be_bytes())?.to_system_time()),
+            None => None,
+        };
+        Ok(Self {
+            timestamp_scale: info
+                .timestamp_scale
+                .map_or(TimestampScale::DEFAULT, |s| s.value),
+            duration: info.duration.map(|d| d.value),
+            date_utc,
+            title: info.title.map(|t| t.value),
+            muxing_app: info
+                .muxing_app
+                .ok_or(EbmlError::MissingElement(MuxingApp::NAME))?
+                .value,
+            writing_app: info
+                .writing_app
+                .ok_or(EbmlError::MissingElement(WritingApp::NAME))?
+                .value,
+            segment_uuid: info.segment_uuid.map(|u| u.value),
+        })
+    }
+
+    /// Converts a timestamp in TimestampScale units, saturating at the largest duration
+    pub fn to_duration(&self, timestamp: u64) -> time::Duration {
+        let nanos = timestamp as u128 * self.timestamp_scale as u128;
+        time::Duration::from_nanos(nanos.min(u64::MAX as u128) as u64)
+    }
+
+    /// The time of a block from its Cluster's timestamp and its relative timestamp,
+    /// times before the start of the Segment become zero
+    pub fn block_time(&self, cluster: u64, block: i16) -> time::Duration {
+        self.to_duration(cluster.saturating_add_signed(block as i64))
+    }
+
+    /// The `Duration` as a time, `None` when it's missing or not a valid length
+    pub fn total_duration(&self) -> Option<time::Duration> {
+        let seconds = self.duration? * self.timestamp_scale as f64 / 1e9;
+        time::Duration::try_from_secs_f64(seconds).ok()
+    }
+}
+
+impl<'a> SegmentReader<'a> {
+    /// Reads the `Info` of the Segment
+    pub fn info(&mut self) -> Result<Option<SegmentInfo>, EbmlError> {
+        match self.seek_to(Info::ID)? {
+            Some(info) => SegmentInfo::from_node(&info).map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matroska::segment::tests::file;
+    use crate::matroska::{DateUTC, Duration, SegmentUUID, Title};
+    use std::time::UNIX_EPOCH;
+
+    #[test]
+    fn test_info() {
+        let info = Info {
+            timestamp_scale: None,
+            duration: Some(Duration::new(1500.0)),
+            date_utc: Some(DateUTC::new(1_000_000_000)),
+            title: Some(Title::new("Example")),
+            muxing_app: Some(MuxingApp::new("rebml")),
+            writing_app: Some(WritingApp::new("tests")),
+            segment_uuid: Some(SegmentUUID::new(vec![7; 16])),
+            ..Default::default()
+        };
+        let data = file(vec![info.to_node()]);
+        let info = SegmentReader::new(&data).unwrap().info().unwrap().unwrap();
+        assert_eq!(info.timestamp_scale, 1_000_000);
+        assert_eq!(info.title.as_deref(), Some("Example"));
+        assert_eq!(info.muxing_app, "rebml");
+        assert_eq!(info.segment_uuid, Some(vec![7; 16]));
+        let date = info.date_utc.unwrap().duration_since(UNIX_EPOCH).unwrap();
+        assert_eq!(date.as_secs(), EbmlDate::EPOCH_OFFSET_SECONDS as u64 + 1);
+
+        assert_eq!(
+            info.total_duration(),
+            Some(time::Duration::from_millis(1500))
+        );
+        assert_eq!(info.to_duration(40), time::Duration::from_millis(40));
+        assert_eq!(info.block_time(1000, -20), time::Duration::from_millis(980));
+        assert_eq!(info.block_time(10, -20), time::Duration::ZERO);
+        assert_eq!(
+            info.to_duration(u64::MAX),
+            time::Duration::from_nanos(u64::MAX)
+        );
+
+        let missing = Info {
+            muxing_app: None,
+            ..Default::default()
+        };
+        assert!(SegmentInfo::from_info(missing).is_err());
+    }
+}
@@ -0,0 +1,304 @@
+use super::{BlockView, Lacing};
+use crate::{EbmlError, VarInt, VarIntLength};
+use std::io::Cursor;
+
+/// The frames of a block, in order
+#[derive(Debug, Clone)]
+pub struct Frames<'a> {
+    data: &'a [u8],
+    sizes: std::vec::IntoIter<usize>,
+}
+
+impl<'a> Iterator for Frames<'a> {
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let size = self.sizes.next()?;
+        let (frame, rest) = self.data.split_at(size);
+        self.data = rest;
+        Some(frame)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.sizes.size_hint()
+    }
+}
+
+impl ExactSizeIterator for Frames<'_> {}
+
+impl<'a> BlockView<'a> {
+    /// Splits the block data into frames according to its lacing
+    pub fn frames(&self) -> Result<Frames<'a>, EbmlError> {
+        let (header, sizes) = match self.lacing {
+            Lacing::None => (0, vec![self.data.len()]),
+            lacing => frame_sizes(lacing, self.data)?,
+        };
+        Ok(Frames {
+            data: &self.data[header..],
+            sizes: sizes.into_iter(),
+        })
+    }
+}
+
+/// Reads the lacing header, returning its length and the size of every frame
+fn frame_sizes(lacing: Lacing, data: &[u8]) -> Result<(usize, Vec<usize>), EbmlError> {
+    let truncated = || EbmlError::InvalidBlock("the lacing header is truncated");
+    let too_large = || EbmlError::InvalidBlock("laced frames are larger than the block");
+    let count = *data.first().ok_or_else(truncated)? as usize + 1;
+    let mut sizes = Vec::with_capacity(count);
+    let mut position = 1;
+
+    match lacing {
+        Lacing::Xiph => {
+            for _ in 1..count {
+                let mut size = 0;
+                loop {
+                    let byte = *data.get(position).ok_or_else(truncated)?;
+                    position += 1;
+                    size += byte as usize;
+                    if byte != 0xFF {
+                        break;
+                    }
+                }
+                sizes.push(size);
+            }
+        }
+        Lacing::Ebml => {
+            let mut cursor = Cursor::new(data);
+            cursor.set_position(1);
+            if count > 1 {
+                let mut size = VarInt::get_var_int(&mut cursor)?.value as i64;
+                sizes.push(size as usize);
+                for _ in 2..count {
+                    let delta = VarInt::get_var_int(&mut cursor)?;
+                    size = size
+                        .checked_add(delta.value as i64 - signed_bias(&delta.length))
+                        .ok_or_else(too_large)?;
+                    if size < 0 {
+                        return Err(EbmlError::InvalidBlock("a laced frame has a negative size"));
+                    }
+                    sizes.push(size as usize);
+                }
+            }
+            position = cursor.position() as usize;
+        }
+        Lacing::FixedSize => {
+            let remaining = data.len() - position;
+            if remaining % count != 0 {
+                return Err(EbmlError::InvalidBlock(
+                    "fixed size frames don't divide the data evenly",
+                ));
+            }
+            return Ok((position, vec![remaining / count; count]));
+        }
+        Lacing::None => return Ok((0, vec![data.len()])),
+    }
+
+    // Sizes come from the file, each has to fit in what's left of the block
+    let remaining = data.len() - position;
+    let laced = sizes
+        .iter()
+        .try_fold(0usize, |laced, size| {
+            laced.checked_add(*size).filter(|laced| *laced <= remaining)
+        })
+        .ok_or_else(too_large)?;
+    sizes.push(remaining - laced);
+    Ok((position, sizes))
+}
+
+/// Subtracted from a signed VarInt's value to get the difference it stores
+fn signed_bias(length: &VarIntLength) -> i64 {
+    (1 << (7 * length.num_bytes() - 1)) - 1
+}
+
+/// Packs frames into block data using `lacing`, [`Lacing::None`] takes a single frame
+/// and [`Lacing::FixedSize`] frames of equal size
+pub fn lace(lacing: Lacing, frames: &[&[u8]]) -> Result<Vec<u8>, EbmlError> {
+    let (last, rest) = frames
+        .split_last()
+        .ok_or(EbmlError::InvalidBlock("a block needs at least one frame"))?;
+    if frames.len() > 256 {
+        return Err(EbmlError::InvalidBlock("at most 256 frames can be laced"));
+    }
+    let total: usize = frames.iter().map(|f| f.len()).sum();
+    let mut data = Vec::with_capacity(total + frames.len() * 2 + 1);
+    if lacing != Lacing::None {
+        data.push((frames.len() - 1) as u8);
+    }
+
+    match lacing {
+        Lacing::None if !rest.is_empty() => {
+            return Err(EbmlError::InvalidBlock(
+                "only laced blocks hold several frames",
+            ));
+        }
+        Lacing::None => {}
+        Lacing::Xiph => {
+            for frame in rest {
+                data.extend(std::iter::repeat(0xFF).take(frame.len() / 0xFF));
+                data.push((frame.len() % 0xFF) as u8);
+            }
+        }
+        Lacing::Ebml => {
+            if let Some((first, others)) = rest.split_first() {
+                VarInt::new(first.len() as u64)?.write(&mut data)?;
+                let mut previous = first.len() as i64;
+                for frame in others {
+                    signed_var_int(frame.len() as i64 - previous)?.write(&mut data)?;
+                    previous = frame.len() as i64;
+                }
+            }
+        }
+        Lacing::FixedSize => {
+            if rest.iter().any(|f| f.len() != last.len()) {
+                return Err(EbmlError::InvalidBlock(
+                    "fixed size lacing needs frames of equal size",
+                ));
+            }
+        }
+    }
+
+    for frame in frames {
+        data.extend_from_slice(frame);
+    }
+    Ok(data)
+}
+
+/// Packs frames with whichever lacing gives the smallest data
+pub fn lace_smallest(frames: &[&[u8]]) -> Result<(Lacing, Vec<u8>), EbmlError> {
+    let first = frames.first().map(|f| f.len());
+    let lacing = if frames.len() == 1 {
+        Lacing::None
+    } else if frames.iter().all(|f| Some(f.len()) == first) {
+        Lacing::FixedSize
+    } else {
+        let xiph = lace(Lacing::Xiph, frames)?;
+        let ebml = lace(Lacing::Ebml, frames)?;
+        return Ok(if ebml.len() < xiph.len() {
+            (Lacing::Ebml, ebml)
+        } else {
+            (Lacing::Xiph, xiph)
+        });
+    };
+    Ok((lacing, lace(lacing, frames)?))
+}
+
+/// Encodes a size difference of EBML lacing in the fewest octets
+fn signed_var_int(delta: i64) -> Result<VarInt, EbmlError> {
+    for num_bytes in 1..=8 {
+        let length = VarIntLength::new(num_bytes)?;
+        let bias = signed_bias(&length);
+        if delta.abs() <= bias {
+            return VarInt::with_length((delta + bias) as u64, length);
+        }
+    }
+    Err(EbmlError::VarIntTooLarge)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn block(lacing: Lacing, data: &[u8]) -> BlockView<'_> {
+        BlockView {
+            lacing,
+            ..BlockView::new(true, 1, 0, data)
+        }
+    }
+
+    fn frames(lacing: Lacing, data: &[u8]) -> Vec<Vec<u8>> {
+        block(lacing, data)
+            .frames()
+            .unwrap()
+            .map(|f| f.to_vec())
+            .collect()
+    }
+
+    #[test]
+    fn test_decode() {
+        // Xiph: sizes 256 (255 + 1) and 2, then the rest
+        let mut data = vec![0x02, 0xFF, 0x01, 0x02];
+        data.extend(std::iter::repeat(7).take(256 + 2 + 3));
+        let decoded = frames(Lacing::Xiph, &data);
+        let sizes: Vec<_> = decoded.iter().map(|f| f.len()).collect();
+        assert_eq!(sizes, [256, 2, 3]);
+
+        // EBML: 3, then +2 (0xBF + 2), then the rest
+        let data = [0x02, 0x83, 0xC1, 1, 1, 1, 2, 2, 2, 2, 2, 3];
+        assert_eq!(
+            frames(Lacing::Ebml, &data),
+            [vec![1; 3], vec![2; 5], vec![3]]
+        );
+
+        let data = [0x01, 1, 1, 2, 2];
+        assert_eq!(frames(Lacing::FixedSize, &data), [vec![1, 1], vec![2, 2]]);
+        assert_eq!(frames(Lacing::None, &[5, 6]), [vec![5, 6]]);
+
+        assert!(block(Lacing::FixedSize, &[0x01, 1, 2, 3]).frames().is_err());
+        assert!(block(Lacing::Xiph, &[0x01, 0x05, 1]).frames().is_err());
+        assert!(block(Lacing::Ebml, &[0x02, 0x81, 0x80, 1])
+            .frames()
+            .is_err());
+        assert!(block(Lacing::Xiph, &[]).frames().is_err());
+    }
+
+    #[test]
+    fn test_huge_sizes() {
+        // 255 frames, the largest first size and deltas add up past u64::MAX
+        let mut data = vec![0xFE, 0x01, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFE];
+        for _ in 0..253 {
+            data.extend_from_slice(&[0x01, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFE]);
+        }
+        data.extend_from_slice(&[1, 2, 3]);
+        assert!(matches!(
+            block(Lacing::Ebml, &data).frames(),
+            Err(EbmlError::InvalidBlock(_))
+        ));
+
+        // A small first size followed by a huge delta
+        let mut data = vec![0x02, 0x81];
+        data.extend_from_slice(&[0x01, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFE, 1]);
+        assert!(block(Lacing::Ebml, &data).frames().is_err());
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let long = vec![9; 300];
+        let sets: [&[&[u8]]; 4] = [
+            &[&[1, 2, 3]],
+            &[&[1, 2], &[3, 4], &[5, 6]],
+            &[&long, &[1], &[2, 3]],
+            &[&[1; 1000], &[2; 1001], &[3; 1002], &[]],
+        ];
+        for set in sets {
+            for lacing in [Lacing::Xiph, Lacing::Ebml] {
+                let data = lace(lacing, set).unwrap();
+                let decoded: Vec<_> = block(lacing, &data).frames().unwrap().collect();
+                assert_eq!(decoded, set);
+            }
+            let (lacing, data) = lace_smallest(set).unwrap();
+            let decoded: Vec<_> = block(lacing, &data).frames().unwrap().collect();
+            assert_eq!(decoded, set);
+        }
+
+        assert_eq!(lace_smallest(sets[0]).unwrap().0, Lacing::None);
+        assert_eq!(lace_smallest(sets[1]).unwrap().0, Lacing::FixedSize);
+        // Xiph needs two octets for the 300 octet frame, EBML sizes take two each
+        assert_eq!(lace_smallest(sets[2]).unwrap().0, Lacing::Xiph);
+        // Similar sizes are small differences for EBML but four octets each for Xiph
+        assert_eq!(lace_smallest(sets[3]).unwrap().0, Lacing::Ebml);
+
+        assert!(lace(Lacing::None, sets[1]).is_err());
+        assert!(lace(Lacing::FixedSize, sets[2]).is_err());
+        assert!(lace(Lacing::Xiph, &[]).is_err());
+    }
+
+    #[test]
+    fn test_signed_var_int() {
+        assert_eq!(signed_var_int(0).unwrap().as_bytes()[7], 0xBF);
+        assert_eq!(signed_var_int(-63).unwrap().as_bytes()[7], 0x80);
+        let two = signed_var_int(64).unwrap();
+        assert_eq!(two.length, VarIntLength::Two);
+        assert_eq!(two.value as i64 - signed_bias(&two.length), 64);
+    }
+}
@@ -0,0 +1,162 @@
+//! Matroska and WebM element definitions
+//!
+//! Every element of the bundled schema has a typed struct generated by
+//! [`codegen`](crate::codegen), so `matroska::Segment::ID` can be used in place of
+//! `0x18538067`. WebM uses the same elements under the `webm` DocType.
+//!
+//! [`BlockView`] reads the track, timestamp and flags packed into `SimpleBlock` and
+//! `Block` data and splits laced blocks into frames, [`lace`] packs frames together.
+//! [`SegmentReader`] jumps to top level elements through the `SeekHead` and to the
+//! Cluster to decode a timestamp from through the [`CueIndex`], [`SegmentWriter`]
+//! writes Clusters and generates the Cues for their keyframes. [`TrackInfo`] reads a
+//! `TrackEntry` with the schema defaults filled in, [`SegmentInfo`] does the same for
+//! `Info` and converts timestamps to durations. [`TagList`] edits `Tags` and writes
+//! them back over their `Void` padding, [`ChapterList`] does the same for `Chapters`.
+//! [`AttachmentView`] borrows attached files from the buffer, [`add_attachment`] and
+//! [`remove_attachment`] edit them in place.
+
+mod attachments;
+mod block;
+mod chapters;
+mod cues;
+#[rustfmt::skip]
+mod elements;
+mod info;
+mod lacing;
+mod segment;
+mod tags;
+mod tracks;
+mod writer;
+
+pub use attachments::{add_attachment, remove_attachment, AttachmentView};
+pub use block::{BlockView, Lacing};
+pub use chapters::{Chapter, ChapterList, ChapterName, Edition};
+pub use cues::{CueEntry, CueIndex, CueTarget};
+pub use elements::*;
+pub use info::SegmentInfo;
+pub use lacing::{lace, lace_smallest, Frames};
+pub use segment::{SeekEntry, SegmentReader};
+pub use tags::{TagField, TagGroup, TagList};
+pub use tracks::{AudioInfo, TrackInfo, TrackKind, VideoInfo};
+pub use writer::SegmentWriter;
+
+use crate::{EbmlSchema, ElementType};
+use std::sync::OnceLock;
+
+/// The Matroska EBML schema the element structs are generated from
+pub const SCHEMA_XML: &str = include_str!("../../schemas/matroska.xml");
+
+/// The parsed Matroska schema
+pub fn schema() -> &'static EbmlSchema {
+    static SCHEMA: OnceLock<EbmlSchema> = OnceLock::new();
+    SCHEMA.get_or_init(|| EbmlSchema::from_xml(SCHEMA_XML).expect("The Matroska schema is valid"))
+}
+
+/// The name of a Matroska or EBML header element, e.g. `Some("Segment")` for `0x18538067`
+pub fn element_name(id: u64) -> Option<&'static str> {
+    schema().element(id).map(|e| e.name.as_str())
+}
+
+pub fn element_type(id: u64) -> Option<ElementType> {
+    schema().element(id).map(|e| e.element_type)
+}
+
+/// The ID of the element's parent, `None` for top level, global and unknown elements.
+/// Recursive elements report the parent they first appear under.
+pub fn parent_of(id: u64) -> Option<u64> {
+    let parent = schema().element(id)?.path.parent_name()?;
+    schema().element_by_name(parent).map(|e| e.id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::codegen::Generator;
+
+    #[test]
+    fn test_generated_is_current() {
+        let code = Generator::new(schema()).with_crate_path("crate").generate();
+        assert!(
+            code == include_str!("elements.rs"),
+            "src/matroska/elements.rs is out of date with schemas/matroska.xml"
+        );
+    }
+
+    #[test]
+    fn test_xml_round_trip() {
+        let xml = schema().to_xml();
+        assert_eq!(&EbmlSchema::from_xml(&xml).unwrap(), schema());
+    }
+
+    #[test]
+    fn test_lookups() {
+        assert_eq!(element_name(0x18538067), Some("Segment"));
+        assert_eq!(element_name(0x1A45DFA3), Some("EBML"));
+        assert_eq!(element_name(0xEC), Some("Void"));
+        assert_eq!(element_name(0x12), None);
+
+        assert_eq!(element_type(Cluster::ID), Some(ElementType::Master));
+        assert_eq!(element_type(SimpleBlock::ID), Some(ElementType::Binary));
+        assert_eq!(element_type(Duration::ID), Some(ElementType::Float));
+
+        assert_eq!(parent_of(Segment::ID), None);
+        assert_eq!(parent_of(Cluster::ID), Some(Segment::ID));
+        assert_eq!(parent_of(PixelWidth::ID), Some(Video::ID));
+        assert_eq!(parent_of(ChapterAtom::ID), Some(EditionEntry::ID));
+        assert_eq!(parent_of(ChapString::ID), Some(ChapterDisplay::ID));
+        assert_eq!(parent_of(0xEC), None);
+        assert_eq!(parent_of(0x4282), Some(0x1A45DFA3));
+    }
+
+    #[test]
+    fn test_zero_id() {
+        let display = ChapterDisplay {
+            chap_string: Some(ChapString::new("Intro")),
+            ..Default::default()
+        };
+        let data = crate::EbmlDocument::new(vec![display.to_node()])
+            .to_bytes()
+            .unwrap();
+        let doc = crate::EbmlDocument::parse(&data[..], schema()).unwrap();
+        assert_eq!(ChapterDisplay::from_node(&doc.children[0]).unwrap(), display);
+
+        // A second display ends one of unknown size
+        #[rustfmt::skip]
+        let unknown = [
+            0x80, 0xFF, // ChapterDisplay
+            0x85, 0x81, b'a', // ChapString
+            0x80, 0x84, // ChapterDisplay
+            0x85, 0x82, b'b', b'c', // ChapString
+        ];
+        let atom = crate::EbmlNode::master(ChapterAtom::ID, Vec::new());
+        let mut bytes = Vec::new();
+        atom.write(&mut bytes).unwrap();
+        bytes.pop();
+        bytes.push(0x80 | unknown.len() as u8);
+        bytes.extend_from_slice(&unknown);
+        let doc = crate::EbmlDocument::parse(&bytes[..], schema()).unwrap();
+        let atom = ChapterAtom::from_node(&doc.children[0]).unwrap();
+        assert_eq!(atom.chapter_display.len(), 2);
+
+        // Strict reads without the schema still reject it
+        let kinds = |id| id == ChapterDisplay::ID;
+        assert!(matches!(
+            crate::EbmlDocument::parse(&data[..], &kinds),
+            Err(crate::EbmlError::ElementIdAllZeros)
+        ));
+    }
+
+    #[test]
+    fn test_unique_ids() {
+        let elements = schema().elements();
+        for element in elements {
+            assert_eq!(schema().element(element.id).unwrap().name, element.name);
+            assert!(
+                crate::EbmlSchema::builtin().element(element.id).is_none(),
+                "{} reuses a header ID",
+                element.name
+            );
+        }
+        assert!(elements.len() > 200);
+    }
+}
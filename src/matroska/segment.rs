@@ -0,0 +1,466 @@
+use super::{schema, Cluster, Seek, SeekHead, SeekID, SeekPosition, Segment};
+use crate::inplace::write_void;
+use crate::tree::TreeParser;
+use crate::{
+    element_id_length, Ebml, EbmlElement, EbmlError, EbmlNode, EbmlWarning, InPlaceEditor,
+    ParseContext, Void,
+};
+use std::io::Cursor;
+
+/// A `Seek` entry, pointing at a top level element
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SeekEntry {
+    pub id: u64,
+    /// Relative to the start of the Segment's data
+    pub position: u64,
+}
+
+impl SeekEntry {
+    /// Reads a `Seek` element, `None` when it lacks the ID or the position
+    pub fn from_seek(seek: &Seek) -> Option<Self> {
+        let id = seek.seek_id.as_ref()?.value.iter();
+        Some(Self {
+            id: id.fold(0, |id, b| id << 8 | *b as u64),
+            position: seek.seek_position.as_ref()?.value,
+        })
+    }
+
+    /// The `Seek` element pointing at the entry
+    pub fn to_seek(&self) -> Seek {
+        let length = element_id_length(self.id);
+        Seek {
+            seek_id: Some(SeekID::new(self.id.to_be_bytes()[8 - length..].to_vec())),
+            seek_position: Some(SeekPosition::new(self.position)),
+            ..Default::default()
+        }
+    }
+}
+
+/// Finds the top level elements of a Matroska Segment through its `SeekHead` without
+/// reading Clusters.
+///
+/// Only the elements ahead of the first Cluster are read when opening, elements after
+/// it are located through the `SeekHead` entries (following a second `SeekHead` when the
+/// first one points to it).
+#[derive(Debug)]
+pub struct SegmentReader<'a> {
+    data: &'a [u8],
+    /// Position of the Segment element
+    offset: u64,
+    data_start: u64,
+    end: u64,
+    entries: Vec<SeekEntry>,
+    context: ParseContext,
+}
+
+impl<'a> SegmentReader<'a> {
+    /// Opens the first Segment in `data`, which starts with the EBML header
+    pub fn new(data: &'a [u8]) -> Result<Self, EbmlError> {
+        Self::with_context(data, ParseContext::strict())
+    }
+
+    /// Opens the first Segment reading with `context`
+    pub fn with_context(data: &'a [u8], mut context: ParseContext) -> Result<Self, EbmlError> {
+        let mut cursor = Cursor::new(data);
+        let (offset, segment) = loop {
+            let offset = cursor.position();
+            let element = EbmlElement::read(&mut cursor, &mut context)?;
+            match element.id {
+                Segment::ID => break (offset, element),
+                Ebml::ID => cursor.set_position(cursor.position() + element.size.value),
+                id => return Err(EbmlError::UnexpectedElement(Segment::ID, id)),
+            }
+        };
+        let data_start = cursor.position();
+        let end = if segment.size.all_ones() {
+            data.len() as u64
+        } else {
+            (data_start + segment.size.value).min(data.len() as u64)
+        };
+
+        let mut reader = Self {
+            data,
+            offset,
+            data_start,
+            end,
+            entries: Vec::new(),
+            context,
+        };
+        reader.scan()?;
+        Ok(reader)
+    }
+
+    /// Records the elements ahead of the first Cluster and reads the SeekHeads
+    fn scan(&mut self) -> Result<(), EbmlError> {
+        let mut cursor = Cursor::new(self.data);
+        cursor.set_position(self.data_start);
+        let mut seek_heads = Vec::new();
+        while cursor.position() < self.end {
+            let position = cursor.position() - self.data_start;
+            let element = EbmlElement::read(&mut cursor, &mut self.context)?;
+            if element.id == Cluster::ID || element.size.all_ones() {
+                break;
+            }
+            self.push(SeekEntry {
+                id: element.id,
+                position,
+            });
+            if element.id == SeekHead::ID {
+                seek_heads.push(position);
+            }
+            cursor.set_position(cursor.position() + element.size.value);
+        }
+
+        let mut read = Vec::new();
+        while let Some(position) = seek_heads.pop() {
+            if read.contains(&position) {
+                continue;
+            }
+            read.push(position);
+            let node = self.node_at(position)?;
+            let seeks = SeekHead::from_node(&node)?.seek;
+            for entry in seeks.iter().filter_map(SeekEntry::from_seek) {
+                if entry.id == SeekHead::ID {
+                    seek_heads.push(entry.position);
+                }
+                self.push(entry);
+            }
+        }
+        Ok(())
+    }
+
+    fn push(&mut self, entry: SeekEntry) {
+        if !self.entries.contains(&entry) {
+            self.entries.push(entry);
+        }
+    }
+
+    /// The buffer being read
+    pub fn data(&self) -> &'a [u8] {
+        self.data
+    }
+
+    /// Position of the Segment element
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// Position of the Segment's data, which seek positions are relative to
+    pub fn data_start(&self) -> u64 {
+        self.data_start
+    }
+
+    /// End of the Segment's data
+    pub fn end(&self) -> u64 {
+        self.end
+    }
+
+    /// Every top level element found through the SeekHeads or ahead of the first Cluster
+    pub fn entries(&self) -> &[SeekEntry] {
+        &self.entries
+    }
+
+    /// The absolute position of the first top level element with the given ID
+    pub fn position(&self, id: u64) -> Option<u64> {
+        self.entries
+            .iter()
+            .find(|e| e.id == id)
+            .and_then(|e| self.data_start.checked_add(e.position))
+    }
+
+    /// Reads the first top level element with the given ID, `None` when the Segment
+    /// has no entry for it
+    pub fn seek_to(&mut self, id: u64) -> Result<Option<EbmlNode<'a>>, EbmlError> {
+        let position = match self.entries.iter().find(|e| e.id == id) {
+            Some(entry) => entry.position,
+            None => return Ok(None),
+        };
+        let node = self.node_at(position)?;
+        if node.id != id {
+            return Err(EbmlError::UnexpectedElement(id, node.id));
+        }
+        Ok(Some(node))
+    }
+
+    /// Parses the element at a position relative to the Segment's data
+    pub fn node_at(&mut self, position: u64) -> Result<EbmlNode<'a>, EbmlError> {
+        let mut cursor = Cursor::new(self.data);
+        cursor.set_position(self.absolute(position)?);
+        let mut parser = TreeParser {
+            kinds: schema(),
+            context: &mut self.context,
+        };
+        parser.node(&mut cursor, self.end, 1)
+    }
+
+    /// Reads the header of the element at a position relative to the Segment's data
+    pub(super) fn header_at(&mut self, position: u64) -> Result<EbmlElement, EbmlError> {
+        let mut cursor = Cursor::new(self.data);
+        cursor.set_position(self.absolute(position)?);
+        EbmlElement::read(&mut cursor, &mut self.context)
+    }
+
+    /// Turns a position read from the file, relative to the Segment's data, into an
+    /// offset in the buffer
+    fn absolute(&self, position: u64) -> Result<u64, EbmlError> {
+        self.data_start
+            .checked_add(position)
+            .ok_or_else(|| EbmlError::InvalidElement(format!("Position {} out of range", position)))
+    }
+
+    /// Violations accepted so far when reading leniently
+    pub fn warnings(&self) -> &[EbmlWarning] {
+        self.context.warnings()
+    }
+}
+
+/// Writes `node` over the Segment's top level element with the same ID without moving
+/// any other data, using the `Void` after it to grow. When there is no such element it
+/// goes into the first top level `Void` large enough, along with an entry in the
+/// `SeekHead`.
+pub(super) fn write_in_place(data: &mut [u8], node: &EbmlNode) -> Result<(), EbmlError> {
+    let reader = SegmentReader::new(data)?;
+    let end = reader.end();
+    let data_start = reader.data_start();
+    let existing = reader.position(node.id);
+    let voids: Vec<_> = reader
+        .entries()
+        .iter()
+        .filter(|e| e.id == Void::ID)
+        .map(|e| data_start + e.position)
+        .collect();
+
+    if let Some(position) = existing {
+        let mut editor = InPlaceEditor::new(data);
+        let span = editor.element_at(position, end)?;
+        editor.replace_element(&span, node)?;
+        return Ok(());
+    }
+
+    let needed = node.encoded_size();
+    let mut error = EbmlError::NotEnoughSpace(needed, 0);
+    for position in voids {
+        let editor = InPlaceEditor::new(data);
+        let span = editor.element_at(position, end)?;
+        let available = editor.available_space(&span)?;
+        if available < needed {
+            error = EbmlError::NotEnoughSpace(needed, available);
+            continue;
+        }
+        // The SeekHead may need the same Void to grow, put it back when it does
+        let range = position as usize..(position + available) as usize;
+        let previous = data[range.clone()].to_vec();
+        InPlaceEditor::new(data).replace_element(&span, node)?;
+        let entry = SeekEntry {
+            id: node.id,
+            position: position - data_start,
+        };
+        match add_seek_entry(data, entry) {
+            Ok(()) => return Ok(()),
+            Err(e) => {
+                data[range].copy_from_slice(&previous);
+                error = e;
+            }
+        }
+    }
+    Err(error)
+}
+
+/// Replaces the Segment's top level element with the given ID by a `Void` and drops
+/// its `SeekHead` entries, returning whether there was one
+pub(super) fn remove_in_place(data: &mut [u8], id: u64) -> Result<bool, EbmlError> {
+    let reader = SegmentReader::new(data)?;
+    let (position, end) = match reader.position(id) {
+        Some(position) => (position, reader.end()),
+        None => return Ok(false),
+    };
+    edit_seek_head(data, |seek_head| {
+        seek_head
+            .seek
+            .retain(|seek| SeekEntry::from_seek(seek).map_or(true, |e| e.id != id))
+    })?;
+
+    let span = InPlaceEditor::new(data).element_at(position, end)?;
+    let mut void = Vec::new();
+    write_void(span.end() - span.offset, &mut void)?;
+    data[span.offset as usize..span.end() as usize].copy_from_slice(&void);
+    Ok(true)
+}
+
+/// Adds an entry to the first `SeekHead` of the Segment in `data`, growing it over the
+/// `Void` after it
+fn add_seek_entry(data: &mut [u8], entry: SeekEntry) -> Result<(), EbmlError> {
+    edit_seek_head(data, |seek_head| seek_head.seek.push(entry.to_seek()))
+}
+
+/// Rewrites the first `SeekHead` in place, nothing is written when the Segment has none
+fn edit_seek_head(data: &mut [u8], edit: impl FnOnce(&mut SeekHead)) -> Result<(), EbmlError> {
+    let mut reader = SegmentReader::new(data)?;
+    let node = match reader.seek_to(SeekHead::ID)? {
+        Some(node) => node,
+        None => return Ok(()),
+    };
+    let position = reader
+        .position(SeekHead::ID)
+        .ok_or_else(|| EbmlError::InvalidElement("SeekHead has no position".to_string()))?;
+    let mut seek_head = SeekHead::from_node(&node)?;
+    edit(&mut seek_head);
+    let end = reader.end();
+
+    let mut editor = InPlaceEditor::new(data);
+    let span = editor.element_at(position, end)?;
+    editor.replace_element(&span, &seek_head.to_node())?;
+    Ok(())
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::matroska::{Info, Tags, Title, Tracks};
+    use crate::{DocType, EbmlDocument};
+
+    /// A file with `children` in its Segment. Seek entries are filled in with the
+    /// position of the first other child with their ID, their eight octet positions
+    /// keep the layout fixed.
+    pub(crate) fn file(children: Vec<EbmlNode<'static>>) -> Vec<u8> {
+        let mut children = children;
+        let mut positions = Vec::new();
+        let mut position = 0;
+        for child in &children {
+            positions.push((child.id, position));
+            position += child.encoded_size();
+        }
+        for (index, child) in children.iter_mut().enumerate() {
+            if child.id != SeekHead::ID {
+                continue;
+            }
+            for seek in child.children_mut().unwrap() {
+                let id = seek.children()[0].data().unwrap();
+                let id = id.iter().fold(0, |id, b| id << 8 | *b as u64);
+                let (_, position) = positions
+                    .iter()
+                    .enumerate()
+                    .find(|(i, (child, _))| *child == id && *i != index)
+                    .unwrap()
+                    .1;
+                let position_node = seek.child_mut(SeekPosition::ID).unwrap();
+                position_node
+                    .set_data(position.to_be_bytes().to_vec())
+                    .unwrap();
+            }
+        }
+        let header = EbmlNode::master(Ebml::ID, vec![EbmlNode::string(DocType::ID, "matroska")]);
+        EbmlDocument::new(vec![header, EbmlNode::master(Segment::ID, children)])
+            .to_bytes()
+            .unwrap()
+    }
+
+    /// A SeekHead with room for eight octet positions
+    pub(crate) fn seek_head(ids: &[u64]) -> EbmlNode<'static> {
+        let mut node = SeekHead {
+            seek: ids
+                .iter()
+                .map(|id| {
+                    SeekEntry {
+                        id: *id,
+                        position: 0,
+                    }
+                    .to_seek()
+                })
+                .collect(),
+            ..Default::default()
+        }
+        .to_node();
+        for seek in node.children_mut().unwrap() {
+            let position = seek.child_mut(SeekPosition::ID).unwrap();
+            position.set_data(vec![0; 8]).unwrap();
+        }
+        node
+    }
+
+    #[test]
+    fn test_seek_to() {
+        let info = Info {
+            title: Some(Title::new("seeking")),
+            ..Default::default()
+        };
+        let cluster = EbmlNode::master(Cluster::ID, vec![EbmlNode::unsigned(0xE7, 0)]);
+        let data = file(vec![
+            seek_head(&[Info::ID, Tags::ID, SeekHead::ID]),
+            info.to_node(),
+            cluster,
+            EbmlNode::master(Tags::ID, vec![]),
+            seek_head(&[Tracks::ID]),
+            EbmlNode::master(Tracks::ID, vec![]),
+        ]);
+
+        let mut reader = SegmentReader::new(&data).unwrap();
+        let node = reader.seek_to(Info::ID).unwrap().unwrap();
+        assert_eq!(Info::from_node(&node).unwrap(), info);
+        assert_eq!(reader.position(Info::ID), node.offset());
+        assert!(reader.seek_to(Tags::ID).unwrap().is_some());
+        // Only listed in the second SeekHead
+        let tracks = reader.seek_to(Tracks::ID).unwrap().unwrap();
+        assert_eq!(tracks.offset(), Some(data.len() as u64 - 5));
+        assert!(reader.seek_to(0x1043A770).unwrap().is_none());
+        assert!(reader.position(Cluster::ID).is_none());
+    }
+
+    #[test]
+    fn test_without_seek_head() {
+        let data = file(vec![
+            EbmlNode::master(Info::ID, vec![]),
+            EbmlNode::master(Tracks::ID, vec![]),
+        ]);
+        let mut reader = SegmentReader::new(&data).unwrap();
+        assert_eq!(reader.entries().len(), 2);
+        assert!(reader.seek_to(Tracks::ID).unwrap().is_some());
+
+        // A SeekHead pointing at the wrong element, Info is replaced by a second Tracks
+        let mut data = file(vec![
+            seek_head(&[Info::ID]),
+            EbmlNode::master(Info::ID, vec![]),
+            EbmlNode::master(Tracks::ID, vec![]),
+        ]);
+        let info = data.len() - 10;
+        data[info..info + 4].copy_from_slice(&Tracks::ID.to_be_bytes()[4..]);
+        let mut reader = SegmentReader::new(&data).unwrap();
+        assert!(reader.seek_to(Info::ID).is_err());
+    }
+
+    #[test]
+    fn test_out_of_range() {
+        let out_of_range = (u64::MAX - 2).to_be_bytes();
+        // The first SeekPosition, patched to point past the end of any buffer
+        let first_position = |data: &[u8]| {
+            data.windows(3)
+                .position(|w| w == [0x53, 0xAC, 0x88])
+                .unwrap()
+                + 3
+        };
+
+        let mut data = file(vec![
+            seek_head(&[SeekHead::ID]),
+            EbmlNode::master(Info::ID, vec![]),
+            seek_head(&[Info::ID]),
+        ]);
+        let at = first_position(&data);
+        data[at..at + 8].copy_from_slice(&out_of_range);
+        assert!(matches!(
+            SegmentReader::new(&data),
+            Err(EbmlError::InvalidElement(_))
+        ));
+
+        // Only listed in the SeekHead, behind the first Cluster
+        let mut data = file(vec![
+            seek_head(&[Info::ID]),
+            EbmlNode::master(Cluster::ID, vec![EbmlNode::unsigned(0xE7, 0)]),
+            EbmlNode::master(Info::ID, vec![]),
+        ]);
+        let at = first_position(&data);
+        data[at..at + 8].copy_from_slice(&out_of_range);
+        let mut reader = SegmentReader::new(&data).unwrap();
+        assert!(reader.position(Info::ID).is_none());
+        assert!(reader.seek_to(Info::ID).is_err());
+    }
+}
@@ -0,0 +1,353 @@
+use super::segment::write_in_place;
+use super::{
+    SegmentReader, SimpleTag, Tag, TagAttachmentUID, TagBinary, TagChapterUID, TagDefault,
+    TagEditionUID, TagLanguage, TagLanguageBCP47, TagName, TagString, TagTrackUID, Tags,
+    TargetType, TargetTypeValue, Targets,
+};
+use crate::{EbmlError, EbmlNode};
+
+/// The `Tags` of a Segment
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TagList {
+    pub groups: Vec<TagGroup>,
+}
+
+/// A `Tag`: the fields and what they describe
+#[derive(Debug, Clone, PartialEq)]
+pub struct TagGroup {
+    /// The level of the target, 50 for a whole album or movie
+    pub target_type_value: u64,
+    pub target_type: Option<String>,
+    pub track_uids: Vec<u64>,
+    pub edition_uids: Vec<u64>,
+    pub chapter_uids: Vec<u64>,
+    pub attachment_uids: Vec<u64>,
+    pub fields: Vec<TagField>,
+}
+
+/// A `SimpleTag`, which may hold further tags about its value
+#[derive(Debug, Clone, PartialEq)]
+pub struct TagField {
+    pub name: String,
+    pub language: String,
+    pub language_bcp47: Option<String>,
+    pub default: bool,
+    pub string: Option<String>,
+    pub binary: Option<Vec<u8>>,
+    pub children: Vec<TagField>,
+}
+
+impl TagList {
+    /// Reads a `Tags` node
+    pub fn from_node(node: &EbmlNode) -> Result<Self, EbmlError> {
+        Self::from_tags(Tags::from_node(node)?)
+    }
+
+    pub fn from_tags(tags: Tags) -> Result<Self, EbmlError> {
+        let groups = tags.tag.into_iter().map(TagGroup::from_tag);
+        Ok(Self {
+            groups: groups.collect::<Result<_, _>>()?,
+        })
+    }
+
+    pub fn to_tags(&self) -> Tags {
+        Tags {
+            tag: self.groups.iter().map(TagGroup::to_tag).collect(),
+            ..Default::default()
+        }
+    }
+
+    pub fn to_node(&self) -> EbmlNode<'static> {
+        self.to_tags().to_node()
+    }
+
+    /// The value of a field describing the whole Segment
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.groups
+            .iter()
+            .filter(|g| g.is_global())
+            .flat_map(|g| &g.fields)
+            .find(|f| f.name == name)
+            .and_then(|f| f.string.as_deref())
+    }
+
+    /// Sets a field describing the whole Segment, adding a global `Tag` when needed
+    pub fn set(&mut self, name: &str, value: &str) {
+        let existing = self
+            .groups
+            .iter_mut()
+            .filter(|g| g.is_global())
+            .flat_map(|g| &mut g.fields)
+            .find(|f| f.name == name);
+        if let Some(field) = existing {
+            field.string = Some(value.to_string());
+            field.binary = None;
+            return;
+        }
+
+        let field = TagField::new(name, value);
+        match self.groups.iter_mut().find(|g| g.is_global()) {
+            Some(group) => group.fields.push(field),
+            None => self.groups.push(TagGroup {
+                fields: vec![field],
+                ..TagGroup::new(TargetTypeValue::DEFAULT)
+            }),
+        }
+    }
+
+    /// Removes every field describing the whole Segment with the given name
+    pub fn remove(&mut self, name: &str) {
+        for group in self.groups.iter_mut().filter(|g| g.is_global()) {
+            group.fields.retain(|f| f.name != name);
+        }
+    }
+
+    pub fn title(&self) -> Option<&str> {
+        self.get("TITLE")
+    }
+
+    pub fn set_title(&mut self, title: &str) {
+        self.set("TITLE", title)
+    }
+
+    pub fn artist(&self) -> Option<&str> {
+        self.get("ARTIST")
+    }
+
+    pub fn set_artist(&mut self, artist: &str) {
+        self.set("ARTIST", artist)
+    }
+
+    /// Writes the tags over the Segment's `Tags` without moving any other data, using
+    /// the `Void` after them to grow. A Segment without Tags gets them in the first top
+    /// level `Void` large enough, along with an entry in its `SeekHead`.
+    pub fn write_in_place(&self, data: &mut [u8]) -> Result<(), EbmlError> {
+        write_in_place(data, &self.to_node())
+    }
+}
+
+impl TagGroup {
+    /// An empty group targeting the whole Segment at the given level
+    pub fn new(target_type_value: u64) -> Self {
+        Self {
+            target_type_value,
+            target_type: None,
+            track_uids: Vec::new(),
+            edition_uids: Vec::new(),
+            chapter_uids: Vec::new(),
+            attachment_uids: Vec::new(),
+            fields: Vec::new(),
+        }
+    }
+
+    /// Whether the group describes the whole Segment at the album or movie level
+    pub fn is_global(&self) -> bool {
+        let none = |uids: &[u64]| uids.iter().all(|uid| *uid == 0);
+        self.target_type_value == TargetTypeValue::DEFAULT
+            && none(&self.track_uids)
+            && none(&self.edition_uids)
+            && none(&self.chapter_uids)
+            && none(&self.attachment_uids)
+    }
+
+    pub fn from_tag(tag: Tag) -> Result<Self, EbmlError> {
+        let targets = tag.targets.unwrap_or_default();
+        let fields = tag.simple_tag.into_iter().map(TagField::from_simple_tag);
+        Ok(Self {
+            target_type_value: targets
+                .target_type_value
+                .map_or(TargetTypeValue::DEFAULT, |t| t.value),
+            target_type: targets.target_type.map(|t| t.value),
+            track_uids: targets.tag_track_uid.iter().map(|u| u.value).collect(),
+            edition_uids: targets.tag_edition_uid.iter().map(|u| u.value).collect(),
+            chapter_uids: targets.tag_chapter_uid.iter().map(|u| u.value).collect(),
+            attachment_uids: targets.tag_attachment_uid.iter().map(|u| u.value).collect(),
+            fields: fields.collect::<Result<_, _>>()?,
+        })
+    }
+
+    pub fn to_tag(&self) -> Tag {
+        let targets = Targets {
+            target_type_value: Some(TargetTypeValue::new(self.target_type_value)),
+            target_type: self.target_type.as_deref().map(TargetType::new),
+            tag_track_uid: self
+                .track_uids
+                .iter()
+                .map(|u| TagTrackUID::new(*u))
+                .collect(),
+            tag_edition_uid: self
+                .edition_uids
+                .iter()
+                .map(|u| TagEditionUID::new(*u))
+                .collect(),
+            tag_chapter_uid: self
+                .chapter_uids
+                .iter()
+                .map(|u| TagChapterUID::new(*u))
+                .collect(),
+            tag_attachment_uid: self
+                .attachment_uids
+                .iter()
+                .map(|u| TagAttachmentUID::new(*u))
+                .collect(),
+            ..Default::default()
+        };
+        Tag {
+            targets: Some(targets),
+            simple_tag: self.fields.iter().map(TagField::to_simple_tag).collect(),
+            ..Default::default()
+        }
+    }
+}
+
+impl TagField {
+    /// A string field in the undetermined language
+    pub fn new(name: &str, value: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            language: TagLanguage::DEFAULT.to_string(),
+            language_bcp47: None,
+            default: true,
+            string: Some(value.to_string()),
+            binary: None,
+            children: Vec::new(),
+        }
+    }
+
+    pub fn from_simple_tag(tag: SimpleTag) -> Result<Self, EbmlError> {
+        let children = tag.simple_tag.into_iter().map(Self::from_simple_tag);
+        Ok(Self {
+            name: tag
+                .tag_name
+                .ok_or(EbmlError::MissingElement(TagName::NAME))?
+                .value,
+            language: tag
+                .tag_language
+                .map_or(TagLanguage::DEFAULT.to_string(), |l| l.value),
+            language_bcp47: tag.tag_language_bcp47.map(|l| l.value),
+            default: tag.tag_default.map_or(TagDefault::DEFAULT, |d| d.value) != 0,
+            string: tag.tag_string.map(|s| s.value),
+            binary: tag.tag_binary.map(|b| b.value),
+            children: children.collect::<Result<_, _>>()?,
+        })
+    }
+
+    pub fn to_simple_tag(&self) -> SimpleTag {
+        SimpleTag {
+            simple_tag: self.children.iter().map(Self::to_simple_tag).collect(),
+            tag_name: Some(TagName::new(self.name.as_str())),
+            tag_language: Some(TagLanguage::new(self.language.as_str())),
+            tag_language_bcp47: self.language_bcp47.as_deref().map(TagLanguageBCP47::new),
+            tag_default: Some(TagDefault::new(self.default as u64)),
+            tag_string: self.string.as_deref().map(TagString::new),
+            tag_binary: self.binary.clone().map(TagBinary::new),
+            ..Default::default()
+        }
+    }
+}
+
+impl<'a> SegmentReader<'a> {
+    /// Reads the `Tags` of the Segment
+    pub fn tags(&mut self) -> Result<Option<TagList>, EbmlError> {
+        match self.seek_to(Tags::ID)? {
+            Some(tags) => TagList::from_node(&tags).map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matroska::segment::tests::{file, seek_head};
+    use crate::matroska::{Info, SeekHead, Tracks};
+    use crate::Void;
+
+    fn sample() -> TagList {
+        let mut track = TagGroup::new(30);
+        track.track_uids.push(12);
+        let mut field = TagField::new("TITLE", "Track title");
+        field.children.push(TagField::new("SORT_WITH", "title"));
+        track.fields.push(field);
+
+        let mut tags = TagList {
+            groups: vec![track],
+        };
+        tags.set_title("Album");
+        tags.set("CATALOG_NUMBER", "CAT-1");
+        tags
+    }
+
+    #[test]
+    fn test_model() {
+        let mut tags = sample();
+        assert_eq!(tags.groups.len(), 2);
+        assert_eq!(tags.title(), Some("Album"));
+        assert_eq!(tags.artist(), None);
+        tags.set_artist("Someone");
+        tags.set_title("Other album");
+        assert_eq!(tags.groups[1].fields.len(), 3);
+        tags.remove("CATALOG_NUMBER");
+        assert_eq!(tags.get("CATALOG_NUMBER"), None);
+
+        let read = TagList::from_node(&tags.to_node()).unwrap();
+        assert_eq!(read, tags);
+        assert_eq!(
+            read.groups[0].fields[0].children[0].string.as_deref(),
+            Some("title")
+        );
+    }
+
+    #[test]
+    fn test_write_in_place() {
+        let tags = sample();
+        let mut data = file(vec![
+            seek_head(&[Tags::ID]),
+            EbmlNode::binary(Void::ID, vec![0; 20]),
+            tags.to_node(),
+            EbmlNode::binary(Void::ID, vec![0; 40]),
+            EbmlNode::master(Tracks::ID, vec![]),
+        ]);
+        let length = data.len();
+
+        let mut edited = SegmentReader::new(&data).unwrap().tags().unwrap().unwrap();
+        edited.set_artist("Someone");
+        edited.write_in_place(&mut data).unwrap();
+        assert_eq!(data.len(), length);
+        let mut reader = SegmentReader::new(&data).unwrap();
+        assert_eq!(reader.tags().unwrap().unwrap(), edited);
+        assert!(reader.seek_to(Tracks::ID).unwrap().is_some());
+
+        edited.set("COMMENT", &"x".repeat(100));
+        assert!(edited.write_in_place(&mut data).is_err());
+    }
+
+    #[test]
+    fn test_add_in_place() {
+        let mut data = file(vec![
+            seek_head(&[Info::ID]),
+            EbmlNode::binary(Void::ID, vec![0; 40]),
+            EbmlNode::master(Info::ID, vec![]),
+            EbmlNode::binary(Void::ID, vec![0; 150]),
+        ]);
+        let mut tags = TagList::default();
+        tags.set_title("Added");
+        tags.write_in_place(&mut data).unwrap();
+
+        let mut reader = SegmentReader::new(&data).unwrap();
+        assert_eq!(reader.tags().unwrap().unwrap(), tags);
+        assert!(reader.seek_to(Info::ID).unwrap().is_some());
+        let head = reader.seek_to(SeekHead::ID).unwrap().unwrap();
+        assert_eq!(head.children().len(), 2);
+
+        // Without room for the SeekHead to grow the Tags are left out
+        let mut data = file(vec![
+            seek_head(&[Info::ID]),
+            EbmlNode::master(Info::ID, vec![]),
+            EbmlNode::binary(Void::ID, vec![0; 150]),
+        ]);
+        let original = data.clone();
+        assert!(tags.write_in_place(&mut data).is_err());
+        assert_eq!(data, original);
+    }
+}
@@ -0,0 +1,308 @@
+use super::{
+    Audio, Channels, CodecID, DisplayUnit, FlagDefault, FlagEnabled, FlagForced, FlagInterlaced,
+    FlagLacing, Language, PixelHeight, PixelWidth, SamplingFrequency, SegmentReader, TrackEntry,
+    TrackNumber, TrackType, TrackUID, Tracks, Video,
+};
+use crate::{EbmlError, EbmlNode};
+
+/// The kind of data a track holds, from `TrackType`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrackKind {
+    Video,
+    Audio,
+    Complex,
+    Logo,
+    Subtitle,
+    Buttons,
+    Control,
+    Metadata,
+    Other(u64),
+}
+
+impl From<u64> for TrackKind {
+    fn from(value: u64) -> Self {
+        match value {
+            1 => TrackKind::Video,
+            2 => TrackKind::Audio,
+            3 => TrackKind::Complex,
+            0x10 => TrackKind::Logo,
+            0x11 => TrackKind::Subtitle,
+            0x12 => TrackKind::Buttons,
+            0x20 => TrackKind::Control,
+            0x21 => TrackKind::Metadata,
+            value => TrackKind::Other(value),
+        }
+    }
+}
+
+/// A `TrackEntry` with the schema defaults filled in
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrackInfo {
+    pub number: u64,
+    pub uid: u64,
+    pub kind: TrackKind,
+    pub codec_id: String,
+    pub codec_private: Option<Vec<u8>>,
+    pub codec_name: Option<String>,
+    pub name: Option<String>,
+    /// `LanguageBCP47` when present, the `Language` code otherwise
+    pub language: String,
+    pub enabled: bool,
+    pub default: bool,
+    pub forced: bool,
+    pub lacing: bool,
+    /// Nanoseconds per frame
+    pub default_duration: Option<u64>,
+    pub video: Option<VideoInfo>,
+    pub audio: Option<AudioInfo>,
+}
+
+/// The `Video` settings of a track
+#[derive(Debug, Clone, PartialEq)]
+pub struct VideoInfo {
+    pub pixel_width: u64,
+    pub pixel_height: u64,
+    /// Defaults to the cropped pixel size when the display unit is pixels
+    pub display_width: Option<u64>,
+    pub display_height: Option<u64>,
+    pub display_unit: u64,
+    pub interlaced: u64,
+    pub frame_rate: Option<f64>,
+}
+
+/// The `Audio` settings of a track
+#[derive(Debug, Clone, PartialEq)]
+pub struct AudioInfo {
+    pub sampling_frequency: f64,
+    /// Defaults to the sampling frequency
+    pub output_sampling_frequency: f64,
+    pub channels: u64,
+    pub bit_depth: Option<u64>,
+}
+
+impl TrackInfo {
+    /// Reads a `TrackEntry` node
+    pub fn from_node(node: &EbmlNode) -> Result<Self, EbmlError> {
+        Self::from_entry(TrackEntry::from_node(node)?)
+    }
+
+    /// Takes the values out of a generated `TrackEntry`, failing when a mandatory
+    /// element without a default is missing
+    pub fn from_entry(entry: TrackEntry) -> Result<Self, EbmlError> {
+        let number = entry
+            .track_number
+            .ok_or(EbmlError::MissingElement(TrackNumber::NAME))?;
+        let uid = entry
+            .track_uid
+            .ok_or(EbmlError::MissingElement(TrackUID::NAME))?;
+        let kind = entry
+            .track_type
+            .ok_or(EbmlError::MissingElement(TrackType::NAME))?;
+        let codec_id = entry
+            .codec_id
+            .ok_or(EbmlError::MissingElement(CodecID::NAME))?;
+        let language = match (entry.language_bcp47, entry.language) {
+            (Some(bcp47), _) => bcp47.value,
+            (None, Some(language)) => language.value,
+            (None, None) => Language::DEFAULT.to_string(),
+        };
+        let flag = |flag: Option<u64>, default: u64| flag.unwrap_or(default) != 0;
+
+        Ok(Self {
+            number: number.value,
+            uid: uid.value,
+            kind: kind.value.into(),
+            codec_id: codec_id.value,
+            codec_private: entry.codec_private.map(|c| c.value),
+            codec_name: entry.codec_name.map(|c| c.value),
+            name: entry.name.map(|n| n.value),
+            language,
+            enabled: flag(entry.flag_enabled.map(|f| f.value), FlagEnabled::DEFAULT),
+            default: flag(entry.flag_default.map(|f| f.value), FlagDefault::DEFAULT),
+            forced: flag(entry.flag_forced.map(|f| f.value), FlagForced::DEFAULT),
+            lacing: flag(entry.flag_lacing.map(|f| f.value), FlagLacing::DEFAULT),
+            default_duration: entry.default_duration.map(|d| d.value),
+            video: entry.video.map(VideoInfo::from_video).transpose()?,
+            audio: entry.audio.map(AudioInfo::from_audio),
+        })
+    }
+
+    /// Reads every `TrackEntry` of a `Tracks` node
+    pub fn from_tracks(node: &EbmlNode) -> Result<Vec<Self>, EbmlError> {
+        Tracks::from_node(node)?
+            .track_entry
+            .into_iter()
+            .map(Self::from_entry)
+            .collect()
+    }
+}
+
+impl VideoInfo {
+    pub fn from_video(video: Video) -> Result<Self, EbmlError> {
+        let pixel_width = video
+            .pixel_width
+            .ok_or(EbmlError::MissingElement(PixelWidth::NAME))?
+            .value;
+        let pixel_height = video
+            .pixel_height
+            .ok_or(EbmlError::MissingElement(PixelHeight::NAME))?
+            .value;
+        let display_unit = video.display_unit.map_or(DisplayUnit::DEFAULT, |u| u.value);
+        let crop = |a: Option<u64>, b: Option<u64>| a.unwrap_or(0).saturating_add(b.unwrap_or(0));
+        // Only a size in pixels has a default
+        let pixels = display_unit == 0;
+        let display_width = video.display_width.map(|w| w.value).or(pixels.then(|| {
+            let crop = crop(
+                video.pixel_crop_left.map(|c| c.value),
+                video.pixel_crop_right.map(|c| c.value),
+            );
+            pixel_width.saturating_sub(crop)
+        }));
+        let display_height = video.display_height.map(|h| h.value).or(pixels.then(|| {
+            let crop = crop(
+                video.pixel_crop_top.map(|c| c.value),
+                video.pixel_crop_bottom.map(|c| c.value),
+            );
+            pixel_height.saturating_sub(crop)
+        }));
+
+        Ok(Self {
+            pixel_width,
+            pixel_height,
+            display_width,
+            display_height,
+            display_unit,
+            interlaced: video
+                .flag_interlaced
+                .map_or(FlagInterlaced::DEFAULT, |f| f.value),
+            frame_rate: video.frame_rate.map(|f| f.value),
+        })
+    }
+}
+
+impl AudioInfo {
+    pub fn from_audio(audio: Audio) -> Self {
+        let sampling_frequency = audio
+            .sampling_frequency
+            .map_or(SamplingFrequency::DEFAULT, |f| f.value);
+        Self {
+            sampling_frequency,
+            output_sampling_frequency: audio
+                .output_sampling_frequency
+                .map_or(sampling_frequency, |f| f.value),
+            channels: audio.channels.map_or(Channels::DEFAULT, |c| c.value),
+            bit_depth: audio.bit_depth.map(|b| b.value),
+        }
+    }
+}
+
+impl<'a> SegmentReader<'a> {
+    /// Reads the tracks of the Segment, empty when it has no `Tracks`
+    pub fn tracks(&mut self) -> Result<Vec<TrackInfo>, EbmlError> {
+        match self.seek_to(Tracks::ID)? {
+            Some(tracks) => TrackInfo::from_tracks(&tracks),
+            None => Ok(Vec::new()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matroska::segment::tests::file;
+    use crate::matroska::{
+        CodecPrivate, DisplayHeight, LanguageBCP47, OutputSamplingFrequency, PixelCropBottom,
+        PixelCropLeft, PixelCropTop,
+    };
+
+    fn entry(number: u64, kind: u64, codec: &str) -> TrackEntry {
+        TrackEntry {
+            track_number: Some(TrackNumber::new(number)),
+            track_uid: Some(TrackUID::new(number * 100)),
+            track_type: Some(TrackType::new(kind)),
+            codec_id: Some(CodecID::new(codec)),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_tracks() {
+        let video = TrackEntry {
+            codec_private: Some(CodecPrivate::new(vec![1, 2])),
+            flag_default: Some(FlagDefault::new(0)),
+            video: Some(Video {
+                pixel_width: Some(PixelWidth::new(1920)),
+                pixel_height: Some(PixelHeight::new(1080)),
+                pixel_crop_left: Some(PixelCropLeft::new(20)),
+                display_height: Some(DisplayHeight::new(1000)),
+                ..Default::default()
+            }),
+            ..entry(1, 1, "V_VP9")
+        };
+        let audio = TrackEntry {
+            language: Some(Language::new("ger")),
+            language_bcp47: Some(LanguageBCP47::new("de-CH")),
+            audio: Some(Audio {
+                sampling_frequency: Some(SamplingFrequency::new(24000.0)),
+                output_sampling_frequency: Some(OutputSamplingFrequency::new(48000.0)),
+                ..Default::default()
+            }),
+            ..entry(2, 2, "A_OPUS")
+        };
+        let tracks = Tracks {
+            track_entry: vec![video, audio, entry(3, 0x11, "S_TEXT/UTF8")],
+            ..Default::default()
+        };
+        let data = file(vec![tracks.to_node()]);
+        let tracks = SegmentReader::new(&data).unwrap().tracks().unwrap();
+        assert_eq!(tracks.len(), 3);
+
+        let video = &tracks[0];
+        assert_eq!(video.kind, TrackKind::Video);
+        assert_eq!(video.uid, 100);
+        assert_eq!(video.codec_private.as_deref(), Some(&[1, 2][..]));
+        assert_eq!(video.language, "eng");
+        assert!(video.enabled && !video.default && !video.forced && video.lacing);
+        let settings = video.video.as_ref().unwrap();
+        assert_eq!(settings.display_width, Some(1900));
+        assert_eq!(settings.display_height, Some(1000));
+        assert!(video.audio.is_none());
+
+        let audio = &tracks[1];
+        assert_eq!(audio.kind, TrackKind::Audio);
+        assert_eq!(audio.codec_id, "A_OPUS");
+        assert_eq!(audio.language, "de-CH");
+        let settings = audio.audio.as_ref().unwrap();
+        assert_eq!(settings.output_sampling_frequency, 48000.0);
+        assert_eq!(settings.channels, 1);
+
+        assert_eq!(tracks[2].kind, TrackKind::Subtitle);
+    }
+
+    #[test]
+    fn test_missing() {
+        let entry = TrackEntry {
+            codec_id: None,
+            ..entry(1, 1, "V_AV1")
+        };
+        let error = TrackInfo::from_node(&entry.to_node()).unwrap_err();
+        assert!(matches!(error, EbmlError::MissingElement("CodecID")));
+        let video = Video {
+            pixel_width: Some(PixelWidth::new(640)),
+            ..Default::default()
+        };
+        assert!(VideoInfo::from_video(video).is_err());
+
+        // Crops larger than the picture leave nothing to display
+        let video = Video {
+            pixel_width: Some(PixelWidth::new(640)),
+            pixel_height: Some(PixelHeight::new(480)),
+            pixel_crop_top: Some(PixelCropTop::new(u64::MAX)),
+            pixel_crop_bottom: Some(PixelCropBottom::new(u64::MAX)),
+            ..Default::default()
+        };
+        let video = VideoInfo::from_video(video).unwrap();
+        assert_eq!(video.display_height, Some(0));
+        assert_eq!(video.display_width, Some(640));
+    }
+}
@@ -0,0 +1,427 @@
+use super::{
+    AttachedFile, Attachments, BlockGroup, BlockView, Cluster, CueEntry, CueIndex, Cues, SeekEntry,
+    SeekHead, Segment, Timestamp, TimestampScale,
+};
+use crate::inplace::write_void;
+use crate::{
+    write_element_id, DocType, DocTypeReadVersion, DocTypeVersion, Ebml, EbmlError, EbmlNode,
+    InPlaceEditor, VarInt, VarIntLength,
+};
+use std::io::{SeekFrom, Write};
+
+/// Room kept at the start of the Segment for the SeekHead written when finishing,
+/// enough for at least eleven entries
+const SEEK_HEAD_SPACE: u64 = 256;
+
+/// Writes a Matroska Segment, one top level element or block at a time.
+///
+/// Clusters and the Segment are written with eight octet sizes that are filled in once
+/// they are complete. A cue is kept for every `SimpleBlock` keyframe, [`Self::finish`]
+/// writes them as `Cues` along with a `SeekHead` pointing at every top level element.
+#[derive(Debug)]
+pub struct SegmentWriter<W: Write + std::io::Seek> {
+    writer: W,
+    data_start: u64,
+    /// Relative positions of the top level elements written so far
+    entries: Vec<SeekEntry>,
+    /// Relative position and size of the space reserved for Cues
+    cue_space: Option<(u64, u64)>,
+    cues: CueIndex,
+    cluster: Option<OpenCluster>,
+    attachments: Vec<AttachedFile>,
+}
+
+#[derive(Debug)]
+struct OpenCluster {
+    /// Relative to the Segment's data
+    position: u64,
+    /// Absolute position of the Cluster's data
+    data_start: u64,
+    timestamp: u64,
+    blocks: u64,
+}
+
+impl<W: Write + std::io::Seek> SegmentWriter<W> {
+    /// Writes the EBML header for `doc_type` and starts the Segment
+    pub fn new(mut writer: W, doc_type: &str) -> Result<Self, EbmlError> {
+        let header = EbmlNode::master(
+            Ebml::ID,
+            vec![
+                EbmlNode::string(DocType::ID, doc_type),
+                EbmlNode::unsigned(DocTypeVersion::ID, 4),
+                EbmlNode::unsigned(DocTypeReadVersion::ID, 2),
+            ],
+        );
+        header.write(&mut writer)?;
+        write_element_id(Segment::ID, &mut writer)?;
+        VarInt::unknown(VarIntLength::Eight).write(&mut writer)?;
+        let data_start = writer.stream_position()?;
+
+        let mut void = Vec::new();
+        write_void(SEEK_HEAD_SPACE, &mut void)?;
+        writer.write_all(&void)?;
+        Ok(Self {
+            writer,
+            data_start,
+            entries: Vec::new(),
+            cue_space: None,
+            cues: CueIndex::new(TimestampScale::DEFAULT),
+            cluster: None,
+            attachments: Vec::new(),
+        })
+    }
+
+    /// Position of the Segment's data, which seek and cue positions are relative to
+    pub fn data_start(&self) -> u64 {
+        self.data_start
+    }
+
+    /// The cues recorded so far
+    pub fn cues(&self) -> &CueIndex {
+        &self.cues
+    }
+
+    /// Writes a top level element such as `Info` or `Tracks`, closing the current Cluster.
+    ///
+    /// Fails without writing anything when the `SeekHead` would have no room left for
+    /// the element along with the `Cues` and `Attachments` written when finishing.
+    pub fn write_element(&mut self, node: &EbmlNode) -> Result<(), EbmlError> {
+        if node.id != Cluster::ID {
+            self.check_seek_head_space(&[node.id, Cues::ID, Attachments::ID])?;
+        }
+        self.write_top_level(node)
+    }
+
+    fn write_top_level(&mut self, node: &EbmlNode) -> Result<(), EbmlError> {
+        self.close_cluster()?;
+        let position = self.position()?;
+        node.write(&mut self.writer)?;
+        if node.id != Cluster::ID {
+            self.entries.push(SeekEntry {
+                id: node.id,
+                position,
+            });
+        }
+        Ok(())
+    }
+
+    /// Keeps `size` octets as a `Void` for the Cues, so that players can find them
+    /// without reading to the end of the file. The Cues are written at the end instead
+    /// when they don't fit.
+    pub fn reserve_cues(&mut self, size: u64) -> Result<(), EbmlError> {
+        self.close_cluster()?;
+        let position = self.position()?;
+        let mut void = Vec::new();
+        write_void(size, &mut void)?;
+        self.writer.write_all(&void)?;
+        self.cue_space = Some((position, size));
+        Ok(())
+    }
+
+    /// Closes the current Cluster and starts one at `timestamp`, in TimestampScale units
+    pub fn start_cluster(&mut self, timestamp: u64) -> Result<(), EbmlError> {
+        self.close_cluster()?;
+        let position = self.position()?;
+        write_element_id(Cluster::ID, &mut self.writer)?;
+        VarInt::unknown(VarIntLength::Eight).write(&mut self.writer)?;
+        let data_start = self.writer.stream_position()?;
+        EbmlNode::unsigned(Timestamp::ID, timestamp).write(&mut self.writer)?;
+        self.cluster = Some(OpenCluster {
+            position,
+            data_start,
+            timestamp,
+            blocks: 0,
+        });
+        Ok(())
+    }
+
+    /// Writes a block at `timestamp` (in TimestampScale units), replacing its relative
+    /// timestamp. A Cluster is started when there is none or the timestamp is out of
+    /// its reach. `Block`s are wrapped in a `BlockGroup`.
+    pub fn write_block(&mut self, timestamp: u64, block: &BlockView) -> Result<(), EbmlError> {
+        let relative = self
+            .cluster
+            .as_ref()
+            .and_then(|c| timestamp.checked_sub(c.timestamp))
+            .and_then(|t| i16::try_from(t).ok());
+        let relative = match relative {
+            Some(relative) => relative,
+            None => {
+                self.start_cluster(timestamp)?;
+                0
+            }
+        };
+        let block = BlockView {
+            timestamp: relative,
+            ..block.clone()
+        };
+        let mut node = block.to_node()?;
+        if !block.simple {
+            node = EbmlNode::master(BlockGroup::ID, vec![node]);
+        }
+
+        let position = self.writer.stream_position()?;
+        node.write(&mut self.writer)?;
+        let cluster = self.cluster.as_mut().expect("A Cluster was started");
+        cluster.blocks += 1;
+        if block.simple && block.keyframe {
+            self.cues.push(CueEntry {
+                time: timestamp,
+                track: block.track_number,
+                cluster_position: cluster.position,
+                relative_position: Some(position - cluster.data_start),
+                block_number: Some(cluster.blocks),
+                duration: None,
+            });
+        }
+        Ok(())
+    }
+
+    /// Adds a file to the `Attachments` written when finishing
+    pub fn add_attachment(&mut self, file: AttachedFile) {
+        self.attachments.push(file);
+    }
+
+    /// Removes the files with the given UID added so far, returning whether there were any
+    pub fn remove_attachment(&mut self, uid: u64) -> bool {
+        let count = self.attachments.len();
+        self.attachments
+            .retain(|f| f.file_uid.as_ref().map(|u| u.value) != Some(uid));
+        self.attachments.len() != count
+    }
+
+    /// Writes the Attachments, the Cues and the SeekHead and fills in the Segment's size
+    pub fn finish(mut self) -> Result<W, EbmlError> {
+        self.close_cluster()?;
+        if !self.attachments.is_empty() {
+            let attachments = Attachments {
+                attached_file: std::mem::take(&mut self.attachments),
+                ..Default::default()
+            };
+            self.write_top_level(&attachments.to_node())?;
+        }
+        if !self.cues.entries().is_empty() {
+            let cues = self.cues.to_node();
+            let reserved = match self.cue_space {
+                Some((position, size)) => self.write_into(position, size, &cues).is_ok(),
+                None => false,
+            };
+            if !reserved {
+                self.write_top_level(&cues)?;
+            } else if let Some((position, _)) = self.cue_space {
+                self.entries.push(SeekEntry {
+                    id: Cues::ID,
+                    position,
+                });
+            }
+        }
+
+        let seek_head = SeekHead {
+            seek: self.entries.iter().map(SeekEntry::to_seek).collect(),
+            ..Default::default()
+        };
+        self.write_into(0, SEEK_HEAD_SPACE, &seek_head.to_node())?;
+
+        let end = self.writer.seek(SeekFrom::End(0))?;
+        self.writer.seek(SeekFrom::Start(self.data_start - 8))?;
+        VarInt::with_length(end - self.data_start, VarIntLength::Eight)?.write(&mut self.writer)?;
+        self.writer.seek(SeekFrom::Start(end))?;
+        Ok(self.writer)
+    }
+
+    /// Position of the next element relative to the Segment's data
+    fn position(&mut self) -> Result<u64, EbmlError> {
+        Ok(self.writer.stream_position()? - self.data_start)
+    }
+
+    fn close_cluster(&mut self) -> Result<(), EbmlError> {
+        let cluster = match self.cluster.take() {
+            Some(cluster) => cluster,
+            None => return Ok(()),
+        };
+        let end = self.writer.stream_position()?;
+        let size = VarInt::with_length(end - cluster.data_start, VarIntLength::Eight)?;
+        self.writer.seek(SeekFrom::Start(cluster.data_start - 8))?;
+        size.write(&mut self.writer)?;
+        self.writer.seek(SeekFrom::Start(end))?;
+        Ok(())
+    }
+
+    /// Fails when the SeekHead with entries for `ids` added wouldn't fit in its space
+    fn check_seek_head_space(&self, ids: &[u64]) -> Result<(), EbmlError> {
+        // The largest positions give the largest entries
+        let added = ids.iter().map(|id| SeekEntry {
+            id: *id,
+            position: u64::MAX,
+        });
+        let seek_head = SeekHead {
+            seek: self
+                .entries
+                .iter()
+                .copied()
+                .chain(added)
+                .map(|entry| entry.to_seek())
+                .collect(),
+            ..Default::default()
+        };
+        let size = seek_head.to_node().encoded_size();
+        if size > SEEK_HEAD_SPACE {
+            return Err(EbmlError::NotEnoughSpace(size, SEEK_HEAD_SPACE));
+        }
+        Ok(())
+    }
+
+    /// Overwrites `size` reserved octets at a relative position with `node` and a `Void`,
+    /// the way [`InPlaceEditor`] fills a `Void`
+    fn write_into(&mut self, position: u64, size: u64, node: &EbmlNode) -> Result<(), EbmlError> {
+        let mut out = Vec::new();
+        write_void(size, &mut out)?;
+        let mut editor = InPlaceEditor::new(&mut out);
+        let span = editor.element_at(0, size)?;
+        editor.replace_element(&span, node)?;
+        let end = self.writer.stream_position()?;
+        self.writer
+            .seek(SeekFrom::Start(self.data_start + position))?;
+        self.writer.write_all(&out)?;
+        self.writer.seek(SeekFrom::Start(end))?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matroska::{
+        FileData, FileMediaType, FileName, FileUID, Info, SegmentReader, SimpleBlock,
+    };
+    use std::io::Cursor;
+
+    fn write(reserve: Option<u64>) -> Vec<u8> {
+        let mut writer = SegmentWriter::new(Cursor::new(Vec::new()), "webm").unwrap();
+        writer.write_element(&Info::default().to_node()).unwrap();
+        for uid in [1, 2] {
+            writer.add_attachment(AttachedFile {
+                file_name: Some(FileName::new("cover.png")),
+                file_media_type: Some(FileMediaType::new("image/png")),
+                file_data: Some(FileData::new(vec![0x89; 4])),
+                file_uid: Some(FileUID::new(uid)),
+                ..Default::default()
+            });
+        }
+        assert!(writer.remove_attachment(1));
+        if let Some(size) = reserve {
+            writer.reserve_cues(size).unwrap();
+        }
+        for (time, track, keyframe) in
+            [(0, 1, true), (0, 2, true), (40, 1, false), (40000, 1, true)]
+        {
+            let mut block = BlockView::new(true, track, 0, &[1, 2, 3]);
+            block.keyframe = keyframe;
+            writer.write_block(time, &block).unwrap();
+        }
+        assert_eq!(writer.cues().entries().len(), 3);
+        writer.finish().unwrap().into_inner()
+    }
+
+    fn check(data: &[u8]) -> SegmentReader<'_> {
+        let mut reader = SegmentReader::new(data).unwrap();
+        assert!(reader.seek_to(Info::ID).unwrap().is_some());
+        let attachments = reader.attachments().unwrap();
+        assert_eq!(attachments.len(), 1);
+        assert_eq!(attachments[0].uid, 2);
+        let cues = reader.cues().unwrap().unwrap();
+        assert_eq!(cues.entries().len(), 3);
+        assert_eq!(cues.entries()[0].block_number, Some(1));
+        assert_eq!(cues.entries()[1].block_number, Some(2));
+
+        // 40000 is out of reach of the first Cluster's relative timestamps
+        let target = reader.seek_timestamp(1, 40_000_000_000).unwrap().unwrap();
+        assert_ne!(
+            target.cluster,
+            reader.data_start() + cues.entries()[0].cluster_position
+        );
+        let block = target.block.unwrap() as usize;
+        assert_eq!(data[block], SimpleBlock::ID as u8);
+        let view = BlockView::parse(&data[block + 2..block + 9], true).unwrap();
+        assert!(view.keyframe && view.timestamp == 0);
+        reader
+    }
+
+    /// Positions of the Cues and of the first Cluster
+    fn layout(mut reader: SegmentReader) -> (u64, u64) {
+        let cluster = reader.cues().unwrap().unwrap().entries()[0].cluster_position;
+        (
+            reader.position(Cues::ID).unwrap(),
+            reader.data_start() + cluster,
+        )
+    }
+
+    #[test]
+    fn test_cues_at_end() {
+        let data = write(None);
+        let (cues, cluster) = layout(check(&data));
+        assert_eq!(
+            data[cues as usize..cues as usize + 4],
+            Cues::ID.to_be_bytes()[4..]
+        );
+        assert!(cues > cluster);
+    }
+
+    #[test]
+    fn test_reserved_cues() {
+        let data = write(Some(100));
+        let reader = check(&data);
+        let info = reader.position(Info::ID).unwrap();
+        let (cues, cluster) = layout(reader);
+        // The reserved space follows Info, ahead of the Clusters
+        assert!(info < cues && cues < cluster);
+
+        // Not enough room, the Void stays and the Cues go to the end
+        let data = write(Some(10));
+        let (cues, cluster) = layout(check(&data));
+        assert!(cues > cluster);
+
+        // A single octet left over is taken up by a wider size
+        let data = write(None);
+        let size = {
+            let mut reader = SegmentReader::new(&data).unwrap();
+            reader.seek_to(Cues::ID).unwrap().unwrap().encoded_size()
+        };
+        let data = write(Some(size + 1));
+        let (cues, cluster) = layout(check(&data));
+        assert!(cues < cluster);
+    }
+
+    #[test]
+    fn test_seek_head_space() {
+        let mut writer = SegmentWriter::new(Cursor::new(Vec::new()), "webm").unwrap();
+        let info = Info::default().to_node();
+        let mut written = 0;
+        let error = loop {
+            match writer.write_element(&info) {
+                Ok(()) => written += 1,
+                Err(e) => break e,
+            }
+        };
+        assert_eq!(written, 13);
+        assert!(matches!(
+            error,
+            EbmlError::NotEnoughSpace(_, SEEK_HEAD_SPACE)
+        ));
+
+        let mut block = BlockView::new(true, 1, 0, &[1]);
+        block.keyframe = true;
+        writer.write_block(0, &block).unwrap();
+        writer.add_attachment(AttachedFile {
+            file_name: Some(FileName::new("a")),
+            file_media_type: Some(FileMediaType::new("font/ttf")),
+            file_data: Some(FileData::new(vec![1])),
+            file_uid: Some(FileUID::new(1)),
+            ..Default::default()
+        });
+        let data = writer.finish().unwrap().into_inner();
+        let mut reader = SegmentReader::new(&data).unwrap();
+        assert!(reader.cues().unwrap().is_some());
+        assert_eq!(reader.attachments().unwrap().len(), 1);
+    }
+}
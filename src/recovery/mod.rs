@@ -0,0 +1,271 @@
+use crate::tree::TreeParser;
+use crate::{
+    element_id_length, CrcVerification, EbmlError, EbmlNode, EbmlWarning, ElementKinds,
+    ParseContext, ParseLimits, Strictness, Void,
+};
+use std::io::Cursor;
+
+/// Bytes that could not be parsed and were skipped while resynchronizing
+#[derive(Debug)]
+pub struct SkippedRange {
+    pub start: u64,
+    pub end: u64,
+    /// The error that caused the resynchronization
+    pub error: EbmlError,
+}
+
+#[derive(Debug)]
+pub enum Recovered<'a> {
+    Element(EbmlNode<'a>),
+    Skipped(SkippedRange),
+}
+
+/// Reads the elements of one level of a document, skipping over corrupt data.
+///
+/// When an element fails to parse, the data after it is scanned for the next occurrence
+/// of one of the sync IDs (for example the level 1 elements of a Matroska `Segment`) and
+/// reading resumes from there. Every skipped range is reported in order with the elements.
+pub struct Resync<'a, 'k, K: ElementKinds> {
+    data: &'a [u8],
+    kinds: &'k K,
+    sync_ids: Vec<u64>,
+    context: ParseContext,
+    position: u64,
+    end: u64,
+    /// The element found at `position` while resynchronizing, kept so it isn't parsed
+    /// and its warnings recorded twice
+    pending: Option<(EbmlNode<'a>, u64)>,
+}
+
+impl<'a, 'k, K: ElementKinds> Resync<'a, 'k, K> {
+    pub fn new(data: &'a [u8], kinds: &'k K, sync_ids: impl Into<Vec<u64>>) -> Self {
+        Self {
+            data,
+            kinds,
+            sync_ids: sync_ids.into(),
+            context: ParseContext::default(),
+            position: 0,
+            end: data.len() as u64,
+            pending: None,
+        }
+    }
+
+    /// Limits reading to the elements between `start` and `end`, such as the data of a
+    /// `Segment`. Offsets stay relative to the whole buffer.
+    pub fn with_range(mut self, start: u64, end: u64) -> Self {
+        self.position = start;
+        self.end = end.min(self.data.len() as u64);
+        self
+    }
+
+    pub fn with_limits(mut self, limits: ParseLimits) -> Self {
+        self.context.limits = limits;
+        self
+    }
+
+    pub fn with_strictness(mut self, strictness: Strictness) -> Self {
+        self.context.strictness = strictness;
+        self
+    }
+
+    /// Checks CRC-32 elements against the data they cover
+    pub fn with_crc_verification(mut self, crc: CrcVerification) -> Self {
+        self.context.crc = crc;
+        self
+    }
+
+    /// Reports `Void` elements instead of skipping them
+    pub fn with_keep_void(mut self, keep_void: bool) -> Self {
+        self.context.keep_void = keep_void;
+        self
+    }
+
+    /// Violations accepted so far when reading leniently
+    pub fn warnings(&self) -> &[EbmlWarning] {
+        self.context.warnings()
+    }
+
+    /// Parses the element at `position`, returning it with the position after it
+    fn parse_at(&mut self, position: u64) -> Result<(EbmlNode<'a>, u64), EbmlError> {
+        let mut cursor = Cursor::new(&self.data[..self.end as usize]);
+        cursor.set_position(position);
+        let warnings = self.context.warnings().len();
+        let mut parser = TreeParser {
+            kinds: self.kinds,
+            context: &mut self.context,
+        };
+        match parser.node(&mut cursor, self.end, 0) {
+            Ok(node) => Ok((node, cursor.position())),
+            Err(e) => {
+                // Warnings from data that ends up skipped aren't useful
+                self.context.truncate_warnings(warnings);
+                Err(e)
+            }
+        }
+    }
+
+    /// The position of the next sync ID at or after `from`
+    fn find_sync(&self, from: u64) -> Option<u64> {
+        let haystack = &self.data[from as usize..self.end as usize];
+        let needles: Vec<_> = self
+            .sync_ids
+            .iter()
+            .map(|id| (*id, element_id_length(*id)))
+            .collect();
+        (0..haystack.len())
+            .find(|&i| {
+                let rest = &haystack[i..];
+                needles
+                    .iter()
+                    .any(|(id, length)| rest.starts_with(&id.to_be_bytes()[8 - length..]))
+            })
+            .map(|found| from + found as u64)
+    }
+}
+
+impl<'a, 'k, K: ElementKinds> Iterator for Resync<'a, 'k, K> {
+    type Item = Recovered<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let error = loop {
+            if self.position >= self.end {
+                return None;
+            }
+            let parsed = match self.pending.take() {
+                Some(parsed) => Ok(parsed),
+                None => self.parse_at(self.position),
+            };
+            match parsed {
+                Ok((node, end)) => {
+                    self.position = end;
+                    if node.id != Void::ID || self.context.keep_void {
+                        return Some(Recovered::Element(node));
+                    }
+                }
+                Err(e) => break e,
+            }
+        };
+
+        // Keep scanning until a sync ID starts an element that parses
+        let start = self.position;
+        let mut candidate = self.position + 1;
+        while let Some(found) = self.find_sync(candidate.min(self.end)) {
+            if let Ok(parsed) = self.parse_at(found) {
+                self.position = found;
+                self.pending = Some(parsed);
+                return Some(Recovered::Skipped(SkippedRange {
+                    start,
+                    end: found,
+                    error,
+                }));
+            }
+            candidate = found + 1;
+        }
+
+        self.position = self.end;
+        Some(Recovered::Skipped(SkippedRange {
+            start,
+            end: self.end,
+            error,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INFO: u64 = 0x1549A966;
+    const CLUSTER: u64 = 0x1F43B675;
+    const TIMESTAMP: u64 = 0xE7;
+
+    fn kinds(id: u64) -> bool {
+        id == INFO || id == CLUSTER
+    }
+
+    fn cluster(timestamp: u8) -> Vec<u8> {
+        vec![0x1F, 0x43, 0xB6, 0x75, 0x83, 0xE7, 0x81, timestamp]
+    }
+
+    #[test]
+    fn test_skips_garbled_cluster() {
+        let mut data = cluster(1);
+        // A cluster whose second child has a garbled ID
+        data.extend_from_slice(&[0x1F, 0x43, 0xB6, 0x75, 0x85, 0xE7, 0x81, 0x02]);
+        data.extend_from_slice(&[0xFF, 0x00, 0x13]);
+        let damaged_end = data.len() as u64;
+        data.extend_from_slice(&cluster(3));
+
+        let results: Vec<_> = Resync::new(&data[..], &kinds, [INFO, CLUSTER]).collect();
+        assert_eq!(results.len(), 3);
+        match &results[0] {
+            Recovered::Element(node) => {
+                assert_eq!(node.find(&[TIMESTAMP]).unwrap().data(), Some(&[1][..]))
+            }
+            other => panic!("Expected an element: {:?}", other),
+        }
+        match &results[1] {
+            Recovered::Skipped(range) => {
+                assert_eq!(range.start, 8);
+                assert_eq!(range.end, damaged_end);
+            }
+            other => panic!("Expected a skipped range: {:?}", other),
+        }
+        match &results[2] {
+            Recovered::Element(node) => {
+                assert_eq!(node.offset(), Some(damaged_end));
+                assert_eq!(node.find(&[TIMESTAMP]).unwrap().data(), Some(&[3][..]))
+            }
+            other => panic!("Expected an element: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_trailing_garbage() {
+        let mut data = vec![0xFF, 0xFF];
+        data.extend_from_slice(&cluster(1));
+        data.extend_from_slice(&[0x00, 0x00, 0x00]);
+
+        let results: Vec<_> = Resync::new(&data[..], &kinds, [CLUSTER])
+            .with_range(0, data.len() as u64)
+            .collect();
+        assert_eq!(results.len(), 3);
+        assert!(matches!(&results[0], Recovered::Skipped(r) if r.start == 0 && r.end == 2));
+        assert!(matches!(&results[1], Recovered::Element(n) if n.id == CLUSTER));
+        assert!(matches!(&results[2], Recovered::Skipped(r) if r.start == 10 && r.end == 13));
+    }
+
+    #[test]
+    fn test_skips_void() {
+        let mut data = cluster(1);
+        data.extend_from_slice(&[0xEC, 0x82, 0x00, 0x00]);
+        data.extend_from_slice(&cluster(2));
+
+        let results: Vec<_> = Resync::new(&data[..], &kinds, [CLUSTER]).collect();
+        assert_eq!(results.len(), 2);
+        assert!(matches!(&results[1], Recovered::Element(n) if n.offset() == Some(12)));
+
+        let results: Vec<_> = Resync::new(&data[..], &kinds, [CLUSTER])
+            .with_keep_void(true)
+            .collect();
+        assert_eq!(results.len(), 3);
+        assert!(matches!(&results[1], Recovered::Element(n) if n.id == Void::ID));
+    }
+
+    #[test]
+    fn test_lenient_warnings() {
+        let mut data = vec![0xFF, 0xFF];
+        // A cluster with a mismatched CRC-32
+        data.extend_from_slice(&[0x1F, 0x43, 0xB6, 0x75, 0x89, 0xBF, 0x84, 0, 0, 0, 0]);
+        data.extend_from_slice(&[0xE7, 0x81, 0x01]);
+
+        let mut resync = Resync::new(&data[..], &kinds, [CLUSTER])
+            .with_strictness(Strictness::Lenient)
+            .with_crc_verification(CrcVerification::Verify);
+        assert!(matches!(resync.next(), Some(Recovered::Skipped(r)) if r.end == 2));
+        assert!(matches!(resync.next(), Some(Recovered::Element(n)) if n.id == CLUSTER));
+        assert!(resync.next().is_none());
+        assert_eq!(resync.warnings().len(), 1);
+        assert_eq!(resync.warnings()[0].offset, 7);
+    }
+}
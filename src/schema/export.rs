@@ -0,0 +1,202 @@
+use super::{Documentation, EbmlSchema, SchemaElement, SCHEMA_NAMESPACE};
+use std::fmt::Write;
+
+impl EbmlSchema {
+    /// Writes the schema as RFC 8794 XML. The built in header and global elements are
+    /// left out unless the schema redefines them, loading the result gives back an
+    /// equal schema.
+    pub fn to_xml(&self) -> String {
+        let mut out = String::from("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n");
+        let _ = write!(
+            out,
+            "<EBMLSchema xmlns=\"{}\" docType=\"{}\" version=\"{}\"",
+            SCHEMA_NAMESPACE,
+            escape_attribute(&self.doc_type),
+            self.version
+        );
+        if self.ebml != 1 {
+            let _ = write!(out, " ebml=\"{}\"", self.ebml);
+        }
+        out.push_str(">\n");
+        for element in self.elements() {
+            write_element(&mut out, element);
+        }
+        out.push_str("</EBMLSchema>\n");
+        out
+    }
+}
+
+fn write_element(out: &mut String, element: &SchemaElement) {
+    let _ = write!(
+        out,
+        "  <element name=\"{}\" path=\"{}\" id=\"0x{:X}\" type=\"{}\"",
+        escape_attribute(&element.name),
+        escape_attribute(&element.path.to_string()),
+        element.id,
+        element.element_type.as_str()
+    );
+    let mut attribute = |name: &str, value: Option<String>| {
+        if let Some(value) = value {
+            let _ = write!(out, " {}=\"{}\"", name, escape_attribute(&value));
+        }
+    };
+    attribute("range", element.range.as_ref().map(|r| r.to_string()));
+    attribute("length", element.length.as_ref().map(|r| r.to_string()));
+    attribute("default", element.default.clone());
+    attribute(
+        "minOccurs",
+        (element.min_occurs != 0).then(|| element.min_occurs.to_string()),
+    );
+    attribute("maxOccurs", element.max_occurs.map(|m| m.to_string()));
+    attribute(
+        "minver",
+        (element.min_ver != 1).then(|| element.min_ver.to_string()),
+    );
+    attribute("maxver", element.max_ver.map(|m| m.to_string()));
+    attribute("recursive", element.recursive.then(|| "1".to_string()));
+    attribute("recurring", element.recurring.then(|| "1".to_string()));
+    attribute(
+        "unknownsizeallowed",
+        element.unknown_size_allowed.then(|| "1".to_string()),
+    );
+
+    let empty = element.documentation.is_empty()
+        && element.implementation_notes.is_empty()
+        && element.restrictions.is_empty()
+        && element.extensions.is_empty();
+    if empty {
+        out.push_str("/>\n");
+        return;
+    }
+    out.push_str(">\n");
+
+    for documentation in &element.documentation {
+        write_documentation(out, documentation, "    ");
+    }
+    for note in &element.implementation_notes {
+        let _ = writeln!(
+            out,
+            "    <implementation_note note_attribute=\"{}\">{}</implementation_note>",
+            escape_attribute(&note.note_attribute),
+            escape(&note.text)
+        );
+    }
+    if !element.restrictions.is_empty() {
+        out.push_str("    <restriction>\n");
+        for entry in &element.restrictions {
+            let _ = write!(
+                out,
+                "      <enum value=\"{}\" label=\"{}\"",
+                escape_attribute(&entry.value),
+                escape_attribute(&entry.label)
+            );
+            if entry.documentation.is_empty() {
+                out.push_str("/>\n");
+                continue;
+            }
+            out.push_str(">\n");
+            for documentation in &entry.documentation {
+                write_documentation(out, documentation, "        ");
+            }
+            out.push_str("      </enum>\n");
+        }
+        out.push_str("    </restriction>\n");
+    }
+    for extension in &element.extensions {
+        out.push_str("    <extension");
+        for (name, value) in &extension.attributes {
+            let _ = write!(out, " {}=\"{}\"", name, escape_attribute(value));
+        }
+        out.push_str("/>\n");
+    }
+    out.push_str("  </element>\n");
+}
+
+fn write_documentation(out: &mut String, documentation: &Documentation, indent: &str) {
+    out.push_str(indent);
+    out.push_str("<documentation");
+    if let Some(lang) = &documentation.lang {
+        let _ = write!(out, " lang=\"{}\"", escape_attribute(lang));
+    }
+    let _ = writeln!(
+        out,
+        " purpose=\"{}\">{}</documentation>",
+        escape_attribute(&documentation.purpose),
+        escape(&documentation.text)
+    );
+}
+
+/// Escapes text for use in element content, where a `\r` would be read back as `\n`
+fn escape(text: &str) -> String {
+    escape_chars(text, false)
+}
+
+/// Escapes text for use in attribute values, where line breaks and tabs would be read
+/// back as spaces
+fn escape_attribute(text: &str) -> String {
+    escape_chars(text, true)
+}
+
+fn escape_chars(text: &str, attribute: bool) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\r' => out.push_str("&#13;"),
+            '\n' if attribute => out.push_str("&#10;"),
+            '\t' if attribute => out.push_str("&#9;"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::tests::SAMPLE;
+    use crate::schema::{ElementType, SchemaPath};
+
+    #[test]
+    fn test_round_trip() {
+        let schema = EbmlSchema::from_xml(SAMPLE).unwrap();
+        let xml = schema.to_xml();
+        assert_eq!(EbmlSchema::from_xml(&xml).unwrap(), schema);
+        assert!(xml.contains(
+            "<element name=\"Title\" path=\"\\Segment\\Info\\Title\" id=\"0x7BA9\" \
+             type=\"utf-8\" maxOccurs=\"1\"/>"
+        ));
+    }
+
+    #[test]
+    fn test_edited() {
+        let mut schema = EbmlSchema::new("private", 1);
+        let path = SchemaPath::parse("\\Root").unwrap();
+        let mut root = SchemaElement::new("Root", path, 0x1A2B3C4D, ElementType::Master);
+        root.documentation.push(Documentation {
+            lang: None,
+            purpose: "definition".to_string(),
+            text: "Holds <everything> & more".to_string(),
+        });
+        schema.insert(root);
+        let path = SchemaPath::parse("\\Root\\Note").unwrap();
+        let mut note = SchemaElement::new("Note", path, 0x4321, ElementType::Utf8);
+        note.default = Some("\"quoted\"\tand\r\nsplit".to_string());
+        note.documentation.push(Documentation {
+            lang: Some("en".to_string()),
+            purpose: "definition".to_string(),
+            text: "Two\r\nlines".to_string(),
+        });
+        schema.insert(note);
+
+        let reloaded = EbmlSchema::from_xml(&schema.to_xml()).unwrap();
+        assert_eq!(reloaded, schema);
+        assert_eq!(
+            reloaded.element(0x1A2B3C4D).unwrap().documentation[0].text,
+            "Holds <everything> & more"
+        );
+    }
+}
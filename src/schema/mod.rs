@@ -0,0 +1,585 @@
+mod export;
+mod path;
+mod range;
+mod registry;
+mod validate;
+mod value;
+
+pub use path::{PathAtom, SchemaPath};
+pub use range::{Number, Range, RangePart};
+pub use registry::SchemaRegistry;
+pub use validate::{IssueKind, ValidationIssue};
+pub use value::{EbmlValue, ResolvedValue, ResolvedView};
+
+use crate::{element_id_length, read_element_id, EbmlError, ElementKinds, ParseContext};
+use std::collections::HashMap;
+use std::io::{Cursor, Read};
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+/// The namespace of RFC 8794 EBML Schema documents
+pub const SCHEMA_NAMESPACE: &str = "urn:ietf:rfc:8794";
+
+/// The EBML header and global elements every schema contains implicitly (RFC 8794
+/// sections 11.2 and 11.3)
+const BUILTIN_SCHEMA: &str = r#"<EBMLSchema xmlns="urn:ietf:rfc:8794" docType="ebml" version="1">
+  <element name="EBML" path="\EBML" id="0x1A45DFA3" type="master" minOccurs="1" maxOccurs="1"/>
+  <element name="EBMLVersion" path="\EBML\EBMLVersion" id="0x4286" type="uinteger" range="not 0" default="1" minOccurs="1" maxOccurs="1"/>
+  <element name="EBMLReadVersion" path="\EBML\EBMLReadVersion" id="0x42F7" type="uinteger" range="1" default="1" minOccurs="1" maxOccurs="1"/>
+  <element name="EBMLMaxIDLength" path="\EBML\EBMLMaxIDLength" id="0x42F2" type="uinteger" range=">=4" default="4" minOccurs="1" maxOccurs="1"/>
+  <element name="EBMLMaxSizeLength" path="\EBML\EBMLMaxSizeLength" id="0x42F3" type="uinteger" range="not 0" default="8" minOccurs="1" maxOccurs="1"/>
+  <element name="DocType" path="\EBML\DocType" id="0x4282" type="string" length=">0" minOccurs="1" maxOccurs="1"/>
+  <element name="DocTypeVersion" path="\EBML\DocTypeVersion" id="0x4287" type="uinteger" range="not 0" default="1" minOccurs="1" maxOccurs="1"/>
+  <element name="DocTypeReadVersion" path="\EBML\DocTypeReadVersion" id="0x4285" type="uinteger" range="not 0" default="1" minOccurs="1" maxOccurs="1"/>
+  <element name="DocTypeExtension" path="\EBML\DocTypeExtension" id="0x4281" type="master"/>
+  <element name="DocTypeExtensionName" path="\EBML\DocTypeExtension\DocTypeExtensionName" id="0x4283" type="string" length=">0" minOccurs="1" maxOccurs="1"/>
+  <element name="DocTypeExtensionVersion" path="\EBML\DocTypeExtension\DocTypeExtensionVersion" id="0x4284" type="uinteger" range="not 0" minOccurs="1" maxOccurs="1"/>
+  <element name="CRC-32" path="\(1-\)CRC-32" id="0xBF" type="binary" length="4" maxOccurs="1"/>
+  <element name="Void" path="\(-\)Void" id="0xEC" type="binary"/>
+</EBMLSchema>"#;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ElementType {
+    Integer,
+    UnsignedInteger,
+    Float,
+    String,
+    Date,
+    Utf8,
+    Master,
+    Binary,
+}
+
+impl ElementType {
+    /// The name used by the `type` attribute
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ElementType::Integer => "integer",
+            ElementType::UnsignedInteger => "uinteger",
+            ElementType::Float => "float",
+            ElementType::String => "string",
+            ElementType::Date => "date",
+            ElementType::Utf8 => "utf-8",
+            ElementType::Master => "master",
+            ElementType::Binary => "binary",
+        }
+    }
+}
+
+impl FromStr for ElementType {
+    type Err = EbmlError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "integer" => Ok(ElementType::Integer),
+            "uinteger" => Ok(ElementType::UnsignedInteger),
+            "float" => Ok(ElementType::Float),
+            "string" => Ok(ElementType::String),
+            "date" => Ok(ElementType::Date),
+            "utf-8" => Ok(ElementType::Utf8),
+            "master" => Ok(ElementType::Master),
+            "binary" => Ok(ElementType::Binary),
+            _ => Err(EbmlError::InvalidSchema(format!(
+                "Unknown element type: {}",
+                s
+            ))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Documentation {
+    pub lang: Option<String>,
+    pub purpose: String,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImplementationNote {
+    pub note_attribute: String,
+    pub text: String,
+}
+
+/// An `enum` entry of a `restriction`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Enumeration {
+    pub value: String,
+    pub label: String,
+    pub documentation: Vec<Documentation>,
+}
+
+/// An `extension` element, its attributes are defined by whoever uses it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Extension {
+    pub attributes: Vec<(String, String)>,
+}
+
+/// The definition of one element in an EBML Schema
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchemaElement {
+    pub name: String,
+    pub path: SchemaPath,
+    pub id: u64,
+    pub element_type: ElementType,
+    pub range: Option<Range>,
+    pub length: Option<Range>,
+    /// The default as written in the schema
+    pub default: Option<String>,
+    pub min_occurs: u64,
+    /// `None` when unbounded
+    pub max_occurs: Option<u64>,
+    pub min_ver: u64,
+    pub max_ver: Option<u64>,
+    pub recursive: bool,
+    pub recurring: bool,
+    pub unknown_size_allowed: bool,
+    pub documentation: Vec<Documentation>,
+    pub implementation_notes: Vec<ImplementationNote>,
+    pub restrictions: Vec<Enumeration>,
+    pub extensions: Vec<Extension>,
+}
+
+impl SchemaElement {
+    pub fn new(name: &str, path: SchemaPath, id: u64, element_type: ElementType) -> Self {
+        Self {
+            name: name.to_string(),
+            path,
+            id,
+            element_type,
+            range: None,
+            length: None,
+            default: None,
+            min_occurs: 0,
+            max_occurs: None,
+            min_ver: 1,
+            max_ver: None,
+            recursive: false,
+            recurring: false,
+            unknown_size_allowed: false,
+            documentation: Vec::new(),
+            implementation_notes: Vec::new(),
+            restrictions: Vec::new(),
+            extensions: Vec::new(),
+        }
+    }
+
+    pub fn is_master(&self) -> bool {
+        self.element_type == ElementType::Master
+    }
+
+    pub fn is_global(&self) -> bool {
+        self.path.is_global()
+    }
+
+    /// Whether the element may be nested inside itself
+    pub fn is_recursive(&self) -> bool {
+        self.recursive || self.path.is_recursive()
+    }
+
+    fn from_node(node: roxmltree::Node) -> Result<Self, EbmlError> {
+        let name = required(node, "name")?;
+        let path = SchemaPath::parse(required(node, "path")?)?;
+        let id = parse_id(required(node, "id")?)?;
+        let element_type = required(node, "type")?.parse()?;
+
+        let mut element = SchemaElement::new(name, path, id, element_type);
+        element.range = node.attribute("range").map(Range::parse).transpose()?;
+        element.length = node.attribute("length").map(Range::parse).transpose()?;
+        element.default = node.attribute("default").map(str::to_string);
+        element.min_occurs = optional(node, "minOccurs")?.unwrap_or(0);
+        element.max_occurs = match node.attribute("maxOccurs") {
+            None | Some("unbounded") => None,
+            Some(_) => optional(node, "maxOccurs")?,
+        };
+        element.min_ver = optional(node, "minver")?.unwrap_or(1);
+        element.max_ver = optional(node, "maxver")?;
+        element.recursive = flag(node, "recursive")?;
+        element.recurring = flag(node, "recurring")?;
+        element.unknown_size_allowed = flag(node, "unknownsizeallowed")?;
+
+        for child in node.children().filter(|c| c.is_element()) {
+            match child.tag_name().name() {
+                "documentation" => element.documentation.push(documentation(child)?),
+                "implementation_note" => element.implementation_notes.push(ImplementationNote {
+                    note_attribute: required(child, "note_attribute")?.to_string(),
+                    text: text(child),
+                }),
+                "restriction" => {
+                    for entry in child.children().filter(|c| c.has_tag_name("enum")) {
+                        element.restrictions.push(Enumeration {
+                            value: required(entry, "value")?.to_string(),
+                            label: required(entry, "label")?.to_string(),
+                            documentation: entry
+                                .children()
+                                .filter(|c| c.has_tag_name("documentation"))
+                                .map(documentation)
+                                .collect::<Result<_, _>>()?,
+                        });
+                    }
+                }
+                "extension" => element.extensions.push(Extension {
+                    attributes: child
+                        .attributes()
+                        .map(|a| (a.name().to_string(), a.value().to_string()))
+                        .collect(),
+                }),
+                _ => {}
+            }
+        }
+
+        Ok(element)
+    }
+}
+
+/// An EBML Schema (RFC 8794) describing the elements of one document type
+#[derive(Debug, Clone, PartialEq)]
+pub struct EbmlSchema {
+    pub doc_type: String,
+    pub version: u64,
+    /// The EBML version the schema is written for
+    pub ebml: u64,
+    elements: Vec<SchemaElement>,
+    by_id: HashMap<u64, usize>,
+}
+
+impl EbmlSchema {
+    pub fn new(doc_type: &str, version: u64) -> Self {
+        Self {
+            doc_type: doc_type.to_string(),
+            version,
+            ebml: 1,
+            elements: Vec::new(),
+            by_id: HashMap::new(),
+        }
+    }
+
+    /// Loads a schema from its XML representation, whose root must be in [`SCHEMA_NAMESPACE`]
+    pub fn from_xml(xml: &str) -> Result<Self, EbmlError> {
+        let document =
+            roxmltree::Document::parse(xml).map_err(|e| EbmlError::InvalidSchema(e.to_string()))?;
+        let root = document.root_element();
+        if root.tag_name().name() != "EBMLSchema" {
+            return Err(EbmlError::InvalidSchema(format!(
+                "Expected an EBMLSchema root element, found {}",
+                root.tag_name().name()
+            )));
+        }
+        if root.tag_name().namespace() != Some(SCHEMA_NAMESPACE) {
+            return Err(EbmlError::InvalidSchema(format!(
+                "Expected the {} namespace, found {}",
+                SCHEMA_NAMESPACE,
+                root.tag_name().namespace().unwrap_or("none")
+            )));
+        }
+
+        let mut schema = EbmlSchema::new(
+            required(root, "docType")?,
+            optional(root, "version")?.unwrap_or(1),
+        );
+        schema.ebml = optional(root, "ebml")?.unwrap_or(1);
+        for node in root.children().filter(|c| c.has_tag_name("element")) {
+            let element = SchemaElement::from_node(node)?;
+            if schema.by_id.contains_key(&element.id) {
+                return Err(EbmlError::InvalidSchema(format!(
+                    "Element ID {:X} is defined more than once",
+                    element.id
+                )));
+            }
+            schema.insert(element);
+        }
+        Ok(schema)
+    }
+
+    pub fn from_reader<R: Read>(mut reader: R) -> Result<Self, EbmlError> {
+        let mut xml = String::new();
+        reader.read_to_string(&mut xml)?;
+        Self::from_xml(&xml)
+    }
+
+    /// The EBML header and global elements shared by every schema
+    pub fn builtin() -> &'static EbmlSchema {
+        static BUILTIN: OnceLock<EbmlSchema> = OnceLock::new();
+        BUILTIN.get_or_init(|| {
+            EbmlSchema::from_xml(BUILTIN_SCHEMA).expect("The built in schema is valid")
+        })
+    }
+
+    /// The elements defined by this schema, without the built in ones
+    pub fn elements(&self) -> &[SchemaElement] {
+        &self.elements
+    }
+
+    /// Looks up an element by ID, falling back to the EBML header and global elements
+    pub fn element(&self, id: u64) -> Option<&SchemaElement> {
+        match self.by_id.get(&id) {
+            Some(i) => Some(&self.elements[*i]),
+            None => Self::builtin()
+                .by_id
+                .get(&id)
+                .map(|i| &Self::builtin().elements[*i]),
+        }
+    }
+
+    /// This schema's elements followed by the built in ones it doesn't redefine
+    pub(crate) fn all_elements(&self) -> impl Iterator<Item = &SchemaElement> {
+        self.elements.iter().chain(
+            Self::builtin()
+                .elements
+                .iter()
+                .filter(|e| !self.by_id.contains_key(&e.id)),
+        )
+    }
+
+    pub fn element_by_name(&self, name: &str) -> Option<&SchemaElement> {
+        self.elements
+            .iter()
+            .chain(Self::builtin().elements.iter())
+            .find(|e| e.name == name)
+    }
+
+    /// Adds an element, replacing any existing definition with the same ID
+    pub fn insert(&mut self, element: SchemaElement) -> Option<SchemaElement> {
+        match self.by_id.get(&element.id) {
+            Some(i) => Some(std::mem::replace(&mut self.elements[*i], element)),
+            None => {
+                self.by_id.insert(element.id, self.elements.len());
+                self.elements.push(element);
+                None
+            }
+        }
+    }
+
+    pub fn remove(&mut self, id: u64) -> Option<SchemaElement> {
+        let index = self.by_id.remove(&id)?;
+        let element = self.elements.remove(index);
+        for i in self.by_id.values_mut() {
+            if *i > index {
+                *i -= 1;
+            }
+        }
+        Some(element)
+    }
+}
+
+impl ElementKinds for EbmlSchema {
+    fn is_master(&self, id: u64) -> bool {
+        self.element(id).map(|e| e.is_master()).unwrap_or(false)
+    }
+
+    /// An unknown size ends at any element the schema places outside of it, global and
+    /// unknown elements are taken as children
+    fn ends_unknown_size(&self, parent: u64, id: u64) -> bool {
+        let (parent, element) = match (self.element(parent), self.element(id)) {
+            (Some(parent), Some(element)) => (parent, element),
+            _ => return false,
+        };
+        if element.is_global() || (element.is_recursive() && element.id == parent.id) {
+            return false;
+        }
+        let atoms = element.path.atoms();
+        let descendant = atoms[..atoms.len().saturating_sub(1)]
+            .iter()
+            .any(|a| matches!(a, PathAtom::Name { name, .. } if *name == parent.name));
+        !descendant
+    }
+
+    fn defines_zero_id(&self) -> bool {
+        self.by_id.contains_key(&0x80)
+    }
+}
+
+fn invalid_attribute(node: roxmltree::Node, name: &str) -> EbmlError {
+    EbmlError::InvalidSchema(format!(
+        "Invalid or missing {} attribute on {} element{}",
+        name,
+        node.tag_name().name(),
+        node.attribute("name")
+            .map(|n| format!(" {}", n))
+            .unwrap_or_default()
+    ))
+}
+
+fn required<'n>(node: roxmltree::Node<'n, '_>, name: &str) -> Result<&'n str, EbmlError> {
+    node.attribute(name)
+        .ok_or_else(|| invalid_attribute(node, name))
+}
+
+fn optional<T: FromStr>(node: roxmltree::Node, name: &str) -> Result<Option<T>, EbmlError> {
+    node.attribute(name)
+        .map(|v| v.parse().map_err(|_| invalid_attribute(node, name)))
+        .transpose()
+}
+
+fn flag(node: roxmltree::Node, name: &str) -> Result<bool, EbmlError> {
+    match node.attribute(name) {
+        None | Some("0") | Some("false") => Ok(false),
+        Some("1") | Some("true") => Ok(true),
+        Some(_) => Err(invalid_attribute(node, name)),
+    }
+}
+
+fn text(node: roxmltree::Node) -> String {
+    node.text().map(str::trim).unwrap_or_default().to_string()
+}
+
+fn documentation(node: roxmltree::Node) -> Result<Documentation, EbmlError> {
+    Ok(Documentation {
+        lang: node.attribute("lang").map(str::to_string),
+        purpose: required(node, "purpose")?.to_string(),
+        text: text(node),
+    })
+}
+
+/// Parses a hexadecimal element ID and checks it is a valid, minimally encoded ID. The
+/// all zero ID RFC 8794 reserves may be defined at one octet, like Matroska's
+/// `ChapterDisplay`.
+fn parse_id(id: &str) -> Result<u64, EbmlError> {
+    let invalid = || EbmlError::InvalidSchema(format!("Invalid element ID: {}", id));
+    let hex = id
+        .strip_prefix("0x")
+        .or_else(|| id.strip_prefix("0X"))
+        .ok_or_else(invalid)?;
+    let value = u64::from_str_radix(hex, 16).map_err(|_| invalid())?;
+    let length = element_id_length(value);
+    let bytes = value.to_be_bytes();
+    let mut cursor = Cursor::new(&bytes[8 - length..]);
+    let mut context = ParseContext::strict();
+    context.allow_zero_id = value == 0x80;
+    match read_element_id(&mut cursor, &mut context) {
+        Ok(parsed) if parsed == value && cursor.position() == length as u64 => Ok(value),
+        _ => Err(invalid()),
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    pub(crate) const SAMPLE: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<EBMLSchema xmlns="urn:ietf:rfc:8794" docType="sample" version="2">
+  <element name="Segment" path="\Segment" id="0x18538067" type="master" minOccurs="1" maxOccurs="1" unknownsizeallowed="1">
+    <documentation lang="en" purpose="definition">The root element.</documentation>
+  </element>
+  <element name="Info" path="\Segment\Info" id="0x1549A966" type="master" minOccurs="1" maxOccurs="1"/>
+  <element name="TimestampScale" path="\Segment\Info\TimestampScale" id="0x2AD7B1" type="uinteger" range="not 0" default="1000000" minOccurs="1" maxOccurs="1"/>
+  <element name="Title" path="\Segment\Info\Title" id="0x7BA9" type="utf-8" maxOccurs="1"/>
+  <element name="Duration" path="\Segment\Info\Duration" id="0x4489" type="float" range="> 0x0p+0" maxOccurs="1"/>
+  <element name="Tags" path="\Segment\Tags" id="0x1254C367" type="master"/>
+  <element name="Tag" path="\Segment\Tags\Tag" id="0x7373" type="master" minOccurs="1"/>
+  <element name="SimpleTag" path="\Segment\Tags\Tag\+SimpleTag" id="0x67C8" type="master" minOccurs="1" recursive="1"/>
+  <element name="TagName" path="\Segment\Tags\Tag\+SimpleTag\TagName" id="0x45A3" type="utf-8" minOccurs="1" maxOccurs="1"/>
+  <element name="FlagInterlaced" path="\Segment\Info\FlagInterlaced" id="0x9A" type="uinteger" default="0" minver="2">
+    <restriction>
+      <enum value="0" label="undetermined"/>
+      <enum value="1" label="interlaced">
+        <documentation lang="en" purpose="definition">Interlaced frames.</documentation>
+      </enum>
+    </restriction>
+    <extension type="libmatroska" cppname="VideoFlagInterlaced"/>
+  </element>
+</EBMLSchema>"#;
+
+    #[test]
+    fn test_load() {
+        let schema = EbmlSchema::from_xml(SAMPLE).unwrap();
+        assert_eq!(schema.doc_type, "sample");
+        assert_eq!(schema.version, 2);
+        assert_eq!(schema.elements().len(), 10);
+
+        let segment = schema.element(0x18538067).unwrap();
+        assert!(segment.is_master());
+        assert!(segment.unknown_size_allowed);
+        assert_eq!(segment.min_occurs, 1);
+        assert_eq!(segment.max_occurs, Some(1));
+        assert_eq!(segment.documentation[0].text, "The root element.");
+
+        let scale = schema.element_by_name("TimestampScale").unwrap();
+        assert_eq!(scale.element_type, ElementType::UnsignedInteger);
+        assert_eq!(scale.default.as_deref(), Some("1000000"));
+        assert!(!scale.range.as_ref().unwrap().contains(0u64));
+        assert_eq!(scale.path.parent_name(), Some("Info"));
+
+        let simple_tag = schema.element(0x67C8).unwrap();
+        assert!(simple_tag.is_recursive());
+        assert_eq!(simple_tag.max_occurs, None);
+
+        let interlaced = schema.element(0x9A).unwrap();
+        assert_eq!(interlaced.min_ver, 2);
+        assert_eq!(interlaced.restrictions[1].label, "interlaced");
+        assert_eq!(interlaced.extensions[0].attributes.len(), 2);
+    }
+
+    #[test]
+    fn test_builtin_elements() {
+        let schema = EbmlSchema::from_xml(SAMPLE).unwrap();
+        assert!(schema.is_master(0x1A45DFA3));
+        assert_eq!(schema.element(0xEC).unwrap().name, "Void");
+        assert!(schema.element_by_name("CRC-32").unwrap().is_global());
+        assert!(schema.element(0x4242).is_none());
+    }
+
+    #[test]
+    fn test_unknown_sizes() {
+        let schema = EbmlSchema::from_xml(SAMPLE).unwrap();
+        #[rustfmt::skip]
+        let data = [
+            0x18, 0x53, 0x80, 0x67, 0xFF, // Segment
+            0x15, 0x49, 0xA9, 0x66, 0xFF, // Info
+            0x7B, 0xA9, 0x81, b'a', // Title
+            0x12, 0x54, 0xC3, 0x67, 0xFF, // Tags, a sibling of Info
+            0x73, 0x73, 0xFF, // Tag
+            0x67, 0xC8, 0xFF, // SimpleTag
+            0x45, 0xA3, 0x81, b'x', // TagName
+            0x67, 0xC8, 0xFF, // SimpleTag nested in itself
+            0x45, 0xA3, 0x81, b'y', // TagName
+            0xEC, 0x80, // Void, global elements don't end anything
+            0x73, 0x73, 0x80, // Tag, ends both SimpleTags and the first Tag
+            0x1A, 0x45, 0xDF, 0xA3, 0x80, // EBML, ends Tags and Segment
+        ];
+        let doc = crate::EbmlDocument::parse(&data[..], &schema).unwrap();
+        assert_eq!(doc.children.len(), 2);
+        let segment = &doc.children[0];
+        let ids: Vec<_> = segment.children().iter().map(|c| c.id).collect();
+        assert_eq!(ids, [0x1549A966, 0x1254C367]);
+        assert_eq!(segment.children()[0].children().len(), 1);
+
+        let tags = &segment.children()[1];
+        assert_eq!(tags.children().len(), 2);
+        let simple_tag = &tags.children()[0].children()[0];
+        assert_eq!(simple_tag.children().len(), 2);
+        let nested = &simple_tag.children()[1];
+        assert_eq!(nested.id, 0x67C8);
+        assert_eq!(nested.children()[0].data(), Some(&b"y"[..]));
+        assert!(nested.size().unwrap().all_ones());
+
+        // Unknown sizes are kept, the Void is dropped
+        let mut expected = data.to_vec();
+        expected.drain(36..38);
+        assert_eq!(doc.to_bytes().unwrap(), expected);
+    }
+
+    #[test]
+    fn test_invalid() {
+        let duplicate = SAMPLE.replace("0x7BA9", "0x4489");
+        assert!(EbmlSchema::from_xml(&duplicate).is_err());
+
+        // Two octets with a one octet marker bit
+        let bad_id = SAMPLE.replace("0x7BA9", "0x80A9");
+        assert!(EbmlSchema::from_xml(&bad_id).is_err());
+
+        // Reserved, but any schema may define it at one octet
+        let zero_id = SAMPLE.replace("0x9A", "0x80");
+        assert!(EbmlSchema::from_xml(&zero_id).unwrap().defines_zero_id());
+        let long_zero_id = SAMPLE.replace("0x9A", "0x4000");
+        assert!(EbmlSchema::from_xml(&long_zero_id).is_err());
+
+        let bad_type = SAMPLE.replace("utf-8", "text");
+        assert!(EbmlSchema::from_xml(&bad_type).is_err());
+        assert!(EbmlSchema::from_xml("<schema/>").is_err());
+
+        let no_namespace = SAMPLE.replace(r#" xmlns="urn:ietf:rfc:8794""#, "");
+        assert!(EbmlSchema::from_xml(&no_namespace).is_err());
+        let other_namespace = SAMPLE.replace("urn:ietf:rfc:8794", "urn:example");
+        assert!(EbmlSchema::from_xml(&other_namespace).is_err());
+    }
+}
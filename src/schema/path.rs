@@ -0,0 +1,259 @@
+use crate::EbmlError;
+use std::fmt;
+
+/// One step of an EBML path
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PathAtom {
+    /// A named element, `recursive` elements may appear nested in themselves (`\+Name`)
+    Name { name: String, recursive: bool },
+    /// The global placeholder `(min-max\)`, standing for any `min` to `max` ancestors
+    Global { min: u64, max: Option<u64> },
+}
+
+/// A parsed `path` attribute, e.g. `\Segment\Cluster` or `\(1-\)CRC-32`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaPath {
+    atoms: Vec<PathAtom>,
+}
+
+impl SchemaPath {
+    pub fn parse(path: &str) -> Result<Self, EbmlError> {
+        let invalid = || EbmlError::InvalidSchema(format!("Invalid element path: {}", path));
+        let mut atoms = Vec::new();
+        let mut rest = path.strip_prefix('\\').ok_or_else(invalid)?;
+
+        loop {
+            if let Some(global) = rest.strip_prefix('(') {
+                let close = global.find(')').ok_or_else(invalid)?;
+                let occurrence = global[..close].strip_suffix('\\').ok_or_else(invalid)?;
+                let (min, max) = occurrence.split_once('-').ok_or_else(invalid)?;
+                let min = match min {
+                    "" => 0,
+                    v => v.parse().map_err(|_| invalid())?,
+                };
+                let max = match max {
+                    "" => None,
+                    v => Some(v.parse().map_err(|_| invalid())?),
+                };
+                atoms.push(PathAtom::Global { min, max });
+                // The name follows the placeholder without another delimiter
+                rest = &global[close + 1..];
+            }
+
+            let end = rest.find('\\').unwrap_or(rest.len());
+            let atom = &rest[..end];
+            let (name, recursive) = match atom.strip_prefix('+') {
+                Some(name) => (name, true),
+                None => (atom, false),
+            };
+            if name.is_empty() {
+                return Err(invalid());
+            }
+            atoms.push(PathAtom::Name {
+                name: name.to_string(),
+                recursive,
+            });
+
+            if end == rest.len() {
+                break;
+            }
+            rest = &rest[end + 1..];
+        }
+
+        Ok(Self { atoms })
+    }
+
+    pub fn atoms(&self) -> &[PathAtom] {
+        &self.atoms
+    }
+
+    /// The name of the element the path leads to
+    pub fn name(&self) -> &str {
+        match self.atoms.last() {
+            Some(PathAtom::Name { name, .. }) => name,
+            _ => "",
+        }
+    }
+
+    /// Whether the element may be nested inside itself
+    pub fn is_recursive(&self) -> bool {
+        matches!(
+            self.atoms.last(),
+            Some(PathAtom::Name {
+                recursive: true,
+                ..
+            })
+        )
+    }
+
+    /// Whether the element may appear under several parents (like `Void` or `CRC-32`)
+    pub fn is_global(&self) -> bool {
+        self.atoms
+            .iter()
+            .any(|a| matches!(a, PathAtom::Global { .. }))
+    }
+
+    /// The name of the direct parent for paths without a global placeholder
+    pub fn parent_name(&self) -> Option<&str> {
+        if self.is_global() {
+            return None;
+        }
+        match self.atoms.len().checked_sub(2).map(|i| &self.atoms[i]) {
+            Some(PathAtom::Name { name, .. }) => Some(name),
+            _ => None,
+        }
+    }
+
+    /// The depth of the element when it isn't global or nested in recursive ancestors,
+    /// top level elements are at depth 0
+    pub fn depth(&self) -> usize {
+        self.atoms.len() - 1
+    }
+
+    /// Whether an element with this path may be a child of the ancestors named in
+    /// `parents`, ordered from the top level down
+    pub fn allows_parents<S: AsRef<str>>(&self, parents: &[S]) -> bool {
+        match self.atoms.split_last() {
+            Some((last, ancestors)) => {
+                if matches_atoms(ancestors, parents) {
+                    return true;
+                }
+                // A recursive element may also be the child of itself
+                match last {
+                    PathAtom::Name {
+                        name,
+                        recursive: true,
+                    } => {
+                        let nested = parents
+                            .iter()
+                            .rposition(|p| p.as_ref() != name)
+                            .map(|i| i + 1)
+                            .unwrap_or(0);
+                        nested < parents.len() && matches_atoms(ancestors, &parents[..nested])
+                    }
+                    _ => false,
+                }
+            }
+            None => false,
+        }
+    }
+}
+
+fn matches_atoms<S: AsRef<str>>(atoms: &[PathAtom], names: &[S]) -> bool {
+    let (atom, rest) = match atoms.split_first() {
+        Some(v) => v,
+        None => return names.is_empty(),
+    };
+
+    match atom {
+        PathAtom::Name { name, recursive } => {
+            let mut count = 0;
+            while count < names.len() && names[count].as_ref() == name {
+                count += 1;
+                if matches_atoms(rest, &names[count..]) {
+                    return true;
+                }
+                if !recursive {
+                    break;
+                }
+            }
+            false
+        }
+        PathAtom::Global { min, max } => {
+            let min = *min as usize;
+            let max = max.map(|m| m as usize).unwrap_or(names.len());
+            (min..=max.min(names.len())).any(|skip| matches_atoms(rest, &names[skip..]))
+        }
+    }
+}
+
+impl fmt::Display for SchemaPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut after_global = false;
+        for atom in &self.atoms {
+            match atom {
+                PathAtom::Name { name, recursive } => {
+                    if !after_global {
+                        f.write_str("\\")?;
+                    }
+                    if *recursive {
+                        f.write_str("+")?;
+                    }
+                    f.write_str(name)?;
+                    after_global = false;
+                }
+                PathAtom::Global { min, max } => {
+                    f.write_str("\\(")?;
+                    if *min > 0 {
+                        write!(f, "{}", min)?;
+                    }
+                    f.write_str("-")?;
+                    if let Some(max) = max {
+                        write!(f, "{}", max)?;
+                    }
+                    f.write_str("\\)")?;
+                    after_global = true;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let path = SchemaPath::parse("\\Segment\\Cluster\\BlockGroup").unwrap();
+        assert_eq!(path.name(), "BlockGroup");
+        assert_eq!(path.parent_name(), Some("Cluster"));
+        assert_eq!(path.depth(), 2);
+
+        let path = SchemaPath::parse("\\(1-\\)CRC-32").unwrap();
+        assert_eq!(path.name(), "CRC-32");
+        assert!(path.is_global());
+        assert_eq!(path.atoms()[0], PathAtom::Global { min: 1, max: None });
+        assert_eq!(path.to_string(), "\\(1-\\)CRC-32");
+
+        let path = SchemaPath::parse("\\Segment\\Chapters\\EditionEntry\\+ChapterAtom").unwrap();
+        assert!(path.is_recursive());
+        assert_eq!(
+            path.to_string(),
+            "\\Segment\\Chapters\\EditionEntry\\+ChapterAtom"
+        );
+
+        assert!(SchemaPath::parse("Segment").is_err());
+        assert!(SchemaPath::parse("\\Segment\\\\Info").is_err());
+    }
+
+    #[test]
+    fn test_allows_parents() {
+        let void = SchemaPath::parse("\\(-\\)Void").unwrap();
+        assert!(void.allows_parents::<&str>(&[]));
+        assert!(void.allows_parents(&["Segment", "Cluster"]));
+
+        let crc = SchemaPath::parse("\\(1-\\)CRC-32").unwrap();
+        assert!(!crc.allows_parents::<&str>(&[]));
+        assert!(crc.allows_parents(&["Segment"]));
+
+        let atom = SchemaPath::parse("\\Segment\\Chapters\\EditionEntry\\+ChapterAtom").unwrap();
+        assert!(atom.allows_parents(&["Segment", "Chapters", "EditionEntry"]));
+        assert!(atom.allows_parents(&["Segment", "Chapters", "EditionEntry", "ChapterAtom"]));
+        assert!(!atom.allows_parents(&["Segment", "Chapters"]));
+
+        let display =
+            SchemaPath::parse("\\Segment\\Chapters\\EditionEntry\\+ChapterAtom\\ChapterDisplay")
+                .unwrap();
+        let parents = [
+            "Segment",
+            "Chapters",
+            "EditionEntry",
+            "ChapterAtom",
+            "ChapterAtom",
+        ];
+        assert!(display.allows_parents(&parents));
+        assert!(!display.allows_parents(&parents[..3]));
+    }
+}
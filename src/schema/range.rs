@@ -0,0 +1,242 @@
+use crate::EbmlError;
+use std::cmp::Ordering;
+use std::fmt;
+
+/// A numeric value from a schema, kept as an integer when possible so large unsigned
+/// integers aren't rounded
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Number {
+    Integer(i128),
+    Float(f64),
+}
+
+impl Number {
+    pub fn parse(value: &str) -> Result<Self, EbmlError> {
+        let invalid = || EbmlError::InvalidSchema(format!("Invalid number: {}", value));
+        let value = value.trim();
+        let (negative, digits) = match value.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, value.strip_prefix('+').unwrap_or(value)),
+        };
+        let sign = if negative { -1.0 } else { 1.0 };
+
+        if let Some(hex) = digits
+            .strip_prefix("0x")
+            .or_else(|| digits.strip_prefix("0X"))
+        {
+            if hex.contains(['p', 'P']) {
+                return Ok(Number::Float(
+                    sign * parse_hex_float(hex).ok_or_else(invalid)?,
+                ));
+            }
+            let integer = i128::from_str_radix(hex, 16).map_err(|_| invalid())?;
+            return Ok(Number::Integer(if negative { -integer } else { integer }));
+        }
+
+        if digits.contains(['.', 'e', 'E']) || digits == "inf" {
+            let float: f64 = digits.parse().map_err(|_| invalid())?;
+            return Ok(Number::Float(sign * float));
+        }
+
+        let integer: i128 = digits.parse().map_err(|_| invalid())?;
+        Ok(Number::Integer(if negative { -integer } else { integer }))
+    }
+
+    pub fn as_f64(&self) -> f64 {
+        match self {
+            Number::Integer(v) => *v as f64,
+            Number::Float(v) => *v,
+        }
+    }
+}
+
+impl PartialOrd for Number {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (Number::Integer(a), Number::Integer(b)) => a.partial_cmp(b),
+            _ => self.as_f64().partial_cmp(&other.as_f64()),
+        }
+    }
+}
+
+impl From<u64> for Number {
+    fn from(value: u64) -> Self {
+        Number::Integer(value as i128)
+    }
+}
+
+impl From<i64> for Number {
+    fn from(value: i64) -> Self {
+        Number::Integer(value as i128)
+    }
+}
+
+impl From<f64> for Number {
+    fn from(value: f64) -> Self {
+        Number::Float(value)
+    }
+}
+
+/// Parses the part of a C style hexadecimal float after `0x`, e.g. `1.8p+1`
+fn parse_hex_float(hex: &str) -> Option<f64> {
+    let (mantissa, exponent) = hex.split_once(['p', 'P'])?;
+    let exponent: i32 = exponent.parse().ok()?;
+    let (whole, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+
+    let mut value = 0.0;
+    for digit in whole.chars() {
+        value = value * 16.0 + digit.to_digit(16)? as f64;
+    }
+    let mut scale = 1.0 / 16.0;
+    for digit in fraction.chars() {
+        value += digit.to_digit(16)? as f64 * scale;
+        scale /= 16.0;
+    }
+    Some(value * 2f64.powi(exponent))
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RangePart {
+    Exact(Number),
+    Not(Number),
+    Greater(Number),
+    GreaterOrEqual(Number),
+    Less(Number),
+    LessOrEqual(Number),
+    /// Inclusive on both ends
+    Between(Number, Number),
+}
+
+impl RangePart {
+    fn contains(&self, value: Number) -> bool {
+        match self {
+            RangePart::Exact(n) => value.partial_cmp(n) == Some(Ordering::Equal),
+            RangePart::Not(n) => value.partial_cmp(n) != Some(Ordering::Equal),
+            RangePart::Greater(n) => value > *n,
+            RangePart::GreaterOrEqual(n) => value >= *n,
+            RangePart::Less(n) => value < *n,
+            RangePart::LessOrEqual(n) => value <= *n,
+            RangePart::Between(low, high) => value >= *low && value <= *high,
+        }
+    }
+}
+
+/// A `range` or `length` attribute, a comma separated list of allowed values
+#[derive(Debug, Clone, PartialEq)]
+pub struct Range {
+    /// The attribute as written in the schema
+    raw: String,
+    parts: Vec<RangePart>,
+}
+
+impl Range {
+    pub fn parse(range: &str) -> Result<Self, EbmlError> {
+        let parts = range
+            .split(',')
+            .map(|part| parse_part(part.trim()))
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            raw: range.to_string(),
+            parts,
+        })
+    }
+
+    pub fn parts(&self) -> &[RangePart] {
+        &self.parts
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.raw
+    }
+
+    /// Whether the value satisfies any part of the range
+    pub fn contains(&self, value: impl Into<Number>) -> bool {
+        let value = value.into();
+        self.parts.iter().any(|part| part.contains(value))
+    }
+}
+
+impl fmt::Display for Range {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.raw)
+    }
+}
+
+fn parse_part(part: &str) -> Result<RangePart, EbmlError> {
+    if let Some(v) = part.strip_prefix("not") {
+        return Ok(RangePart::Not(Number::parse(v)?));
+    }
+    if let Some(v) = part.strip_prefix(">=") {
+        return Ok(RangePart::GreaterOrEqual(Number::parse(v)?));
+    }
+    if let Some(v) = part.strip_prefix("<=") {
+        return Ok(RangePart::LessOrEqual(Number::parse(v)?));
+    }
+    if let Some(v) = part.strip_prefix('>') {
+        return Ok(RangePart::Greater(Number::parse(v)?));
+    }
+    if let Some(v) = part.strip_prefix('<') {
+        return Ok(RangePart::Less(Number::parse(v)?));
+    }
+
+    // A hyphen after the first character separates the bounds, unless it's the sign
+    // of an exponent or of the upper bound
+    let bytes = part.as_bytes();
+    let split = (1..bytes.len())
+        .find(|i| bytes[*i] == b'-' && !matches!(bytes[*i - 1], b'p' | b'P' | b'e' | b'E' | b'-'));
+    match split {
+        Some(i) => Ok(RangePart::Between(
+            Number::parse(&part[..i])?,
+            Number::parse(&part[i + 1..])?,
+        )),
+        None => Ok(RangePart::Exact(Number::parse(part)?)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_numbers() {
+        assert_eq!(Number::parse("12").unwrap(), Number::Integer(12));
+        assert_eq!(Number::parse("-3").unwrap(), Number::Integer(-3));
+        assert_eq!(Number::parse("0xFF").unwrap(), Number::Integer(255));
+        assert_eq!(Number::parse("0x1p+0").unwrap(), Number::Float(1.0));
+        assert_eq!(Number::parse("0x1.8p+1").unwrap(), Number::Float(3.0));
+        assert_eq!(Number::parse("0x0p+0").unwrap(), Number::Float(0.0));
+        assert_eq!(Number::parse("2.5").unwrap(), Number::Float(2.5));
+        assert!(Number::parse("abc").is_err());
+    }
+
+    #[test]
+    fn test_ranges() {
+        let range = Range::parse("not 0").unwrap();
+        assert!(range.contains(1u64));
+        assert!(!range.contains(0u64));
+
+        let range = Range::parse("1-8").unwrap();
+        assert!(range.contains(8u64));
+        assert!(!range.contains(9u64));
+
+        let range = Range::parse(">= 4").unwrap();
+        assert!(range.contains(4u64));
+        assert!(!range.contains(3u64));
+
+        let range = Range::parse("> 0x0p+0").unwrap();
+        assert!(range.contains(0.5));
+        assert!(!range.contains(0.0));
+
+        let range = Range::parse("0x0p+0-0x1p+0").unwrap();
+        assert!(range.contains(1.0));
+        assert!(!range.contains(1.5));
+
+        let range = Range::parse("-5--1,3").unwrap();
+        assert!(range.contains(-2i64));
+        assert!(range.contains(3i64));
+        assert!(!range.contains(0i64));
+        assert_eq!(range.to_string(), "-5--1,3");
+
+        assert!(Range::parse("0-").is_err());
+    }
+}
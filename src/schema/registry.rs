@@ -0,0 +1,208 @@
+use super::EbmlSchema;
+use crate::{EbmlError, EbmlHeader, ParseContext};
+use std::collections::HashMap;
+use std::io::Cursor;
+
+/// Schemas for the document types and extensions a reader understands.
+///
+/// Documents are matched by the DocType in their header. Elements of the extensions the
+/// header declares are merged into the DocType's schema, extensions that aren't
+/// registered are ignored since their elements can still be read as unknown ones.
+#[derive(Debug, Clone, Default)]
+pub struct SchemaRegistry {
+    doc_types: HashMap<String, EbmlSchema>,
+    /// Extension schemas keyed by name, each version at most once
+    extensions: HashMap<String, Vec<(u64, EbmlSchema)>>,
+}
+
+impl SchemaRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the Matroska schema for both the `matroska` and `webm` DocTypes
+    #[cfg(feature = "matroska")]
+    pub fn with_matroska(mut self) -> Self {
+        let schema = crate::matroska::schema();
+        self.register(schema.clone());
+        self.register_as("webm", schema.clone());
+        self
+    }
+
+    /// Registers a schema under its own DocType, replacing any previous one
+    pub fn register(&mut self, schema: EbmlSchema) -> Option<EbmlSchema> {
+        let doc_type = schema.doc_type.clone();
+        self.register_as(&doc_type, schema)
+    }
+
+    /// Registers a schema for a DocType other than the one it declares, e.g. a subset
+    /// like `webm` read with the `matroska` schema
+    pub fn register_as(&mut self, doc_type: &str, schema: EbmlSchema) -> Option<EbmlSchema> {
+        self.doc_types.insert(doc_type.to_string(), schema)
+    }
+
+    /// Registers the elements a `DocTypeExtension` adds, keyed by the extension's name
+    /// and version
+    pub fn register_extension(&mut self, name: &str, version: u64, schema: EbmlSchema) {
+        let versions = self.extensions.entry(name.to_string()).or_default();
+        match versions.iter_mut().find(|(v, _)| *v == version) {
+            Some(existing) => existing.1 = schema,
+            None => versions.push((version, schema)),
+        }
+    }
+
+    pub fn get(&self, doc_type: &str) -> Option<&EbmlSchema> {
+        self.doc_types.get(doc_type)
+    }
+
+    /// The schema to read a document with the given header. Fails when the DocType isn't
+    /// registered or the document needs a newer version than the registered schema.
+    pub fn resolve(&self, header: &EbmlHeader) -> Result<EbmlSchema, EbmlError> {
+        self.resolve_with(header, &mut ParseContext::strict())
+    }
+
+    /// Like [`Self::resolve`], but a DocTypeReadVersion newer than the registered schema
+    /// is a violation so lenient reads carry on with the schema
+    pub fn resolve_with(
+        &self,
+        header: &EbmlHeader,
+        context: &mut ParseContext,
+    ) -> Result<EbmlSchema, EbmlError> {
+        let doc_type = header.doc_type().unwrap_or_default();
+        let base = self
+            .get(doc_type)
+            .ok_or_else(|| EbmlError::UnknownDocType(doc_type.to_string()))?;
+        header.check_doc_type_read_version(base.version, context)?;
+
+        let mut schema = base.clone();
+        for extension in header.doc_type_extensions() {
+            if let Some(elements) = self.extension(extension.name(), extension.version()) {
+                merge(&mut schema, elements, extension.name())?;
+            }
+        }
+        Ok(schema)
+    }
+
+    /// Reads the EBML header at the start of `data` and resolves its schema
+    pub fn detect(&self, data: &[u8], context: &mut ParseContext) -> Result<EbmlSchema, EbmlError> {
+        let header = EbmlHeader::parse(&mut Cursor::new(data), context)?;
+        self.resolve_with(&header, context)
+    }
+
+    /// The oldest registered version of an extension that covers `version`
+    fn extension(&self, name: &str, version: u64) -> Option<&EbmlSchema> {
+        self.extensions
+            .get(name)?
+            .iter()
+            .filter(|(v, _)| *v >= version)
+            .min_by_key(|(v, _)| *v)
+            .map(|(_, schema)| schema)
+    }
+}
+
+fn merge(schema: &mut EbmlSchema, extension: &EbmlSchema, name: &str) -> Result<(), EbmlError> {
+    for element in extension.elements() {
+        if let Some(existing) = schema.element(element.id) {
+            if existing.name != element.name {
+                return Err(EbmlError::InvalidSchema(format!(
+                    "Extension {} redefines element {:X} ({}) as {}",
+                    name, element.id, existing.name, element.name
+                )));
+            }
+        }
+        schema.insert(element.clone());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::tests::SAMPLE;
+    use crate::{DocType, DocTypeReadVersion, Ebml, EbmlNode, ElementKinds};
+
+    const EXTENSION: &str = r#"<EBMLSchema xmlns="urn:ietf:rfc:8794" docType="chapters-plus" version="2">
+  <element name="ChapterNote" path="\Segment\Info\ChapterNote" id="0x5A5A" type="utf-8"/>
+</EBMLSchema>"#;
+
+    fn header(doc_type: &str, read_version: u64, extension: Option<(&str, u64)>) -> Vec<u8> {
+        let mut children = vec![
+            EbmlNode::string(DocType::ID, doc_type),
+            EbmlNode::unsigned(DocTypeReadVersion::ID, read_version),
+        ];
+        if let Some((name, version)) = extension {
+            children.push(EbmlNode::master(
+                0x4281,
+                vec![
+                    EbmlNode::string(0x4283, name),
+                    EbmlNode::unsigned(0x4284, version),
+                ],
+            ));
+        }
+        let mut bytes = Vec::new();
+        EbmlNode::master(Ebml::ID, children)
+            .write(&mut bytes)
+            .unwrap();
+        bytes
+    }
+
+    fn registry() -> SchemaRegistry {
+        let mut registry = SchemaRegistry::new();
+        registry.register(EbmlSchema::from_xml(SAMPLE).unwrap());
+        registry.register_extension("chapters-plus", 2, EbmlSchema::from_xml(EXTENSION).unwrap());
+        registry
+    }
+
+    #[test]
+    fn test_resolve() {
+        let registry = registry();
+        let mut context = ParseContext::strict();
+        let schema = registry
+            .detect(&header("sample", 2, None), &mut context)
+            .unwrap();
+        assert!(schema.element(0x5A5A).is_none());
+        assert!(schema.is_master(0x1549A966));
+
+        let data = header("sample", 1, Some(("chapters-plus", 1)));
+        let schema = registry.detect(&data, &mut context).unwrap();
+        assert_eq!(schema.element(0x5A5A).unwrap().name, "ChapterNote");
+
+        // No registered version covers it, the extension's elements stay unknown
+        let data = header("sample", 1, Some(("chapters-plus", 3)));
+        let schema = registry.detect(&data, &mut context).unwrap();
+        assert!(schema.element(0x5A5A).is_none());
+    }
+
+    #[test]
+    fn test_rejected() {
+        let mut registry = registry();
+        let mut context = ParseContext::strict();
+        match registry.detect(&header("sample", 3, None), &mut context) {
+            Err(EbmlError::UnsupportedReadVersion(doc_type, 3, 2)) if doc_type == "sample" => {}
+            other => panic!("Incorrect result: {:?}", other),
+        }
+        // Lenient reads go on with the registered schema
+        let mut lenient = ParseContext::lenient();
+        assert!(registry
+            .detect(&header("sample", 3, None), &mut lenient)
+            .is_ok());
+        assert_eq!(lenient.warnings().len(), 1);
+
+        match registry.detect(&header("other", 1, None), &mut context) {
+            Err(EbmlError::UnknownDocType(doc_type)) if doc_type == "other" => {}
+            other => panic!("Incorrect result: {:?}", other),
+        }
+
+        let conflicting = EXTENSION.replace("0x5A5A", "0x7BA9");
+        registry.register_extension(
+            "chapters-plus",
+            2,
+            EbmlSchema::from_xml(&conflicting).unwrap(),
+        );
+        let data = header("sample", 1, Some(("chapters-plus", 2)));
+        assert!(matches!(
+            registry.detect(&data, &mut context),
+            Err(EbmlError::InvalidSchema(_))
+        ));
+    }
+}
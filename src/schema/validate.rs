@@ -0,0 +1,358 @@
+use super::{EbmlSchema, ElementType, Number, SchemaElement};
+use crate::{
+    EbmlDate, EbmlDocument, EbmlError, EbmlFloat, EbmlNode, EbmlSignedInteger, EbmlString,
+    EbmlUnsignedInteger, EbmlUtf8,
+};
+use std::collections::HashMap;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum IssueKind {
+    /// The ID isn't defined by the schema
+    UnknownElement,
+    /// The element isn't allowed under its parent
+    IllegalParent,
+    /// A master where data was expected or the other way around
+    WrongType {
+        expected: ElementType,
+    },
+    /// The data couldn't be decoded as the element's type
+    InvalidValue(String),
+    /// The value is outside of the element's `range`
+    OutOfRange(String),
+    /// The data length is outside of the element's `length`
+    InvalidLength(usize),
+    /// A mandatory element without a default is absent from its parent, `None` at the
+    /// top level
+    Missing {
+        parent: Option<u64>,
+    },
+    TooManyOccurrences {
+        count: usize,
+        max: u64,
+    },
+}
+
+impl fmt::Display for IssueKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IssueKind::UnknownElement => write!(f, "unknown element"),
+            IssueKind::IllegalParent => write!(f, "not allowed in its parent"),
+            IssueKind::WrongType { expected } => {
+                write!(f, "expected a {} element", expected.as_str())
+            }
+            IssueKind::InvalidValue(e) => write!(f, "invalid value: {}", e),
+            IssueKind::OutOfRange(v) => write!(f, "value {} is out of range", v),
+            IssueKind::InvalidLength(l) => write!(f, "invalid length {}", l),
+            IssueKind::Missing { parent: Some(p) } => write!(f, "missing from {:X}", p),
+            IssueKind::Missing { parent: None } => write!(f, "missing from the top level"),
+            IssueKind::TooManyOccurrences { count, max } => {
+                write!(f, "occurs {} times, at most {} allowed", count, max)
+            }
+        }
+    }
+}
+
+/// A schema violation found in a document
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationIssue {
+    /// The element the issue is about
+    pub id: u64,
+    /// Position of the offending element, or of the parent for missing elements. `None`
+    /// for elements that were created in memory.
+    pub offset: Option<u64>,
+    pub kind: IssueKind,
+}
+
+impl fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Element {:X}", self.id)?;
+        if let Some(offset) = self.offset {
+            write!(f, " at {}", offset)?;
+        }
+        write!(f, ": {}", self.kind)
+    }
+}
+
+impl EbmlSchema {
+    /// Checks a document against the schema, returning every violation found
+    pub fn validate(&self, document: &EbmlDocument) -> Vec<ValidationIssue> {
+        let validator = Validator::new(self);
+        let mut issues = Vec::new();
+        validator.check_children(None, &document.children, &mut Vec::new(), &mut issues);
+        issues
+    }
+}
+
+struct Validator<'s> {
+    schema: &'s EbmlSchema,
+    /// Non global elements keyed by the name of the parent they may appear in, top level
+    /// elements are under an empty name
+    by_parent: HashMap<&'s str, Vec<&'s SchemaElement>>,
+}
+
+impl<'s> Validator<'s> {
+    fn new(schema: &'s EbmlSchema) -> Self {
+        let mut by_parent: HashMap<&str, Vec<&SchemaElement>> = HashMap::new();
+        for element in schema.all_elements().filter(|e| !e.is_global()) {
+            let parent = element.path.parent_name().unwrap_or("");
+            by_parent.entry(parent).or_default().push(element);
+            if element.is_recursive() {
+                by_parent.entry(&element.name).or_default().push(element);
+            }
+        }
+        Self { schema, by_parent }
+    }
+
+    fn check_children(
+        &self,
+        parent: Option<&EbmlNode>,
+        children: &[EbmlNode],
+        parents: &mut Vec<&'s str>,
+        issues: &mut Vec<ValidationIssue>,
+    ) {
+        let mut counts: HashMap<u64, (usize, Option<u64>)> = HashMap::new();
+        // IDs in the order they first appear so issues are reported in document order
+        let mut seen = Vec::new();
+
+        for child in children {
+            let count = counts.entry(child.id).or_insert_with(|| {
+                seen.push(child.id);
+                (0, None)
+            });
+            count.0 += 1;
+
+            let element = match self.schema.element(child.id) {
+                Some(element) => element,
+                None => {
+                    issues.push(issue(child, IssueKind::UnknownElement));
+                    continue;
+                }
+            };
+            if let Some(max) = element.max_occurs {
+                // Report the first occurrence past the maximum
+                if count.0 as u64 == max + 1 {
+                    count.1 = child.offset();
+                }
+            }
+
+            if !element.path.allows_parents(parents) {
+                issues.push(issue(child, IssueKind::IllegalParent));
+            }
+
+            if element.is_master() != child.is_master() {
+                issues.push(issue(
+                    child,
+                    IssueKind::WrongType {
+                        expected: element.element_type,
+                    },
+                ));
+                continue;
+            }
+
+            match child.data() {
+                Some(data) => check_value(element, child, data, issues),
+                None => {
+                    parents.push(&element.name);
+                    self.check_children(Some(child), child.children(), parents, issues);
+                    parents.pop();
+                }
+            }
+        }
+
+        for id in seen {
+            let (count, offset) = counts[&id];
+            if let Some(max) = self.schema.element(id).and_then(|e| e.max_occurs) {
+                if count as u64 > max {
+                    issues.push(ValidationIssue {
+                        id,
+                        offset,
+                        kind: IssueKind::TooManyOccurrences { count, max },
+                    });
+                }
+            }
+        }
+
+        let parent_name = parents.last().copied().unwrap_or("");
+        let expected = self.by_parent.get(parent_name).into_iter().flatten();
+        for element in expected {
+            // Nesting a recursive element in itself is always optional
+            let nested = element.is_recursive() && element.name == parent_name;
+            if element.min_occurs == 0
+                || nested
+                || element.default.is_some()
+                || counts.contains_key(&element.id)
+                || !element.path.allows_parents(parents)
+            {
+                continue;
+            }
+            issues.push(ValidationIssue {
+                id: element.id,
+                offset: parent.and_then(|p| p.offset()),
+                kind: IssueKind::Missing {
+                    parent: parent.map(|p| p.id),
+                },
+            });
+        }
+    }
+}
+
+fn issue(node: &EbmlNode, kind: IssueKind) -> ValidationIssue {
+    ValidationIssue {
+        id: node.id,
+        offset: node.offset(),
+        kind,
+    }
+}
+
+fn check_value(
+    element: &SchemaElement,
+    node: &EbmlNode,
+    data: &[u8],
+    issues: &mut Vec<ValidationIssue>,
+) {
+    if let Some(length) = &element.length {
+        if !length.contains(data.len() as u64) {
+            issues.push(issue(node, IssueKind::InvalidLength(data.len())));
+        }
+    }
+
+    let value: Result<Option<Number>, EbmlError> = match element.element_type {
+        ElementType::UnsignedInteger => {
+            EbmlUnsignedInteger::new(data).map(|v| Some(v.value().into()))
+        }
+        ElementType::Integer => EbmlSignedInteger::new(data).map(|v| Some(v.value().into())),
+        ElementType::Float => EbmlFloat::new(data).map(|v| Some(v.value().into())),
+        ElementType::Date => EbmlDate::new(data).map(|v| Some(v.value().into())),
+        ElementType::String => EbmlString::new(data).map(|_| None),
+        ElementType::Utf8 => EbmlUtf8::new(data).map(|_| None),
+        ElementType::Master | ElementType::Binary => Ok(None),
+    };
+
+    match value {
+        Err(e) => issues.push(issue(node, IssueKind::InvalidValue(e.to_string()))),
+        Ok(Some(value)) => {
+            // An empty element stands for its default rather than zero
+            let uses_default = data.is_empty() && element.default.is_some();
+            if let Some(range) = &element.range {
+                if !uses_default && !range.contains(value) {
+                    let value = match value {
+                        Number::Integer(v) => v.to_string(),
+                        Number::Float(v) => v.to_string(),
+                    };
+                    issues.push(issue(node, IssueKind::OutOfRange(value)));
+                }
+            }
+        }
+        Ok(None) => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::tests::SAMPLE;
+    use crate::{Ebml, EbmlNode};
+
+    const SEGMENT: u64 = 0x18538067;
+    const INFO: u64 = 0x1549A966;
+
+    fn header() -> EbmlNode<'static> {
+        EbmlNode::master(Ebml::ID, vec![EbmlNode::string(0x4282, "sample")])
+    }
+
+    #[test]
+    fn test_valid() {
+        let schema = EbmlSchema::from_xml(SAMPLE).unwrap();
+        let info = EbmlNode::master(
+            INFO,
+            vec![
+                EbmlNode::unsigned(0x2AD7B1, 1_000_000),
+                EbmlNode::string(0x7BA9, "title"),
+                EbmlNode::binary(0xEC, vec![0; 4]),
+            ],
+        );
+        let doc = EbmlDocument::new(vec![header(), EbmlNode::master(SEGMENT, vec![info])]);
+        assert_eq!(schema.validate(&doc), vec![]);
+    }
+
+    #[test]
+    fn test_issues() {
+        let schema = EbmlSchema::from_xml(SAMPLE).unwrap();
+        let info = EbmlNode::master(
+            INFO,
+            vec![
+                EbmlNode::unsigned(0x2AD7B1, 0),
+                EbmlNode::string(0x7BA9, "a"),
+                EbmlNode::string(0x7BA9, "b"),
+                EbmlNode::binary(0x4489, vec![0; 3]),
+                EbmlNode::master(0x7373, vec![]),
+                EbmlNode::binary(0x4242, vec![]),
+            ],
+        );
+        let segment = EbmlNode::master(SEGMENT, vec![info]);
+        let doc = EbmlDocument::new(vec![EbmlNode::master(Ebml::ID, vec![]), segment]);
+        let data = doc.to_bytes().unwrap();
+        let doc = EbmlDocument::parse(&data[..], &schema).unwrap();
+        let issues = schema.validate(&doc);
+
+        let find = |id: u64| -> Vec<&IssueKind> {
+            issues
+                .iter()
+                .filter(|i| i.id == id)
+                .map(|i| &i.kind)
+                .collect()
+        };
+        assert_eq!(
+            find(0x4282),
+            vec![&IssueKind::Missing {
+                parent: Some(Ebml::ID)
+            }]
+        );
+        assert_eq!(
+            find(0x2AD7B1),
+            vec![&IssueKind::OutOfRange("0".to_string())]
+        );
+        assert_eq!(
+            find(0x7BA9),
+            vec![&IssueKind::TooManyOccurrences { count: 2, max: 1 }]
+        );
+        assert!(matches!(find(0x4489)[..], [IssueKind::InvalidValue(_)]));
+        assert_eq!(find(0x7373), vec![&IssueKind::IllegalParent]);
+        assert_eq!(find(0x4242), vec![&IssueKind::UnknownElement]);
+        assert_eq!(issues.len(), 6);
+
+        // The second Title is the first one too many
+        let title = issues.iter().find(|i| i.id == 0x7BA9).unwrap();
+        assert_eq!(title.offset, Some(24));
+        assert_eq!(
+            title.to_string(),
+            "Element 7BA9 at 24: occurs 2 times, at most 1 allowed"
+        );
+    }
+
+    #[test]
+    fn test_wrong_type_and_recursion() {
+        let schema = EbmlSchema::from_xml(SAMPLE).unwrap();
+        let simple_tag = |children| EbmlNode::master(0x67C8, children);
+        let nested = simple_tag(vec![
+            EbmlNode::string(0x45A3, "outer"),
+            simple_tag(vec![EbmlNode::string(0x45A3, "inner")]),
+        ]);
+        let tags = EbmlNode::master(0x1254C367, vec![EbmlNode::master(0x7373, vec![nested])]);
+        let info = EbmlNode::master(INFO, vec![EbmlNode::master(0x7BA9, vec![])]);
+        let doc = EbmlDocument::new(vec![header(), EbmlNode::master(SEGMENT, vec![info, tags])]);
+
+        let issues = schema.validate(&doc);
+        assert_eq!(
+            issues,
+            vec![ValidationIssue {
+                id: 0x7BA9,
+                offset: None,
+                kind: IssueKind::WrongType {
+                    expected: ElementType::Utf8
+                }
+            }]
+        );
+    }
+}
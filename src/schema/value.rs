@@ -0,0 +1,307 @@
+use super::{EbmlSchema, ElementType, Number, SchemaElement};
+use crate::{
+    EbmlDate, EbmlError, EbmlFloat, EbmlNode, EbmlSignedInteger, EbmlString, EbmlUnsignedInteger,
+    EbmlUtf8,
+};
+use std::borrow::Cow;
+
+/// The decoded value of an element, typed according to its schema
+#[derive(Debug, Clone, PartialEq)]
+pub enum EbmlValue<'a> {
+    Integer(i64),
+    UnsignedInteger(u64),
+    Float(f64),
+    String(String),
+    /// Nanoseconds since 2001-01-01T00:00:00 UTC
+    Date(i64),
+    Utf8(String),
+    Binary(Cow<'a, [u8]>),
+    /// Master elements have children rather than a value
+    Master,
+}
+
+impl<'a> EbmlValue<'a> {
+    /// Decodes element data as the given type
+    pub fn decode(element_type: ElementType, data: &'a [u8]) -> Result<Self, EbmlError> {
+        Ok(match element_type {
+            ElementType::Integer => EbmlValue::Integer(EbmlSignedInteger::new(data)?.value()),
+            ElementType::UnsignedInteger => {
+                EbmlValue::UnsignedInteger(EbmlUnsignedInteger::new(data)?.value())
+            }
+            ElementType::Float => EbmlValue::Float(EbmlFloat::new(data)?.value()),
+            ElementType::String => EbmlValue::String(EbmlString::new(data)?.as_str().to_string()),
+            ElementType::Date => EbmlValue::Date(EbmlDate::new(data)?.value()),
+            ElementType::Utf8 => EbmlValue::Utf8(EbmlUtf8::new(data)?.as_str().to_string()),
+            ElementType::Binary => EbmlValue::Binary(Cow::Borrowed(data)),
+            ElementType::Master => EbmlValue::Master,
+        })
+    }
+
+    pub fn as_unsigned(&self) -> Option<u64> {
+        match self {
+            EbmlValue::UnsignedInteger(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    /// Signed integers and dates
+    pub fn as_signed(&self) -> Option<i64> {
+        match self {
+            EbmlValue::Integer(v) | EbmlValue::Date(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    pub fn as_float(&self) -> Option<f64> {
+        match self {
+            EbmlValue::Float(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    /// ASCII and UTF-8 strings
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            EbmlValue::String(v) | EbmlValue::Utf8(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    pub fn as_bytes(&self) -> Option<&[u8]> {
+        match self {
+            EbmlValue::Binary(v) => Some(v),
+            _ => None,
+        }
+    }
+}
+
+impl SchemaElement {
+    /// The `default` attribute decoded as the element's type
+    pub fn default_value(&self) -> Result<Option<EbmlValue<'static>>, EbmlError> {
+        let default = match &self.default {
+            Some(default) => default,
+            None => return Ok(None),
+        };
+        let invalid =
+            || EbmlError::InvalidSchema(format!("Invalid default {} for {}", default, self.name));
+        let integer = || match Number::parse(default)? {
+            Number::Integer(v) => Ok(v),
+            Number::Float(_) => Err(invalid()),
+        };
+
+        let value = match self.element_type {
+            ElementType::Integer => {
+                EbmlValue::Integer(i64::try_from(integer()?).map_err(|_| invalid())?)
+            }
+            ElementType::UnsignedInteger => {
+                EbmlValue::UnsignedInteger(u64::try_from(integer()?).map_err(|_| invalid())?)
+            }
+            ElementType::Date => EbmlValue::Date(i64::try_from(integer()?).map_err(|_| invalid())?),
+            ElementType::Float => EbmlValue::Float(Number::parse(default)?.as_f64()),
+            ElementType::String => EbmlValue::String(default.clone()),
+            ElementType::Utf8 => EbmlValue::Utf8(default.clone()),
+            ElementType::Binary => {
+                EbmlValue::Binary(Cow::Owned(parse_hex(default).ok_or_else(invalid)?))
+            }
+            ElementType::Master => return Err(invalid()),
+        };
+        Ok(Some(value))
+    }
+}
+
+/// A child value of a [`ResolvedView`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResolvedValue<'n> {
+    pub id: u64,
+    pub value: EbmlValue<'n>,
+    /// Whether the value comes from the schema rather than from data in the document
+    pub is_default: bool,
+}
+
+/// The children of a master element as a player interprets them: empty elements read
+/// as their default and absent elements that have a default are filled in
+#[derive(Debug, Clone)]
+pub struct ResolvedView<'n, 'a> {
+    node: &'n EbmlNode<'a>,
+    values: Vec<ResolvedValue<'n>>,
+}
+
+impl<'n, 'a> ResolvedView<'n, 'a> {
+    pub fn node(&self) -> &'n EbmlNode<'a> {
+        self.node
+    }
+
+    /// Children in document order followed by the defaults of absent ones
+    pub fn values(&self) -> &[ResolvedValue<'n>] {
+        &self.values
+    }
+
+    /// The first value of a child
+    pub fn get(&self, id: u64) -> Option<&EbmlValue<'n>> {
+        self.values.iter().find(|v| v.id == id).map(|v| &v.value)
+    }
+
+    pub fn get_all(&self, id: u64) -> impl Iterator<Item = &EbmlValue<'n>> {
+        self.values
+            .iter()
+            .filter(move |v| v.id == id)
+            .map(|v| &v.value)
+    }
+
+    pub fn unsigned(&self, id: u64) -> Option<u64> {
+        self.get(id).and_then(|v| v.as_unsigned())
+    }
+
+    pub fn signed(&self, id: u64) -> Option<i64> {
+        self.get(id).and_then(|v| v.as_signed())
+    }
+
+    pub fn float(&self, id: u64) -> Option<f64> {
+        self.get(id).and_then(|v| v.as_float())
+    }
+
+    pub fn str(&self, id: u64) -> Option<&str> {
+        self.get(id).and_then(|v| v.as_str())
+    }
+}
+
+impl EbmlSchema {
+    /// Reads an element's value, an empty element reads as its default. Elements the
+    /// schema doesn't define read as binary.
+    pub fn value<'n>(&self, node: &'n EbmlNode) -> Result<EbmlValue<'n>, EbmlError> {
+        let data = match node.data() {
+            Some(data) => data,
+            None => return Ok(EbmlValue::Master),
+        };
+        let element = match self.element(node.id) {
+            Some(element) => element,
+            None => return Ok(EbmlValue::Binary(Cow::Borrowed(data))),
+        };
+        if data.is_empty() {
+            if let Some(default) = element.default_value()? {
+                return Ok(default);
+            }
+        }
+        EbmlValue::decode(element.element_type, data)
+    }
+
+    /// Reads every child of a master element and fills in the defaults of absent ones
+    pub fn resolve<'n, 'a>(
+        &self,
+        node: &'n EbmlNode<'a>,
+    ) -> Result<ResolvedView<'n, 'a>, EbmlError> {
+        if !node.is_master() {
+            return Err(EbmlError::NotMasterElement(node.id));
+        }
+        let mut values = node
+            .children()
+            .iter()
+            .map(|child| {
+                Ok(ResolvedValue {
+                    id: child.id,
+                    value: self.value(child)?,
+                    is_default: false,
+                })
+            })
+            .collect::<Result<Vec<_>, EbmlError>>()?;
+
+        if let Some(parent) = self.element(node.id) {
+            let absent = self.all_elements().filter(|e| {
+                e.path.parent_name() == Some(parent.name.as_str())
+                    && !node.children().iter().any(|c| c.id == e.id)
+            });
+            for element in absent {
+                if let Some(value) = element.default_value()? {
+                    values.push(ResolvedValue {
+                        id: element.id,
+                        value,
+                        is_default: true,
+                    });
+                }
+            }
+        }
+
+        Ok(ResolvedView { node, values })
+    }
+}
+
+fn parse_hex(hex: &str) -> Option<Vec<u8>> {
+    let hex = hex.strip_prefix("0x").unwrap_or(hex);
+    if hex.len() % 2 != 0 {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::tests::SAMPLE;
+    use crate::Ebml;
+
+    const INFO: u64 = 0x1549A966;
+
+    #[test]
+    fn test_empty_reads_default() {
+        let schema = EbmlSchema::from_xml(SAMPLE).unwrap();
+        let scale = EbmlNode::binary(0x2AD7B1, vec![]);
+        assert_eq!(
+            schema.value(&scale).unwrap(),
+            EbmlValue::UnsignedInteger(1_000_000)
+        );
+        // Without a default an empty element is zero
+        let duration = EbmlNode::binary(0x4489, vec![]);
+        assert_eq!(schema.value(&duration).unwrap(), EbmlValue::Float(0.0));
+        let title = EbmlNode::string(0x7BA9, "title");
+        assert_eq!(schema.value(&title).unwrap().as_str(), Some("title"));
+        let unknown = EbmlNode::binary(0x4242, vec![1]);
+        assert_eq!(schema.value(&unknown).unwrap().as_bytes(), Some(&[1][..]));
+    }
+
+    #[test]
+    fn test_resolve() {
+        let schema = EbmlSchema::from_xml(SAMPLE).unwrap();
+        let info = EbmlNode::master(INFO, vec![EbmlNode::string(0x7BA9, "title")]);
+        let view = schema.resolve(&info).unwrap();
+        assert_eq!(view.str(0x7BA9), Some("title"));
+        assert_eq!(view.unsigned(0x2AD7B1), Some(1_000_000));
+        assert_eq!(view.unsigned(0x9A), Some(0));
+        assert_eq!(view.float(0x4489), None);
+        assert!(view.values().iter().filter(|v| v.is_default).count() == 2);
+
+        // Present values aren't replaced by defaults
+        let info = EbmlNode::master(INFO, vec![EbmlNode::unsigned(0x2AD7B1, 1000)]);
+        let view = schema.resolve(&info).unwrap();
+        assert_eq!(view.unsigned(0x2AD7B1), Some(1000));
+        assert!(!view.values()[0].is_default);
+
+        // Header defaults come from the built in schema
+        let header = EbmlNode::master(Ebml::ID, vec![EbmlNode::string(0x4282, "sample")]);
+        let view = schema.resolve(&header).unwrap();
+        assert_eq!(view.unsigned(0x42F2), Some(4));
+        assert_eq!(view.unsigned(0x42F3), Some(8));
+        assert_eq!(view.str(0x4282), Some("sample"));
+
+        assert!(schema.resolve(&EbmlNode::binary(0x7BA9, vec![])).is_err());
+    }
+
+    #[test]
+    fn test_default_values() {
+        let schema = EbmlSchema::from_xml(SAMPLE).unwrap();
+        let mut element = schema.element(0x4489).unwrap().clone();
+        element.default = Some("0x1.8p+1".to_string());
+        assert_eq!(
+            element.default_value().unwrap(),
+            Some(EbmlValue::Float(3.0))
+        );
+        element.default = Some("abc".to_string());
+        assert!(element.default_value().is_err());
+
+        let mut element = schema.element(0x2AD7B1).unwrap().clone();
+        element.default = Some("-1".to_string());
+        assert!(element.default_value().is_err());
+    }
+}
@@ -0,0 +1,702 @@
+use crate::crc::crc32;
+use crate::{
+    element_id_length, get_range, read_id, write_element_id, Crc32, DocTypeExtension, Ebml, EbmlElement,
+    EbmlError, ParseContext, VarInt, VarIntLength, Void,
+};
+use std::borrow::Cow;
+use std::io::{Cursor, Write};
+
+/// Tells the tree parser which element IDs contain child elements rather than data.
+///
+/// The EBML header master elements are always recognized, everything else is up to the
+/// implementor (usually a schema or a closure over a list of IDs).
+pub trait ElementKinds {
+    fn is_master(&self, id: u64) -> bool;
+
+    /// Whether element `id` ends the master `parent` of unknown size instead of being
+    /// nested in it. By default unknown sizes extend to the end of the enclosing element.
+    fn ends_unknown_size(&self, _parent: u64, _id: u64) -> bool {
+        false
+    }
+
+    /// Whether the all zero ID `0x80`, which RFC 8794 reserves, names an element here
+    fn defines_zero_id(&self) -> bool {
+        false
+    }
+}
+
+impl<F: Fn(u64) -> bool> ElementKinds for F {
+    fn is_master(&self, id: u64) -> bool {
+        self(id)
+    }
+}
+
+#[inline]
+fn is_header_master(id: u64) -> bool {
+    id == Ebml::ID || id == DocTypeExtension::ID
+}
+
+/// An editable, in memory EBML document.
+///
+/// Element data is borrowed from the source buffer until it is replaced, so parsing a
+/// memory mapped file does not copy any payloads.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EbmlDocument<'a> {
+    pub children: Vec<EbmlNode<'a>>,
+}
+
+impl<'a> EbmlDocument<'a> {
+    pub fn new(children: Vec<EbmlNode<'a>>) -> Self {
+        Self { children }
+    }
+
+    /// Parses every top level element in `data` strictly with the default limits
+    pub fn parse<K: ElementKinds>(data: &'a [u8], kinds: &K) -> Result<Self, EbmlError> {
+        Self::parse_with(data, kinds, &mut ParseContext::strict())
+    }
+
+    /// Parses every top level element in `data` using the context's limits and strictness
+    pub fn parse_with<K: ElementKinds>(
+        data: &'a [u8],
+        kinds: &K,
+        context: &mut ParseContext,
+    ) -> Result<Self, EbmlError> {
+        let mut parser = TreeParser { kinds, context };
+        let mut cursor = Cursor::new(data);
+        let end = data.len() as u64;
+        let mut children = Vec::new();
+        while cursor.position() < end {
+            let child = parser.node(&mut cursor, end, 0)?;
+            if child.id != Void::ID || parser.context.keep_void {
+                children.push(child);
+            }
+        }
+        Ok(Self { children })
+    }
+
+    /// Finds an element by following the IDs in `path` from the top level
+    pub fn find(&self, path: &[u64]) -> Option<&EbmlNode<'a>> {
+        let (first, rest) = path.split_first()?;
+        self.children.iter().find(|c| c.id == *first)?.find(rest)
+    }
+
+    pub fn find_mut(&mut self, path: &[u64]) -> Option<&mut EbmlNode<'a>> {
+        let (first, rest) = path.split_first()?;
+        self.children
+            .iter_mut()
+            .find(|c| c.id == *first)?
+            .find_mut(rest)
+    }
+
+    /// The number of octets the document occupies when written
+    pub fn encoded_size(&self) -> u64 {
+        self.children.iter().map(|c| c.encoded_size()).sum()
+    }
+
+    /// Writes the document with every data size recalculated
+    pub fn write<W: Write>(&self, writer: &mut W) -> Result<usize, EbmlError> {
+        let mut written = 0;
+        for child in &self.children {
+            written += child.write(writer)?;
+        }
+        Ok(written)
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>, EbmlError> {
+        let mut bytes = Vec::with_capacity(self.encoded_size() as usize);
+        self.write(&mut bytes)?;
+        Ok(bytes)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum EbmlBody<'a> {
+    Binary(Cow<'a, [u8]>),
+    Master(Vec<EbmlNode<'a>>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EbmlNode<'a> {
+    pub id: u64,
+    /// The data size as it was read, kept so its width can be reused when writing
+    size: Option<VarInt>,
+    /// Position of the element ID in the source data
+    offset: Option<u64>,
+    body: EbmlBody<'a>,
+}
+
+impl<'a> EbmlNode<'a> {
+    pub fn binary(id: u64, data: impl Into<Cow<'a, [u8]>>) -> Self {
+        Self {
+            id,
+            size: None,
+            offset: None,
+            body: EbmlBody::Binary(data.into()),
+        }
+    }
+
+    pub fn master(id: u64, children: Vec<EbmlNode<'a>>) -> Self {
+        Self {
+            id,
+            size: None,
+            offset: None,
+            body: EbmlBody::Master(children),
+        }
+    }
+
+    /// Creates an unsigned integer element using the fewest octets possible
+    pub fn unsigned(id: u64, value: u64) -> Self {
+        let bytes = value.to_be_bytes();
+        let skip = (value.leading_zeros() as usize / 8).min(7);
+        Self::binary(id, bytes[skip..].to_vec())
+    }
+
+    /// Creates a signed integer element using the fewest octets possible
+    pub fn signed(id: u64, value: i64) -> Self {
+        let bytes = value.to_be_bytes();
+        // Octets that only repeat the sign can be dropped
+        let redundant = if value < 0 {
+            value.leading_ones()
+        } else {
+            value.leading_zeros()
+        } as usize;
+        let skip = (redundant.saturating_sub(1) / 8).min(7);
+        Self::binary(id, bytes[skip..].to_vec())
+    }
+
+    /// Creates an 8 octet float element
+    pub fn float(id: u64, value: f64) -> Self {
+        Self::binary(id, value.to_be_bytes().to_vec())
+    }
+
+    /// Creates a date element from nanoseconds since 2001-01-01T00:00:00 UTC
+    pub fn date(id: u64, value: i64) -> Self {
+        Self::binary(id, value.to_be_bytes().to_vec())
+    }
+
+    pub fn string(id: u64, value: &str) -> Self {
+        Self::binary(id, value.as_bytes().to_vec())
+    }
+
+    /// Copies any borrowed data so the element no longer depends on the source buffer
+    pub fn into_owned(self) -> EbmlNode<'static> {
+        EbmlNode {
+            id: self.id,
+            size: self.size,
+            offset: self.offset,
+            body: match self.body {
+                EbmlBody::Binary(data) => EbmlBody::Binary(Cow::Owned(data.into_owned())),
+                EbmlBody::Master(children) => {
+                    EbmlBody::Master(children.into_iter().map(|c| c.into_owned()).collect())
+                }
+            },
+        }
+    }
+
+    pub fn body(&self) -> &EbmlBody<'a> {
+        &self.body
+    }
+
+    /// The size read from the source, `None` for elements created in memory
+    pub fn size(&self) -> Option<&VarInt> {
+        self.size.as_ref()
+    }
+
+    /// Position of the element in the source, `None` for elements created in memory
+    pub fn offset(&self) -> Option<u64> {
+        self.offset
+    }
+
+    pub fn is_master(&self) -> bool {
+        matches!(self.body, EbmlBody::Master(_))
+    }
+
+    /// The data of a binary element
+    pub fn data(&self) -> Option<&[u8]> {
+        match &self.body {
+            EbmlBody::Binary(data) => Some(data),
+            EbmlBody::Master(_) => None,
+        }
+    }
+
+    /// Replaces the data of a binary element
+    pub fn set_data(&mut self, data: impl Into<Cow<'a, [u8]>>) -> Result<(), EbmlError> {
+        match &mut self.body {
+            EbmlBody::Binary(d) => {
+                *d = data.into();
+                Ok(())
+            }
+            EbmlBody::Master(_) => Err(EbmlError::IsMasterElement(self.id)),
+        }
+    }
+
+    /// The children of a master element, empty for binary elements
+    pub fn children(&self) -> &[EbmlNode<'a>] {
+        match &self.body {
+            EbmlBody::Master(children) => children,
+            EbmlBody::Binary(_) => &[],
+        }
+    }
+
+    pub fn children_mut(&mut self) -> Result<&mut Vec<EbmlNode<'a>>, EbmlError> {
+        match &mut self.body {
+            EbmlBody::Master(children) => Ok(children),
+            EbmlBody::Binary(_) => Err(EbmlError::NotMasterElement(self.id)),
+        }
+    }
+
+    /// The first child with the given ID
+    pub fn child(&self, id: u64) -> Option<&EbmlNode<'a>> {
+        self.children().iter().find(|c| c.id == id)
+    }
+
+    pub fn child_mut(&mut self, id: u64) -> Option<&mut EbmlNode<'a>> {
+        self.children_mut().ok()?.iter_mut().find(|c| c.id == id)
+    }
+
+    /// The index of the first child with the given ID
+    pub fn child_position(&self, id: u64) -> Option<usize> {
+        self.children().iter().position(|c| c.id == id)
+    }
+
+    /// Finds a descendant by following the IDs in `path`, an empty path is this element
+    pub fn find(&self, path: &[u64]) -> Option<&EbmlNode<'a>> {
+        match path.split_first() {
+            Some((first, rest)) => self.child(*first)?.find(rest),
+            None => Some(self),
+        }
+    }
+
+    pub fn find_mut(&mut self, path: &[u64]) -> Option<&mut EbmlNode<'a>> {
+        match path.split_first() {
+            Some((first, rest)) => self.child_mut(*first)?.find_mut(rest),
+            None => Some(self),
+        }
+    }
+
+    pub fn push_child(&mut self, child: EbmlNode<'a>) -> Result<(), EbmlError> {
+        self.children_mut()?.push(child);
+        Ok(())
+    }
+
+    pub fn insert_child(&mut self, index: usize, child: EbmlNode<'a>) -> Result<(), EbmlError> {
+        let children = self.children_mut()?;
+        if index > children.len() {
+            return Err(EbmlError::ChildOutOfBounds(index));
+        }
+        children.insert(index, child);
+        Ok(())
+    }
+
+    pub fn remove_child(&mut self, index: usize) -> Result<EbmlNode<'a>, EbmlError> {
+        let children = self.children_mut()?;
+        if index >= children.len() {
+            return Err(EbmlError::ChildOutOfBounds(index));
+        }
+        Ok(children.remove(index))
+    }
+
+    /// Replaces a child, returning the element that was there before
+    pub fn replace_child(
+        &mut self,
+        index: usize,
+        child: EbmlNode<'a>,
+    ) -> Result<EbmlNode<'a>, EbmlError> {
+        let children = self.children_mut()?;
+        match children.get_mut(index) {
+            Some(existing) => Ok(std::mem::replace(existing, child)),
+            None => Err(EbmlError::ChildOutOfBounds(index)),
+        }
+    }
+
+    /// Moves the child at `from` so it ends up at index `to`
+    pub fn move_child(&mut self, from: usize, to: usize) -> Result<(), EbmlError> {
+        let children = self.children_mut()?;
+        if from >= children.len() {
+            return Err(EbmlError::ChildOutOfBounds(from));
+        }
+        if to >= children.len() {
+            return Err(EbmlError::ChildOutOfBounds(to));
+        }
+        let child = children.remove(from);
+        children.insert(to, child);
+        Ok(())
+    }
+
+    /// The size of the element's data with all descendants recalculated
+    pub fn data_size(&self) -> u64 {
+        match &self.body {
+            EbmlBody::Binary(data) => data.len() as u64,
+            EbmlBody::Master(children) => children.iter().map(|c| c.encoded_size()).sum(),
+        }
+    }
+
+    /// The size field that will be written for this element.
+    ///
+    /// The width of the original size is kept when the new size still fits in it so that
+    /// less data has to shift, otherwise the shortest width is used. Unknown sized masters
+    /// stay unknown.
+    pub fn size_field(&self) -> Result<VarInt, EbmlError> {
+        let data_size = self.data_size();
+        match &self.size {
+            Some(size) if size.all_ones() && self.is_master() => Ok(size.clone()),
+            Some(size) if size.fits(data_size) => {
+                VarInt::with_length(data_size, size.length.clone())
+            }
+            _ => VarInt::new(data_size),
+        }
+    }
+
+    /// The number of octets the element occupies when written, including its ID and size
+    pub fn encoded_size(&self) -> u64 {
+        let size_length = self
+            .size_field()
+            .map(|s| s.length.num_bytes())
+            .unwrap_or(VarIntLength::Eight.num_bytes());
+        (element_id_length(self.id) + size_length) as u64 + self.data_size()
+    }
+
+    /// Writes the element with its data size recalculated
+    pub fn write<W: Write>(&self, writer: &mut W) -> Result<usize, EbmlError> {
+        let mut written = write_element_id(self.id, writer)?;
+        written += self.size_field()?.write(writer)?;
+        written += self.write_data(writer)?;
+        Ok(written)
+    }
+
+    /// Writes only the element's data (the children of a master). A CRC-32 leading the
+    /// children is recalculated so edits don't leave it stale.
+    pub fn write_data<W: Write>(&self, writer: &mut W) -> Result<usize, EbmlError> {
+        let mut written = 0;
+        match &self.body {
+            EbmlBody::Binary(data) => {
+                writer.write_all(data)?;
+                written += data.len();
+            }
+            EbmlBody::Master(children) => match children.split_first() {
+                Some((crc, rest)) if crc.id == Crc32::ID && crc.data_size() == 4 => {
+                    let mut data = Vec::new();
+                    for child in rest {
+                        child.write(&mut data)?;
+                    }
+                    let mut crc = crc.clone();
+                    crc.body = EbmlBody::Binary(Cow::Owned(crc32(&data).to_le_bytes().to_vec()));
+                    written += crc.write(writer)?;
+                    writer.write_all(&data)?;
+                    written += data.len();
+                }
+                _ => {
+                    for child in children {
+                        written += child.write(writer)?;
+                    }
+                }
+            },
+        }
+        Ok(written)
+    }
+}
+
+/// Carries the settings shared by every level of a tree parse
+pub(crate) struct TreeParser<'p, K: ElementKinds> {
+    pub kinds: &'p K,
+    pub context: &'p mut ParseContext,
+}
+
+impl<'p, K: ElementKinds> TreeParser<'p, K> {
+    pub fn node<'a>(
+        &mut self,
+        cursor: &mut Cursor<&'a [u8]>,
+        parent_end: u64,
+        depth: usize,
+    ) -> Result<EbmlNode<'a>, EbmlError> {
+        self.context.limits.check_depth(depth)?;
+        let offset = cursor.position();
+        let allow_zero_id = self.context.allow_zero_id || self.kinds.defines_zero_id();
+        let element =
+            EbmlElement::read_allowing_zero_id(&mut *cursor, self.context, allow_zero_id)?;
+
+        let body = if self.kinds.is_master(element.id) || is_header_master(element.id) {
+            let end = if element.size.all_ones() {
+                parent_end
+            } else {
+                cursor.position() + element.size.value
+            };
+            if end > parent_end {
+                return Err(EbmlError::InvalidElement(format!(
+                    "Element {:X} at {} is larger than its parent",
+                    element.id, offset
+                )));
+            }
+            let unknown_size = element.size.all_ones();
+            EbmlBody::Master(self.children(cursor, element.id, end, unknown_size, depth + 1)?)
+        } else {
+            if element.size.all_ones() {
+                return Err(EbmlError::MustBeSized("binary element"));
+            }
+            EbmlBody::Binary(Cow::Borrowed(element.get_data(cursor)?))
+        };
+
+        Ok(EbmlNode {
+            id: element.id,
+            size: Some(element.size),
+            offset: Some(offset),
+            body,
+        })
+    }
+
+    fn children<'a>(
+        &mut self,
+        cursor: &mut Cursor<&'a [u8]>,
+        parent_id: u64,
+        end: u64,
+        unknown_size: bool,
+        depth: usize,
+    ) -> Result<Vec<EbmlNode<'a>>, EbmlError> {
+        let mut children = Vec::new();
+        let mut count = 0;
+        while cursor.position() < end {
+            if unknown_size && self.ends_parent(cursor, parent_id) {
+                break;
+            }
+            count += 1;
+            self.context.limits.check_children(parent_id, count)?;
+            let child = self.node(cursor, end, depth)?;
+            if child.id == Crc32::ID {
+                let covered = get_range(cursor, end)?;
+                let offset = child.offset.unwrap_or_default();
+                let stored = child.data().unwrap_or_default();
+                self.context
+                    .check_crc(parent_id, offset, count == 1, stored, covered)?;
+            }
+            if child.id != Void::ID || self.context.keep_void {
+                children.push(child);
+            }
+        }
+        Ok(children)
+    }
+
+    /// Peeks at the next element ID to see whether it ends a parent of unknown size
+    fn ends_parent(&mut self, cursor: &mut Cursor<&[u8]>, parent_id: u64) -> bool {
+        let position = cursor.position();
+        let warnings = self.context.warnings().len();
+        let allow_zero_id = self.context.allow_zero_id || self.kinds.defines_zero_id();
+        let id = read_id(cursor, self.context, allow_zero_id);
+        cursor.set_position(position);
+        // Invalid IDs and their warnings are left for the child parse to report
+        self.context.truncate_warnings(warnings);
+        id.is_ok_and(|id| self.kinds.ends_unknown_size(parent_id, id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CrcVerification, ParseLimits, Strictness};
+
+    const SEGMENT: u64 = 0x18538067;
+    const INFO: u64 = 0x1549A966;
+    const TITLE: u64 = 0x7BA9;
+
+    fn kinds(id: u64) -> bool {
+        id == SEGMENT || id == INFO
+    }
+
+    fn sample() -> Vec<u8> {
+        vec![
+            0x18, 0x53, 0x80, 0x67, 0x40, 0x09, // Segment, size 9 in two octets
+            0x15, 0x49, 0xA9, 0x66, 0x84, // Info, size 4
+            0x7B, 0xA9, 0x81, b'a', // Title "a"
+        ]
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let data = sample();
+        let doc = EbmlDocument::parse(&data[..], &kinds).unwrap();
+        assert_eq!(doc.to_bytes().unwrap(), data);
+        let title = doc.find(&[SEGMENT, INFO, TITLE]).unwrap();
+        assert_eq!(title.data(), Some(&b"a"[..]));
+        assert_eq!(title.offset(), Some(11));
+    }
+
+    #[test]
+    fn test_size_width_preserved() {
+        let data = sample();
+        let mut doc = EbmlDocument::parse(&data[..], &kinds).unwrap();
+        let title = doc.find_mut(&[SEGMENT, INFO, TITLE]).unwrap();
+        title.set_data(&b"abcd"[..]).unwrap();
+        let bytes = doc.to_bytes().unwrap();
+        // Segment keeps its two octet size, Info still fits in one
+        assert_eq!(
+            &bytes[..11],
+            &[0x18, 0x53, 0x80, 0x67, 0x40, 0x0C, 0x15, 0x49, 0xA9, 0x66, 0x87]
+        );
+        assert_eq!(bytes.len(), 18);
+    }
+
+    #[test]
+    fn test_size_widened() {
+        let data = sample();
+        let mut doc = EbmlDocument::parse(&data[..], &kinds).unwrap();
+        let title = doc.find_mut(&[SEGMENT, INFO, TITLE]).unwrap();
+        title.set_data(vec![b'x'; 200]).unwrap();
+        let bytes = doc.to_bytes().unwrap();
+        let reparsed = EbmlDocument::parse(&bytes[..], &kinds).unwrap();
+        let info = reparsed.find(&[SEGMENT, INFO]).unwrap();
+        assert_eq!(info.size().unwrap().length, VarIntLength::Two);
+        assert_eq!(info.data_size(), 204);
+        let title = reparsed.find(&[SEGMENT, INFO, TITLE]).unwrap();
+        assert_eq!(title.data().unwrap().len(), 200);
+    }
+
+    #[test]
+    fn test_edit_children() {
+        let data = sample();
+        let mut doc = EbmlDocument::parse(&data[..], &kinds).unwrap();
+        let info = doc.find_mut(&[SEGMENT, INFO]).unwrap();
+        info.push_child(EbmlNode::unsigned(0x2AD7B1, 1_000_000))
+            .unwrap();
+        info.move_child(1, 0).unwrap();
+        assert_eq!(info.children()[0].id, 0x2AD7B1);
+        let old = info
+            .replace_child(1, EbmlNode::string(TITLE, "new"))
+            .unwrap();
+        assert_eq!(old.data(), Some(&b"a"[..]));
+        assert!(info.remove_child(5).is_err());
+        let timestamp_scale = info.child_mut(0x2AD7B1).unwrap();
+        assert!(timestamp_scale
+            .push_child(EbmlNode::binary(0xEC, vec![]))
+            .is_err());
+
+        let bytes = doc.to_bytes().unwrap();
+        let reparsed = EbmlDocument::parse(&bytes[..], &kinds).unwrap();
+        let info = reparsed.find(&[SEGMENT, INFO]).unwrap();
+        assert_eq!(info.children()[0].data(), Some(&[0x0F, 0x42, 0x40][..]));
+        assert_eq!(info.children()[1].data(), Some(&b"new"[..]));
+    }
+
+    #[test]
+    fn test_value_constructors() {
+        assert_eq!(EbmlNode::unsigned(1, 0).data(), Some(&[0][..]));
+        assert_eq!(EbmlNode::signed(1, -1).data(), Some(&[0xFF][..]));
+        assert_eq!(EbmlNode::signed(1, 127).data(), Some(&[0x7F][..]));
+        assert_eq!(EbmlNode::signed(1, 128).data(), Some(&[0x00, 0x80][..]));
+        assert_eq!(EbmlNode::signed(1, -129).data(), Some(&[0xFF, 0x7F][..]));
+        assert_eq!(EbmlNode::float(1, 1.5).data().unwrap().len(), 8);
+    }
+
+    #[test]
+    fn test_limits() {
+        let data = sample();
+        let limits = ParseLimits {
+            max_depth: 1,
+            ..Default::default()
+        };
+        let mut context = ParseContext::new(limits, Strictness::Strict);
+        match EbmlDocument::parse_with(&data[..], &kinds, &mut context) {
+            Err(EbmlError::MaxDepthExceeded(1)) => {}
+            other => panic!("Incorrect result: {:?}", other),
+        }
+
+        let limits = ParseLimits {
+            max_element_size: 8,
+            ..Default::default()
+        };
+        let mut context = ParseContext::new(limits, Strictness::Strict);
+        match EbmlDocument::parse_with(&data[..], &kinds, &mut context) {
+            Err(EbmlError::ElementTooLarge(SEGMENT, 9, 8)) => {}
+            other => panic!("Incorrect result: {:?}", other),
+        }
+
+        let mut doc = EbmlDocument::parse(&data[..], &kinds).unwrap();
+        let info = doc.find_mut(&[SEGMENT, INFO]).unwrap();
+        info.push_child(EbmlNode::string(TITLE, "b")).unwrap();
+        let bytes = doc.to_bytes().unwrap();
+        let limits = ParseLimits {
+            max_children: 1,
+            ..Default::default()
+        };
+        let mut context = ParseContext::new(limits, Strictness::Strict);
+        match EbmlDocument::parse_with(&bytes[..], &kinds, &mut context) {
+            Err(EbmlError::TooManyChildren(INFO, 1)) => {}
+            other => panic!("Incorrect result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_globals() {
+        let info = EbmlNode::master(
+            INFO,
+            vec![
+                EbmlNode::binary(Crc32::ID, vec![0; 4]),
+                EbmlNode::string(TITLE, "a"),
+                EbmlNode::binary(Void::ID, vec![0; 2]),
+            ],
+        );
+        let data = EbmlDocument::new(vec![info, EbmlNode::binary(Void::ID, vec![])])
+            .to_bytes()
+            .unwrap();
+
+        let mut context = ParseContext::strict();
+        context.crc = CrcVerification::Verify;
+        let mut doc = EbmlDocument::parse_with(&data[..], &kinds, &mut context).unwrap();
+        assert_eq!(doc.children.len(), 1);
+        let ids: Vec<_> = doc.children[0].children().iter().map(|c| c.id).collect();
+        assert_eq!(ids, [Crc32::ID, TITLE]);
+
+        // The CRC is recomputed after an edit
+        let title = doc.find_mut(&[INFO, TITLE]).unwrap();
+        title.set_data(&b"abc"[..]).unwrap();
+        let bytes = doc.to_bytes().unwrap();
+        EbmlDocument::parse_with(&bytes[..], &kinds, &mut context).unwrap();
+
+        let mut corrupt = data.clone();
+        corrupt[14] = b'b';
+        match EbmlDocument::parse_with(&corrupt[..], &kinds, &mut context) {
+            Err(EbmlError::CrcMismatch(INFO, _, _)) => {}
+            other => panic!("Incorrect result: {:?}", other),
+        }
+
+        context.keep_void = true;
+        context.crc = CrcVerification::Ignore;
+        let doc = EbmlDocument::parse_with(&corrupt[..], &kinds, &mut context).unwrap();
+        assert_eq!(doc.children.len(), 2);
+        assert_eq!(doc.children[0].children().len(), 3);
+    }
+
+    #[test]
+    fn test_void_round_trip() {
+        #[rustfmt::skip]
+        let data = [
+            0x15, 0x49, 0xA9, 0x66, 0x88, // Info
+            0x7B, 0xA9, 0x81, b'a', // Title
+            0xEC, 0x82, 0, 0, // Void
+            0xEC, 0x40, 0x01, 0, // Void with a two octet size
+        ];
+        let doc = EbmlDocument::parse(&data[..], &kinds).unwrap();
+        assert_eq!(doc.children[0].children().len(), 1);
+
+        let mut context = ParseContext::strict();
+        context.keep_void = true;
+        let doc = EbmlDocument::parse_with(&data[..], &kinds, &mut context).unwrap();
+        assert_eq!(doc.children.len(), 2);
+        assert_eq!(doc.children[0].children()[1].id, Void::ID);
+        assert_eq!(doc.to_bytes().unwrap(), data);
+    }
+
+    #[test]
+    fn test_lenient_element_id() {
+        // Info's Title child written with a three octet ID
+        let data = [0x15, 0x49, 0xA9, 0x66, 0x84, 0x20, 0x3B, 0xA9, 0x80];
+        match EbmlDocument::parse(&data[..], &kinds) {
+            Err(EbmlError::ElementIdLongerThanNeeded) => {}
+            other => panic!("Incorrect result: {:?}", other),
+        }
+
+        let mut context = ParseContext::lenient();
+        let doc = EbmlDocument::parse_with(&data[..], &kinds, &mut context).unwrap();
+        assert_eq!(doc.find(&[INFO]).unwrap().children()[0].id, 0x203BA9);
+        assert_eq!(context.warnings().len(), 1);
+        assert_eq!(context.warnings()[0].offset, 5);
+    }
+}
@@ -1,6 +1,10 @@
-use crate::{get_data, get_data_size, get_element_id, EbmlError};
+use crate::{
+    get_data, get_data_size, get_element_id, get_range, read_id, EbmlError, ParseContext,
+};
 use compact_str::CompactString;
-use std::io::{Cursor, Read, Seek};
+use std::io::{Cursor, Read, Seek, Write};
+use std::ops::RangeBounds;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 pub struct EbmlElement {
     pub id: u64,
@@ -13,14 +17,8 @@ impl TryFrom<&mut Cursor<&[u8]>> for EbmlElement {
 
     fn try_from(cursor: &mut Cursor<&[u8]>) -> Result<Self, Self::Error> {
         let start = cursor.position();
-        let id = match get_element_id(cursor) {
-            Ok(v) => v,
-            Err(_) => return Err(EbmlError::ElementIdAllOnes),
-        };
-        let size = match get_data_size(cursor) {
-            Ok(v) => v,
-            Err(_) => return Err(EbmlError::ElementIdAllOnes),
-        };
+        let id = get_element_id(cursor)?;
+        let size = get_data_size(cursor)?;
         let end = cursor.position();
         Ok(EbmlElement {
             id,
@@ -31,12 +29,38 @@ impl TryFrom<&mut Cursor<&[u8]>> for EbmlElement {
 }
 
 impl EbmlElement {
+  /// Reads the element's ID and size, rejecting sizes over the context's limit
+  pub fn read(cursor: &mut Cursor<&[u8]>, context: &mut ParseContext) -> Result<Self, EbmlError> {
+    let allow_zero_id = context.allow_zero_id;
+    Self::read_allowing_zero_id(cursor, context, allow_zero_id)
+  }
+
+  /// Reads the element like [`EbmlElement::read`], taking the all zero ID as valid when
+  /// the schema being read defines it
+  pub(crate) fn read_allowing_zero_id(
+    cursor: &mut Cursor<&[u8]>,
+    context: &mut ParseContext,
+    allow_zero_id: bool,
+  ) -> Result<Self, EbmlError> {
+    let start = cursor.position();
+    let id = read_id(cursor, context, allow_zero_id)?;
+    let size = get_data_size(cursor)?;
+    if !size.all_ones() {
+      context.limits.check_element_size(id, size.value)?;
+    }
+    Ok(EbmlElement {
+      id,
+      size,
+      length: cursor.position() - start,
+    })
+  }
+
   #[inline]
   pub fn get_data<'a>(&self, cursor: &mut Cursor<&'a [u8]>) -> Result<&'a [u8], EbmlError> {
     get_data(self.size.value, cursor)
   }
 
-  pub fn get_child<'a>(&self, cursor: &mut Cursor<&'a [u8]>) -> Result<EbmlElement, EbmlError> {
+  pub fn get_child(&self, cursor: &mut Cursor<&[u8]>) -> Result<EbmlElement, EbmlError> {
     EbmlElement::try_from(&mut *cursor)
   }
 }
@@ -51,15 +75,32 @@ pub struct EbmlHeader {
     doc_type_version: Option<EbmlUnsignedInteger>,
     doc_type_read_version: Option<EbmlUnsignedInteger>,
     doc_type_extensions: Option<Vec<DocTypeExtension>>,
+    /// Position of the EBML element
+    offset: u64,
 }
 
 impl TryFrom<&mut Cursor<&[u8]>> for EbmlHeader {
     type Error = EbmlError;
 
     fn try_from(cursor: &mut Cursor<&[u8]>) -> Result<Self, Self::Error> {
-        let mut header = EbmlHeader::default();
+        EbmlHeader::parse(cursor, &mut ParseContext::strict())
+    }
+}
+
+impl EbmlHeader {
+    /// The newest `EBMLReadVersion` this library can read
+    pub const READ_VERSION: u64 = 1;
 
-        let ebml = EbmlElement::try_from(&mut *cursor)?;
+    /// Reads the header, checking its values against the ranges RFC 8794 gives them.
+    /// Values out of range and an `EBMLReadVersion` newer than [`Self::READ_VERSION`] are
+    /// violations.
+    pub fn parse(cursor: &mut Cursor<&[u8]>, context: &mut ParseContext) -> Result<Self, EbmlError> {
+        let mut header = EbmlHeader {
+            offset: cursor.position(),
+            ..Default::default()
+        };
+
+        let ebml = EbmlElement::read(&mut *cursor, context)?;
         if ebml.id != Ebml::ID {
             return Err(EbmlError::InvalidElement(format!(
                 "Invalid element id: {:X}",
@@ -67,81 +108,176 @@ impl TryFrom<&mut Cursor<&[u8]>> for EbmlHeader {
             )));
         }
 
-        while cursor.position() < ebml.size.value + ebml.length {
-            let element = EbmlElement::try_from(&mut *cursor)?;
+        let end = cursor.position() + ebml.size.value;
+        let mut children = 0;
+        while cursor.position() < end {
+            children += 1;
+            context.limits.check_children(Ebml::ID, children)?;
+            let offset = cursor.position();
+            let element = EbmlElement::read(&mut *cursor, context)?;
             match element.id {
+                Void::ID => {
+                    get_data(element.size.value, &mut *cursor)?;
+                }
+                Crc32::ID => {
+                    let stored = get_data(element.size.value, &mut *cursor)?;
+                    let covered = get_range(cursor, end)?;
+                    context.check_crc(Ebml::ID, offset, children == 1, stored, covered)?;
+                }
                 EbmlVersion::ID => {
                     let data = get_data(element.size.value, &mut *cursor)?;
-                    header.version = Some(EbmlUnsignedInteger::new(data)?);
+                    let version = EbmlUnsignedInteger::new(data)?;
+                    check_range(context, offset, "EBMLVersion", version.value(), 1..)?;
+                    header.version = Some(version);
                 }
                 DocType::ID => {
+                    let data_offset = cursor.position();
                     let data = get_data(element.size.value, &mut *cursor)?;
-                    header.doc_type = Some(EbmlString::new(data)?);
+                    let doc_type = EbmlString::read(data, data_offset, context)?;
+                    check_range(context, offset, "DocType length", data.len() as u64, 1..)?;
+                    header.doc_type = Some(doc_type);
                 }
                 DocTypeVersion::ID => {
                     let data = get_data(element.size.value, &mut *cursor)?;
-                    header.doc_type_version = Some(EbmlUnsignedInteger::new(data)?);
+                    let version = EbmlUnsignedInteger::new(data)?;
+                    check_range(context, offset, "DocTypeVersion", version.value(), 1..)?;
+                    header.doc_type_version = Some(version);
                 }
                 DocTypeReadVersion::ID => {
                     let data = get_data(element.size.value, &mut *cursor)?;
-                    header.doc_type_read_version = Some(EbmlUnsignedInteger::new(data)?);
+                    let version = EbmlUnsignedInteger::new(data)?;
+                    check_range(context, offset, "DocTypeReadVersion", version.value(), 1..)?;
+                    header.doc_type_read_version = Some(version);
                 }
                 EbmlReadVersion::ID => {
                     let data = get_data(element.size.value, &mut *cursor)?;
-                    header.read_version = Some(EbmlUnsignedInteger::new(data)?);
+                    let version = EbmlUnsignedInteger::new(data)?;
+                    check_range(context, offset, "EBMLReadVersion", version.value(), 1..)?;
+                    if version.value() > EbmlHeader::READ_VERSION {
+                        context.violation(
+                            offset,
+                            EbmlError::UnsupportedReadVersion(
+                                "EBML".to_string(),
+                                version.value(),
+                                EbmlHeader::READ_VERSION,
+                            ),
+                        )?;
+                    }
+                    header.read_version = Some(version);
                 }
                 EbmlMaxIdLength::ID => {
                     let data = get_data(element.size.value, &mut *cursor)?;
-                    header.max_id_length = Some(EbmlUnsignedInteger::new(data)?);
+                    let length = EbmlUnsignedInteger::new(data)?;
+                    check_range(context, offset, "EBMLMaxIDLength", length.value(), 4..)?;
+                    header.max_id_length = Some(length);
                 }
                 EbmlMaxSizeLength::ID => {
                     let data = get_data(element.size.value, &mut *cursor)?;
-                    header.max_size_length = Some(EbmlUnsignedInteger::new(data)?);
+                    let length = EbmlUnsignedInteger::new(data)?;
+                    check_range(context, offset, "EBMLMaxSizeLength", length.value(), 1..=8)?;
+                    header.max_size_length = Some(length);
                 }
                 DocTypeExtension::ID => {
-                    let first_element = EbmlElement::try_from(&mut *cursor)?;
-                    let first_data = get_data(first_element.size.value, &mut *cursor)?;
-                    let second_element = EbmlElement::try_from(&mut *cursor)?;
-                    let second_data = get_data(second_element.size.value, &mut *cursor)?;
-
-                    let extension;
-                    if first_element.id == DocTypeExtensionName::ID
-                        && second_element.id == DocTypeExtensionVersion::ID
+                    context.limits.check_depth(2)?;
+                    let extension_end = (cursor.position() + element.size.value).min(end);
+                    if let Some(extension) =
+                        DocTypeExtension::parse(cursor, offset, extension_end, context)?
                     {
-                        let name = EbmlString::new(first_data)?;
-                        let version = EbmlUnsignedInteger::new(second_data)?;
-                        extension = DocTypeExtension::new(name, version);
-                    } else if first_element.id == DocTypeExtensionVersion::ID
-                        && second_element.id == DocTypeExtensionName::ID
-                    {
-                        let name = EbmlString::new(second_data)?;
-                        let version = EbmlUnsignedInteger::new(first_data)?;
-                        extension = DocTypeExtension::new(name, version);
-                    } else {
-                        return Err(EbmlError::InvalidElement(format!(
-                            "Unrecognized one or two element ids in ebml header: {:X}, {:X}",
-                            first_element.id, second_element.id
-                        )));
-                    }
-
-                    match header.doc_type_extensions {
-                        Some(ref mut v) => v.push(extension),
-                        None => {
-                            header.doc_type_extensions = Some(vec![extension]);
+                        match header.doc_type_extensions {
+                            Some(ref mut v) => v.push(extension),
+                            None => {
+                                header.doc_type_extensions = Some(vec![extension]);
+                            }
                         }
                     }
                 }
                 _ => {
-                    return Err(EbmlError::InvalidElement(format!(
-                        "EBML Header contains invalid element ID {:X}",
-                        element.id
-                    )))
+                    context.violation(
+                        offset,
+                        EbmlError::InvalidElement(format!(
+                            "EBML Header contains invalid element ID {:X}",
+                            element.id
+                        )),
+                    )?;
+                    get_data(element.size.value, &mut *cursor)?;
                 }
             }
         }
 
         Ok(header)
     }
+
+    pub fn version(&self) -> u64 {
+        value_or(&self.version, EbmlVersion::DEFAULT)
+    }
+
+    /// The minimum EBML version a reader needs
+    pub fn read_version(&self) -> u64 {
+        value_or(&self.read_version, EbmlReadVersion::DEFAULT)
+    }
+
+    pub fn max_id_length(&self) -> u64 {
+        value_or(&self.max_id_length, EbmlMaxIdLength::DEFAULT)
+    }
+
+    pub fn max_size_length(&self) -> u64 {
+        value_or(&self.max_size_length, EbmlMaxSizeLength::DEFAULT)
+    }
+
+    pub fn doc_type(&self) -> Option<&str> {
+        self.doc_type.as_ref().map(|d| d.as_str())
+    }
+
+    pub fn doc_type_version(&self) -> u64 {
+        value_or(&self.doc_type_version, DocTypeVersion::DEFAULT)
+    }
+
+    /// The minimum DocType version a reader needs
+    pub fn doc_type_read_version(&self) -> u64 {
+        value_or(&self.doc_type_read_version, DocTypeReadVersion::DEFAULT)
+    }
+
+    /// Reports a violation when reading the document needs a newer version of its DocType
+    /// than `supported`
+    pub fn check_doc_type_read_version(
+        &self,
+        supported: u64,
+        context: &mut ParseContext,
+    ) -> Result<(), EbmlError> {
+        if self.doc_type_read_version() > supported {
+            context.violation(
+                self.offset,
+                EbmlError::UnsupportedReadVersion(
+                    self.doc_type().unwrap_or_default().to_string(),
+                    self.doc_type_read_version(),
+                    supported,
+                ),
+            )?;
+        }
+        Ok(())
+    }
+
+    pub fn doc_type_extensions(&self) -> &[DocTypeExtension] {
+        self.doc_type_extensions.as_deref().unwrap_or_default()
+    }
+}
+
+fn value_or(value: &Option<EbmlUnsignedInteger>, default: u64) -> u64 {
+    value.as_ref().map_or(default, |v| v.value())
+}
+
+/// Reports a header value outside of its range as a violation
+fn check_range<R: RangeBounds<u64>>(
+    context: &mut ParseContext,
+    offset: u64,
+    name: &'static str,
+    value: u64,
+    range: R,
+) -> Result<(), EbmlError> {
+    if !range.contains(&value) {
+        context.violation(offset, EbmlError::InvalidHeaderValue(name, value))?;
+    }
+    Ok(())
 }
 
 #[derive(Debug, Clone)]
@@ -176,6 +312,29 @@ impl EbmlString {
         };
         result
     }
+
+    /// Creates the string after checking it against the context's limits. Non printable
+    /// characters are a violation, when reading leniently they are kept (or replaced if
+    /// they aren't UTF-8).
+    pub fn read(data: &[u8], offset: u64, context: &mut ParseContext) -> Result<Self, EbmlError> {
+        context.limits.check_string_length(data.len())?;
+        match Self::new(data) {
+            Err(EbmlError::InvalidString) => {
+                context.violation(offset, EbmlError::InvalidString)?;
+                let end = data.iter().position(|b| *b == 0).unwrap_or(data.len());
+                Ok(Self {
+                    value: CompactString::from_utf8_lossy(&data[..end]),
+                    full_end: data.len(),
+                    end,
+                })
+            }
+            result => result,
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.value
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -199,6 +358,118 @@ impl EbmlUnsignedInteger {
             value: u64::from_be_bytes(bytes),
         })
     }
+
+    pub fn value(&self) -> u64 {
+        self.value
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct EbmlSignedInteger {
+    value: i64,
+}
+
+impl EbmlSignedInteger {
+    pub fn new(data: &[u8]) -> Result<Self, EbmlError> {
+        if data.len() > 8 {
+            return Err(EbmlError::OverMaximumSize(8));
+        }
+        // Sign extend from the first octet
+        let fill = match data.first() {
+            Some(b) if *b & 0x80 != 0 => 0xFF,
+            _ => 0,
+        };
+        let mut bytes: [u8; 8] = [fill; 8];
+        bytes[8 - data.len()..].copy_from_slice(data);
+
+        Ok(Self {
+            value: i64::from_be_bytes(bytes),
+        })
+    }
+
+    pub fn value(&self) -> i64 {
+        self.value
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct EbmlFloat {
+    value: f64,
+}
+
+impl EbmlFloat {
+    /// Floats are 0 (meaning 0.0), 4 or 8 octets
+    pub fn new(data: &[u8]) -> Result<Self, EbmlError> {
+        let value = match data.len() {
+            0 => 0.0,
+            4 => f32::from_be_bytes([data[0], data[1], data[2], data[3]]) as f64,
+            8 => {
+                let mut bytes = [0u8; 8];
+                bytes.copy_from_slice(data);
+                f64::from_be_bytes(bytes)
+            }
+            len => return Err(EbmlError::InvalidDataLength(len, "float")),
+        };
+        Ok(Self { value })
+    }
+
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+}
+
+/// A point in time stored as nanoseconds since 2001-01-01T00:00:00 UTC
+#[derive(Debug, Clone)]
+pub struct EbmlDate {
+    value: i64,
+}
+
+impl EbmlDate {
+    /// Seconds between the Unix epoch and the EBML epoch (2001-01-01)
+    pub const EPOCH_OFFSET_SECONDS: i64 = 978_307_200;
+
+    /// Dates are 0 (meaning the epoch itself) or 8 octets
+    pub fn new(data: &[u8]) -> Result<Self, EbmlError> {
+        match data.len() {
+            0 | 8 => Ok(Self {
+                value: EbmlSignedInteger::new(data)?.value(),
+            }),
+            len => Err(EbmlError::InvalidDataLength(len, "date")),
+        }
+    }
+
+    /// Nanoseconds since 2001-01-01T00:00:00 UTC
+    pub fn value(&self) -> i64 {
+        self.value
+    }
+
+    pub fn to_system_time(&self) -> SystemTime {
+        let epoch = UNIX_EPOCH + Duration::from_secs(Self::EPOCH_OFFSET_SECONDS as u64);
+        if self.value >= 0 {
+            epoch + Duration::from_nanos(self.value as u64)
+        } else {
+            epoch - Duration::from_nanos(self.value.unsigned_abs())
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct EbmlUtf8 {
+    value: CompactString,
+}
+
+impl EbmlUtf8 {
+    /// Decodes the string up to the first null octet, any padding after it is ignored
+    pub fn new(data: &[u8]) -> Result<Self, EbmlError> {
+        let end = data.iter().position(|b| *b == 0).unwrap_or(data.len());
+        Ok(Self {
+            value: CompactString::from_utf8(&data[..end]).map_err(|_e| EbmlError::InvalidString)?,
+        })
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.value
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -262,6 +533,72 @@ impl DocTypeExtension {
     pub fn new(name: EbmlString, version: EbmlUnsignedInteger) -> Self {
         Self { name, version }
     }
+
+    pub fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    pub fn version(&self) -> u64 {
+        self.version.value()
+    }
+
+    /// Reads the name and version children up to `end`. When reading leniently an
+    /// incomplete extension is skipped and `None` is returned.
+    fn parse(
+        cursor: &mut Cursor<&[u8]>,
+        offset: u64,
+        end: u64,
+        context: &mut ParseContext,
+    ) -> Result<Option<Self>, EbmlError> {
+        let mut name = None;
+        let mut version = None;
+        let mut children = 0;
+        while cursor.position() < end {
+            children += 1;
+            context.limits.check_children(DocTypeExtension::ID, children)?;
+            let child_offset = cursor.position();
+            let element = EbmlElement::read(&mut *cursor, context)?;
+            let data_offset = cursor.position();
+            let data = get_data(element.size.value, &mut *cursor)?;
+            match element.id {
+                Void::ID => {}
+                Crc32::ID => {
+                    let covered = get_range(cursor, end)?;
+                    let first = children == 1;
+                    context.check_crc(DocTypeExtension::ID, child_offset, first, data, covered)?
+                }
+                DocTypeExtensionName::ID => {
+                    name = Some(EbmlString::read(data, data_offset, context)?)
+                }
+                DocTypeExtensionVersion::ID => {
+                    let value = EbmlUnsignedInteger::new(data)?;
+                    let name = "DocTypeExtensionVersion";
+                    check_range(context, child_offset, name, value.value(), 1..)?;
+                    version = Some(value)
+                }
+                _ => context.violation(
+                    child_offset,
+                    EbmlError::InvalidElement(format!(
+                        "Unrecognized element id in doc type extension: {:X}",
+                        element.id
+                    )),
+                )?,
+            }
+        }
+
+        match (name, version) {
+            (Some(name), Some(version)) => Ok(Some(DocTypeExtension::new(name, version))),
+            _ => {
+                context.violation(
+                    offset,
+                    EbmlError::InvalidElement(
+                        "Doc type extension needs both a name and a version".to_string(),
+                    ),
+                )?;
+                Ok(None)
+            }
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -319,7 +656,7 @@ pub struct EbmlReadVersion {
 
 impl EbmlReadVersion {
     pub const ID: u64 = 0x42F7;
-    const DEFAULT: u8 = 1; // Range: ==1
+    const DEFAULT: u64 = 1; // Range: ==1
     const MIN_OCCURS: u8 = 1;
     const MAX_OCCURS: u8 = 1;
     pub fn new(size: VarInt, value: EbmlUnsignedInteger) -> Self {
@@ -335,7 +672,7 @@ pub struct EbmlMaxIdLength {
 
 impl EbmlMaxIdLength {
     pub const ID: u64 = 0x42F2;
-    const DEFAULT: u8 = 4; // range: >=4
+    const DEFAULT: u64 = 4; // range: >=4
     const MIN_OCCURS: u8 = 1;
     const MAX_OCCURS: u8 = 1;
     pub fn new(size: VarInt, value: EbmlUnsignedInteger) -> Self {
@@ -351,7 +688,7 @@ pub struct EbmlMaxSizeLength {
 
 impl EbmlMaxSizeLength {
     pub const ID: u64 = 0x42F3;
-    const DEFAULT: u8 = 8; // range: >0
+    const DEFAULT: u64 = 8; // range: >0
     const MIN_OCCURS: u8 = 1;
     const MAX_OCCURS: u8 = 1;
     pub fn new(size: VarInt, value: EbmlUnsignedInteger) -> Self {
@@ -388,6 +725,16 @@ impl<'a> EbmlBinary<'a> {
             data,
         })
     }
+
+    /// The data, borrowed from the buffer being read
+    pub fn data(&self) -> &'a [u8] {
+        self.data
+    }
+
+    /// Position of the data in the buffer
+    pub fn offset(&self) -> u64 {
+        self.index
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -397,7 +744,7 @@ pub struct Crc32<'a> {
 }
 
 impl<'a> Crc32<'a> {
-    const ID: u64 = 0xBF;
+    pub const ID: u64 = 0xBF;
     // length: 4
     // type: binary
     const MIN_OCCURS: u8 = 0; // within parent
@@ -413,7 +760,7 @@ pub struct Void {
 }
 
 impl Void {
-    const ID: u64 = 0xEC;
+    pub const ID: u64 = 0xEC;
     const MIN_OCCURS: u8 = 0;
     pub fn new(size: VarInt) -> Self {
         Void { size }
@@ -433,7 +780,7 @@ pub enum VarIntLength {
 }
 
 impl VarIntLength {
-    fn new(num_bytes: usize) -> Result<Self, EbmlError> {
+    pub(crate) fn new(num_bytes: usize) -> Result<Self, EbmlError> {
         match num_bytes {
             1 => Ok(VarIntLength::One),
             2 => Ok(VarIntLength::Two),
@@ -447,6 +794,20 @@ impl VarIntLength {
         }
     }
 
+    /// The number of octets used to encode a varint of this length
+    pub fn num_bytes(&self) -> usize {
+        match self {
+            VarIntLength::One => 1,
+            VarIntLength::Two => 2,
+            VarIntLength::Three => 3,
+            VarIntLength::Four => 4,
+            VarIntLength::Five => 5,
+            VarIntLength::Six => 6,
+            VarIntLength::Seven => 7,
+            VarIntLength::Eight => 8,
+        }
+    }
+
     fn maximum_value(&self) -> u64 {
         match self {
             VarIntLength::One => 127,
@@ -470,6 +831,60 @@ pub struct VarInt {
 }
 
 impl VarInt {
+    /// Creates a varint using the fewest octets that can hold the value
+    pub fn new(value: u64) -> Result<VarInt, EbmlError> {
+        for num_bytes in 1..=8 {
+            let length = VarIntLength::new(num_bytes)?;
+            // All value bits set to one is reserved for unknown sizes
+            if value < length.maximum_value() {
+                return Self::with_length(value, length);
+            }
+        }
+        Err(EbmlError::VarIntTooLarge)
+    }
+
+    /// Creates a varint encoded with exactly `length` octets
+    pub fn with_length(value: u64, length: VarIntLength) -> Result<VarInt, EbmlError> {
+        if value >= length.maximum_value() {
+            return Err(EbmlError::VarIntTooLarge);
+        }
+        Ok(Self::from_parts(value, length))
+    }
+
+    /// Creates a data size marked as unknown (all value bits set to one)
+    pub fn unknown(length: VarIntLength) -> VarInt {
+        let value = length.maximum_value();
+        Self::from_parts(value, length)
+    }
+
+    fn from_parts(value: u64, length: VarIntLength) -> VarInt {
+        let num_bytes = length.num_bytes();
+        let marker = 1u64 << (7 * num_bytes);
+        VarInt {
+            length,
+            bytes: value.to_be_bytes(),
+            value,
+            raw_value: value | marker,
+        }
+    }
+
+    /// The encoded octets of the varint, including the length marker
+    pub fn as_bytes(&self) -> [u8; 8] {
+        self.raw_value.to_be_bytes()
+    }
+
+    /// Writes the encoded varint, returning the number of octets written
+    pub fn write<W: Write>(&self, writer: &mut W) -> Result<usize, EbmlError> {
+        let num_bytes = self.length.num_bytes();
+        writer.write_all(&self.as_bytes()[8 - num_bytes..])?;
+        Ok(num_bytes)
+    }
+
+    /// Whether the value can be re-encoded with this varint's length
+    pub fn fits(&self, value: u64) -> bool {
+        value < self.length.maximum_value()
+    }
+
     #[inline]
     pub fn get_var_int(cursor: &mut Cursor<&[u8]>) -> Result<VarInt, EbmlError> {
         let (num_bytes, masked_first_byte, first_byte) = Self::get_var_int_length(cursor)?;
@@ -477,7 +892,7 @@ impl VarInt {
             Err(EbmlError::InvalidVarIntLength)
         } else {
             let varint = Self::get_var_int_value(cursor, masked_first_byte, num_bytes)?;
-            let mut raw_value = varint.clone();
+            let mut raw_value = varint;
             raw_value[8 - num_bytes] = first_byte;
             Ok(VarInt {
                 length: VarIntLength::new(num_bytes)?,